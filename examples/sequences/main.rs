@@ -11,7 +11,7 @@ fn main() -> anyhow::Result<()> {
 fn comparing_many() -> anyhow::Result<()> {
     // Computing the data
 
-    let data_1 = vec![0. as f32, 1., 2., 3., 4., 5.];
+    let data_1 = vec![0_f32, 1., 2., 3., 4., 5.];
     let data_2 = vec![0., 1.4, 10., 4.];
 
     // Define plotables
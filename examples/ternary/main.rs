@@ -0,0 +1,23 @@
+use preexplorer::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    // Mixed strategies in Rock-Paper-Scissors converging to the equilibrium
+    let strategies = (0..50).map(|t| {
+        let weight = 0.9_f64.powi(t);
+        [
+            1. / 3. + 2. / 3. * weight,
+            1. / 3. - 1. / 3. * weight,
+            1. / 3. - 1. / 3. * weight,
+        ]
+    });
+
+    pre::Ternary::new(strategies)
+        .set_title("Mixed strategies")
+        .set_labelx("Rock")
+        .set_labely("Paper")
+        .set_labelz("Scissors")
+        .set_style("linespoints")?
+        .plot("my_identifier")?;
+
+    Ok(())
+}
@@ -0,0 +1,409 @@
+// Structs
+use crate::configuration::path::portable;
+use crate::errors::PreexplorerError;
+use std::fs::File;
+use std::io::{BufWriter, SeekFrom};
+use std::path::Path;
+
+// Traits
+use crate::traits::Plotable;
+use chrono::{Datelike, Timelike};
+use core::convert::TryFrom;
+use std::io::{Seek, Write};
+
+/// Writes the archive of a plot: its data files, its plot script, reading the data from
+/// the data folder of the archive, and a README, inside a folder named after ``id``.
+/// In publication mode, the LaTeX snippet of the figure is included too.
+pub(crate) fn export<P>(item: &mut P, id: &str, path: &Path) -> Result<(), PreexplorerError>
+where
+    P: Plotable + ?Sized,
+{
+    // Data files of the struct, or of each struct in comparisons
+    let data_files = item.data_paths();
+
+    // Script and names of the files, with the configuration inside the archive
+    let bundled = item.configuration().bundled();
+    let configuration = std::mem::replace(item.configuration_mut(), bundled);
+    let data_names: Vec<String> = item.data_paths().iter().map(|p| portable(p)).collect();
+    let script_name = portable(item.plot_path());
+    let gnuplot_script = item.plot_script();
+    let figure_snippet = item.publication().map(|publication| {
+        let output = match item.output() {
+            Some(output) => output.to_path_buf(),
+            None => item.configuration().default_output(),
+        };
+        let title = item.title().map(|s| s.as_str());
+        (
+            portable(&item.plot_path().with_extension("figure.tex")),
+            publication.figure_snippet(&output, title, id),
+        )
+    });
+    *item.configuration_mut() = configuration;
+
+    let mut entries = vec![(
+        format!("{}/{}", id, script_name),
+        Contents::Bytes(gnuplot_script.into_bytes()),
+    )];
+    for (data_file, name) in data_files.iter().zip(data_names.iter()) {
+        entries.push((
+            format!("{}/{}", id, name),
+            Contents::File(File::open(data_file).map_err(PreexplorerError::Reading)?),
+        ));
+    }
+    let snippet_name = figure_snippet.as_ref().map(|(name, _)| name.as_str());
+    entries.push((
+        format!("{}/README.md", id),
+        Contents::Bytes(readme(&*item, id, &script_name, &data_names, snippet_name).into_bytes()),
+    ));
+    if let Some((name, latex_snippet)) = figure_snippet {
+        entries.push((
+            format!("{}/{}", id, name),
+            Contents::Bytes(latex_snippet.into_bytes()),
+        ));
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let date = *item.date();
+    let mut writer = BufWriter::new(File::create(path)?);
+    let written = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("zip") => zip(&mut writer, &mut entries, date),
+        _ => tar(&mut writer, &mut entries, date),
+    }
+    .and_then(|_| writer.flush());
+    if let Err(e) = written {
+        // No half-written archives
+        drop(writer);
+        let _ = std::fs::remove_file(path);
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
+/// Contents of a file of the archive.
+enum Contents {
+    /// Written for the archive.
+    Bytes(Vec<u8>),
+    /// Copied from the disk while writing the archive, without keeping it in memory.
+    File(File),
+}
+
+impl Contents {
+    /// Size, in bytes.
+    fn len(&self) -> std::io::Result<u64> {
+        match self {
+            Contents::Bytes(bytes) => Ok(bytes.len() as u64),
+            Contents::File(file) => Ok(file.metadata()?.len()),
+        }
+    }
+
+    /// Copies the whole contents into ``writer``, checking that its size is ``len``.
+    fn copy_to<W: Write>(&mut self, writer: &mut W, len: u64) -> std::io::Result<()> {
+        let copied = match self {
+            Contents::Bytes(bytes) => {
+                writer.write_all(bytes)?;
+                bytes.len() as u64
+            }
+            Contents::File(file) => {
+                file.seek(SeekFrom::Start(0))?;
+                std::io::copy(file, writer)?
+            }
+        };
+        if copied != len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "file changed while writing the archive",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// README of the archive, explaining how to plot.
+fn readme<P>(
+    item: &P,
+    id: &str,
+    script_name: &str,
+    data_names: &[String],
+    snippet_name: Option<&str>,
+) -> String
+where
+    P: Plotable + ?Sized,
+{
+    let mut readme = String::new();
+    readme += &format!("# {}\n\n", item.title().map(|s| s.as_str()).unwrap_or(id));
+    readme += &format!(
+        "Plot exported by preexplorer on {}.\n\n",
+        item.date().format("%Y-%m-%d %H:%M:%S")
+    );
+    readme += "Run the plot script with gnuplot from this folder:\n\n";
+    readme += &format!("    gnuplot -p {}\n\n", script_name);
+    if let Some(snippet_name) = snippet_name {
+        readme += &format!(
+            "Then, include the figure in a LaTeX document with \\input{{{}}}.\n\n",
+            snippet_name
+        );
+    }
+    readme += &format!(
+        "Data files, in {} format with columns {}:\n\n",
+        item.data_format(),
+        item.column_names().join(", ")
+    );
+    for name in data_names {
+        readme += &format!("- {}\n", name);
+    }
+
+    readme
+}
+
+/// Uncompressed tar archive, in the ustar format.
+fn tar<W: Write>(
+    writer: &mut W,
+    entries: &mut [(String, Contents)],
+    date: chrono::DateTime<chrono::Local>,
+) -> std::io::Result<()> {
+    if let Some((name, _)) = entries.iter().find(|(name, _)| name.len() > 100) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("file name too long for a tar archive: {}", name),
+        ));
+    }
+    for (name, contents) in entries.iter_mut() {
+        let len = contents.len()?;
+        let mut header = [0u8; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        header[100..108].copy_from_slice(b"0000644\0");
+        header[108..116].copy_from_slice(b"0000000\0");
+        header[116..124].copy_from_slice(b"0000000\0");
+        header[124..136].copy_from_slice(format!("{:011o}\0", len).as_bytes());
+        header[136..148].copy_from_slice(format!("{:011o}\0", date.timestamp().max(0)).as_bytes());
+        header[148..156].copy_from_slice(b"        ");
+        header[156] = b'0';
+        header[257..265].copy_from_slice(b"ustar\x0000");
+        let checksum: u32 = header.iter().map(|&byte| byte as u32).sum();
+        header[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+
+        writer.write_all(&header)?;
+        contents.copy_to(writer, len)?;
+        let padding = (512 - len % 512) % 512;
+        writer.write_all(&[0; 512][..padding as usize])?;
+    }
+    // End of archive
+    writer.write_all(&[0; 1024])?;
+
+    Ok(())
+}
+
+/// Zip archive, with files stored without compression.
+///
+/// Without the zip64 extensions, it holds less than 65535 files and 4 GiB.
+/// Files are read twice: first for their checksum, which goes before them.
+fn zip<W: Write>(
+    writer: &mut W,
+    entries: &mut [(String, Contents)],
+    date: chrono::DateTime<chrono::Local>,
+) -> std::io::Result<()> {
+    // Sizes, offsets and counts of the headers, whose maximum values are reserved for zip64
+    let too_large = |what: &str| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{} too large for a zip archive", what),
+        )
+    };
+    let u32_field = |value: u64, what: &str| match u32::try_from(value) {
+        Ok(value) if value < u32::MAX => Ok(value),
+        _ => Err(too_large(what)),
+    };
+    let u16_field = |value: usize, what: &str| match u16::try_from(value) {
+        Ok(value) if value < u16::MAX => Ok(value),
+        _ => Err(too_large(what)),
+    };
+    let entry_count = u16_field(entries.len(), "number of files")?;
+
+    // MS-DOS date and time
+    let dos_time = ((date.hour() << 11) | (date.minute() << 5) | (date.second() / 2)) as u16;
+    let dos_date =
+        (((date.year().max(1980) - 1980) << 9) as u32 | (date.month() << 5) | date.day()) as u16;
+    // Names in UTF-8
+    let flags: u16 = 1 << 11;
+
+    let mut written: u64 = 0;
+    let mut central_directory = Vec::new();
+    for (name, contents) in entries.iter_mut() {
+        let offset = u32_field(written, "archive")?;
+        let len = contents.len()?;
+        let size = u32_field(len, name)?;
+        let name_len = u16_field(name.len(), name)?;
+        let mut crc = Crc32::new();
+        contents.copy_to(&mut crc, len)?;
+        let crc = crc.sum();
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        header.extend_from_slice(&20u16.to_le_bytes());
+        header.extend_from_slice(&flags.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&dos_time.to_le_bytes());
+        header.extend_from_slice(&dos_date.to_le_bytes());
+        header.extend_from_slice(&crc.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&name_len.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(name.as_bytes());
+        writer.write_all(&header)?;
+        contents.copy_to(writer, len)?;
+        written += header.len() as u64 + len;
+
+        central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        // Made by unix, for the file permissions
+        central_directory.extend_from_slice(&(3u16 << 8 | 20).to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&flags.to_le_bytes());
+        central_directory.extend_from_slice(&0u16.to_le_bytes());
+        central_directory.extend_from_slice(&dos_time.to_le_bytes());
+        central_directory.extend_from_slice(&dos_date.to_le_bytes());
+        central_directory.extend_from_slice(&crc.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&name_len.to_le_bytes());
+        central_directory.extend_from_slice(&[0; 8]);
+        central_directory.extend_from_slice(&(0o100644u32 << 16).to_le_bytes());
+        central_directory.extend_from_slice(&offset.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+    }
+
+    let central_directory_offset = u32_field(written, "archive")?;
+    let central_directory_size = u32_field(central_directory.len() as u64, "archive")?;
+    writer.write_all(&central_directory)?;
+    writer.write_all(&0x0605_4b50u32.to_le_bytes())?;
+    writer.write_all(&[0; 4])?;
+    writer.write_all(&entry_count.to_le_bytes())?;
+    writer.write_all(&entry_count.to_le_bytes())?;
+    writer.write_all(&central_directory_size.to_le_bytes())?;
+    writer.write_all(&central_directory_offset.to_le_bytes())?;
+    writer.write_all(&0u16.to_le_bytes())?;
+
+    Ok(())
+}
+
+/// CRC-32 checksum, as used by zip, of the bytes written into it.
+struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    fn new() -> Self {
+        Crc32 { crc: !0u32 }
+    }
+
+    fn sum(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl Write for Crc32 {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        for &byte in bytes {
+            self.crc ^= byte as u32;
+            for _ in 0..8 {
+                let mask = (self.crc & 1).wrapping_neg();
+                self.crc = (self.crc >> 1) ^ (0xEDB8_8320 & mask);
+            }
+        }
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn checksums() {
+        let mut crc = Crc32::new();
+        crc.write_all(b"123456789").unwrap();
+        assert_eq!(crc.sum(), 0xCBF4_3926);
+
+        let mut archive = Vec::new();
+        let mut entries = [(String::from("a.txt"), Contents::Bytes(b"hi".to_vec()))];
+        tar(&mut archive, &mut entries, chrono::Local::now()).unwrap();
+        assert_eq!(archive.len(), 512 * 4);
+        let checksum: u32 = archive[..512]
+            .iter()
+            .enumerate()
+            .map(|(i, &byte)| if (148..156).contains(&i) { b' ' } else { byte } as u32)
+            .sum();
+        let written = std::str::from_utf8(&archive[148..154]).unwrap();
+        assert_eq!(u32::from_str_radix(written, 8).unwrap(), checksum);
+    }
+
+    #[test]
+    fn export_bundle() -> Result<(), PreexplorerError> {
+        let root = std::env::temp_dir().join("preexplorer_export_bundle");
+        let mut first = pre::Sequence::new(vec![1, 2]);
+        first.set_title("first");
+        let mut seqs = first + pre::Sequence::new(vec![3]);
+        seqs.set_root_dir(&root)
+            .set_id("bundle_test")
+            .export_bundle(root.join("bundle_test.zip"))?;
+
+        let archive = std::fs::read(root.join("bundle_test.zip"))?;
+        assert_eq!(&archive[..4], b"PK\x03\x04");
+        let contents = String::from_utf8_lossy(&archive);
+        assert!(contents.contains("bundle_test/bundle_test.gnu"));
+        assert!(contents.contains("\"data/bundle_test_0.txt\" using 1:2"));
+        assert!(contents.contains("bundle_test/data/bundle_test_1.txt"));
+        assert!(contents.contains("    gnuplot -p bundle_test.gnu\n"));
+        assert!(!contents.contains(&root.to_string_lossy().into_owned()));
+        assert_eq!(seqs.data_path(), root.join("data").join("bundle_test.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn export_publication_bundle() -> Result<(), PreexplorerError> {
+        let root = std::env::temp_dir().join("preexplorer_export_publication_bundle");
+        let mut seq = pre::Sequence::new(vec![1, 2]);
+        seq.set_root_dir(&root)
+            .set_id("bundle_publication_test")
+            .set_publication(pre::Publication::Tikz)
+            .set_output(root.join("figures").join("error.tex"))
+            .export_bundle(root.join("bundle_publication_test.tar"))?;
+
+        let archive = std::fs::read(root.join("bundle_publication_test.tar"))?;
+        let contents = String::from_utf8_lossy(&archive);
+        assert!(contents.contains("set output \"error.tex\"\n"));
+        assert!(contents.contains("plot \"data/bundle_publication_test.txt\" with lines"));
+        assert!(contents.contains("bundle_publication_test/bundle_publication_test.figure.tex"));
+        assert!(contents.contains("  \\input{error.tex}\n"));
+        assert!(!contents.contains(&root.to_string_lossy().into_owned()));
+        Ok(())
+    }
+
+    #[test]
+    fn tar_name_too_long() {
+        let root = std::env::temp_dir().join("preexplorer_export_long_bundle");
+        let path = root.join("bundle_long_test.tar");
+        let id = "a".repeat(90);
+        let mut seq = pre::Sequence::new(vec![1, 2]);
+        seq.set_root_dir(&root).set_id(&id);
+        assert!(seq.export_bundle(&path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn zip_limits() {
+        let mut entries: Vec<(String, Contents)> = (0..65535)
+            .map(|_| (String::from("a.txt"), Contents::Bytes(Vec::new())))
+            .collect();
+        assert!(zip(&mut Vec::new(), &mut entries, chrono::Local::now()).is_err());
+        assert!(zip(&mut Vec::new(), &mut entries[1..], chrono::Local::now()).is_ok());
+    }
+}
@@ -1,4 +1,5 @@
 // Traits
+use crate::traits::Configurable;
 use core::convert::TryInto;
 use core::fmt::Debug;
 use core::fmt::Display;
@@ -6,8 +7,9 @@ use core::fmt::Display;
 // Structs
 use std::collections::HashMap;
 use std::ffi::OsStr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+pub(crate) mod path;
 pub mod plot;
 pub mod save;
 
@@ -29,17 +31,109 @@ pub struct Configuration {
 impl Configuration {
     /// Opening for a plot script including all common or setted configurations.
     pub fn opening_plot_script(&self) -> String {
-        self.plot_config.opening_plot_script()
+        self.plot_config.opening_plot_script() + &self.save_config.format().plot_script()
     }
 
     pub(crate) fn opening_plot_script_comparison(&self) -> String {
-        self.plot_config.opening_plot_script_comparison()
+        self.plot_config.opening_plot_script_comparison() + &self.save_config.format().plot_script()
+    }
+
+    /// Colorbar of a plot script, from the z axis.
+    pub(crate) fn colorbar_plot_script(&self) -> String {
+        self.plot_config.colorbar_plot_script()
     }
 
     /// Ending for a plot script including all common or setted configurations.
     pub fn ending_plot_script(&self) -> String {
         self.plot_config.ending_plot_script()
     }
+
+    /// Opening for a matplotlib script including all common or setted configurations.
+    pub fn opening_matplotlib_script(&self) -> String {
+        crate::matplotlib::opening_script(self, 1)
+    }
+
+    /// Opening for a matplotlib script with ``panels`` axes, in a grid.
+    pub(crate) fn opening_matplotlib_script_multiplot(&self, panels: usize) -> String {
+        crate::matplotlib::opening_script(self, panels)
+    }
+
+    /// Opening for a matplotlib script with three-dimensional axes.
+    pub(crate) fn opening_matplotlib_script_3d(&self) -> String {
+        crate::matplotlib::opening_script_3d(self)
+    }
+
+    /// Ending for a matplotlib script including all common or setted configurations.
+    pub fn ending_matplotlib_script(&self) -> String {
+        crate::matplotlib::ending_script(self)
+    }
+
+    /// Path of a data file with the given id, in the same directory and with the
+    /// same extension as the data file.
+    pub(crate) fn data_path_with_id<S: Display>(&self, id: S) -> PathBuf {
+        let mut path = self.save_config.path_buf().to_path_buf();
+        path.set_file_name(id.to_string());
+        if let Some(extension) = self.save_config.extension() {
+            path.set_extension(extension);
+        };
+        path
+    }
+
+    /// Paths of the data files saved by a comparison of ``len`` elements,
+    /// named after its id followed by a counter.
+    pub(crate) fn comparison_data_paths(&self, len: usize) -> Vec<PathBuf> {
+        let id = self.checked_id();
+        (0..len)
+            .flat_map(|counter| {
+                let path = self.data_path_with_id(format!("{}_{}", id, counter));
+                self.data_format().paths(&path)
+            })
+            .collect()
+    }
+
+    /// Gnuplot string of a path in the plot script, relative to the plot script
+    /// if so configured.
+    pub(crate) fn script_path<P: AsRef<Path>>(&self, path: P) -> String {
+        self.plot_config.script_path(path.as_ref())
+    }
+
+    /// Configuration inside an archive: the plot script and its output at the top,
+    /// and the data in the data folder next to them.
+    pub(crate) fn bundled(&self) -> Configuration {
+        let mut config = self.clone();
+        config.save_config.set_dir(crate::constants::DATA_FOLDER);
+        config.plot_config.set_dir("").set_relative_paths(false);
+        if let Some(file_name) = self.output().and_then(Path::file_name) {
+            config.plot_config.set_output(PathBuf::from(file_name));
+        }
+        config
+    }
+
+    /// Gnuplot string of a text in the plot script, LaTeX text in publication mode.
+    pub(crate) fn text_string(&self, text: &str) -> String {
+        self.plot_config.text_string(text)
+    }
+
+    /// Python expression of a path in the matplotlib script, relative to the script
+    /// if so configured.
+    pub(crate) fn python_path<P: AsRef<Path>>(&self, path: P) -> String {
+        self.plot_config.python_path(path.as_ref())
+    }
+
+    /// Path in the Vega-Lite specification, relative to the specification if so configured.
+    pub(crate) fn vega_path<P: AsRef<Path>>(&self, path: P) -> String {
+        self.plot_config.vega_path(path.as_ref())
+    }
+
+    /// Custom configuration fields.
+    pub(crate) fn custom_config(&self) -> &HashMap<String, String> {
+        &self.custom_config
+    }
+
+    /// Output file to use when plotting into a file without an explicit output.
+    pub(crate) fn default_output(&self) -> PathBuf {
+        self.plot_config.default_output()
+    }
 }
 
 impl crate::traits::Configurable for Configuration {
@@ -160,6 +254,41 @@ impl crate::traits::Configurable for Configuration {
         self.plot_config.set_pause(pause.map(f64::from));
         self
     }
+    fn set_terminal<T, S>(&mut self, terminal: T) -> &mut Self
+    where
+        T: Into<Option<S>>,
+        S: Display,
+    {
+        let terminal: Option<S> = terminal.into();
+        self.plot_config
+            .set_terminal(terminal.map(|t| t.to_string()));
+        self
+    }
+    fn set_wait(&mut self, wait: bool) -> &mut Self {
+        self.plot_config.set_wait(wait);
+        self
+    }
+    fn set_publication<T>(&mut self, publication: T) -> &mut Self
+    where
+        T: Into<Option<crate::configuration::plot::publication::Publication>>,
+    {
+        self.plot_config.set_publication(publication);
+        self
+    }
+    fn set_relative_paths(&mut self, relative_paths: bool) -> &mut Self {
+        self.plot_config.set_relative_paths(relative_paths);
+        self
+    }
+    fn set_output<T, P>(&mut self, output: T) -> &mut Self
+    where
+        T: Into<Option<P>>,
+        P: AsRef<Path>,
+    {
+        let output: Option<P> = output.into();
+        self.plot_config
+            .set_output(output.map(|o| o.as_ref().to_path_buf()));
+        self
+    }
 
     // Getting
     fn plot_extension(&self) -> Option<&OsStr> {
@@ -216,6 +345,31 @@ impl crate::traits::Configurable for Configuration {
     fn pause(&self) -> Option<f64> {
         *self.plot_config.pause()
     }
+    fn terminal(&self) -> Option<&String> {
+        self.plot_config.terminal().as_ref()
+    }
+    fn output(&self) -> Option<&Path> {
+        self.plot_config.output().as_deref()
+    }
+    fn wait(&self) -> bool {
+        *self.plot_config.wait()
+    }
+    fn relative_paths(&self) -> bool {
+        *self.plot_config.relative_paths()
+    }
+    fn publication(&self) -> Option<crate::configuration::plot::publication::Publication> {
+        *self.plot_config.publication()
+    }
+
+    ////////// Both /////////////////
+    fn set_root_dir<P: AsRef<Path>>(&mut self, root: P) -> &mut Self {
+        let root = root.as_ref();
+        self.save_config
+            .set_dir(root.join(crate::constants::DATA_FOLDER));
+        self.plot_config
+            .set_dir(root.join(crate::constants::PLOT_FOLDER));
+        self
+    }
 
     ////////// SaveConfiguration /////////////////
     // Setting
@@ -227,6 +381,16 @@ impl crate::traits::Configurable for Configuration {
         self.save_config.set_header(header);
         self
     }
+    fn set_data_format<F>(
+        &mut self,
+        format: F,
+    ) -> Result<&mut Self, <F as TryInto<save::format::DataFormat>>::Error>
+    where
+        F: TryInto<save::format::DataFormat>,
+    {
+        self.save_config.set_format(format.try_into()?);
+        Ok(self)
+    }
     fn set_date(&mut self, date: chrono::DateTime<chrono::Local>) -> &mut Self {
         self.save_config.set_date(date);
         self
@@ -248,6 +412,9 @@ impl crate::traits::Configurable for Configuration {
     fn header(&self) -> bool {
         *self.save_config.header()
     }
+    fn data_format(&self) -> save::format::DataFormat {
+        *self.save_config.format()
+    }
     fn date(&self) -> &chrono::DateTime<chrono::Local> {
         self.save_config.date()
     }
@@ -273,7 +440,6 @@ impl crate::traits::Configurable for Configuration {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::prelude::*;
 
     #[test]
     fn custom() {
@@ -313,13 +479,26 @@ mod tests {
         config.set_date(Local::now());
     }
 
+    #[test]
+    fn data_format() {
+        let mut config = Configuration::default();
+        assert_eq!(config.data_format(), save::DataFormat::Gnuplot);
+
+        config.set_data_format("csv").unwrap();
+        assert_eq!(config.data_format(), save::DataFormat::Csv);
+        assert_eq!(config.data_extension().unwrap().to_str(), Some("csv"));
+        assert!(config
+            .opening_plot_script()
+            .contains("set datafile separator comma\n"));
+    }
+
     #[test]
     fn header() {
         let mut config = Configuration::default();
-        assert_eq!(config.header(), true);
+        assert!(config.header());
 
         config.set_header(false);
-        assert_eq!(config.header(), false);
+        assert!(!config.header());
     }
 
     #[test]
@@ -382,6 +561,32 @@ mod tests {
         assert_eq!(config.pause(), Some(2.0));
     }
 
+    #[test]
+    fn output() {
+        let mut config = Configuration::default();
+        assert_eq!(config.terminal(), None);
+        assert_eq!(config.output(), None);
+
+        config.set_terminal("pngcairo").set_output("my_plot.png");
+        assert_eq!(config.terminal(), Some(&"pngcairo".to_string()));
+        assert_eq!(config.output(), Some(Path::new("my_plot.png")));
+
+        config
+            .set_terminal::<_, String>(None)
+            .set_output::<_, PathBuf>(None);
+        assert_eq!(config.terminal(), None);
+        assert_eq!(config.output(), None);
+    }
+
+    #[test]
+    fn wait() {
+        let mut config = Configuration::default();
+        assert!(!config.wait());
+
+        config.set_wait(true);
+        assert!(config.wait());
+    }
+
     #[test]
     fn paths() {
         let mut config = Configuration::default();
@@ -427,6 +632,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn root_dir() {
+        let mut config = Configuration::default();
+        config.set_id("testing");
+        config.set_root_dir("my_results");
+
+        assert_eq!(
+            config.data_path(),
+            Path::new("my_results").join("data").join("testing.txt")
+        );
+        assert_eq!(
+            config.plot_path(),
+            Path::new("my_results").join("plots").join("testing.gnu")
+        );
+    }
+
     #[test]
     fn ranges() {
         let mut config = Configuration::default();
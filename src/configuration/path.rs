@@ -0,0 +1,169 @@
+//! Paths written into plot scripts and serialized configurations.
+
+// Structs
+use std::path::{Component, Path, PathBuf};
+
+/// Path with ``/`` as separator, the same on every platform.
+///
+/// Gnuplot, as well as Windows, understands ``/`` as a separator.
+pub(crate) fn portable(path: &Path) -> String {
+    let mut portable = String::new();
+    for component in path.components() {
+        match component {
+            Component::RootDir => portable.push('/'),
+            component => {
+                if !portable.is_empty() && !portable.ends_with('/') {
+                    portable.push('/');
+                }
+                portable += &component.as_os_str().to_string_lossy();
+            }
+        }
+    }
+    portable
+}
+
+/// Path from its portable form, also accepting paths serialized on Windows with ``\``
+/// as separator.
+///
+/// Out of Windows, ``\`` is a valid character of file names, so it is only taken
+/// as a separator in strings without any ``/``, as those written on Windows. A lone file
+/// name including ``\``, without directories, is then split too.
+#[cfg(feature = "use-serde")]
+pub(crate) fn native(portable: &str) -> PathBuf {
+    if cfg!(windows) || portable.contains('/') {
+        PathBuf::from(portable)
+    } else {
+        PathBuf::from(portable.replace('\\', "/"))
+    }
+}
+
+/// Path relative to the directory ``base``, going up with ``..`` where needed.
+///
+/// Relative paths are taken from the current directory. If there is no relative path,
+/// as with paths in different drives, the absolute path is returned.
+pub(crate) fn relative(path: &Path, base: &Path) -> PathBuf {
+    let absolute = |path: &Path| match std::env::current_dir() {
+        Ok(current_dir) if path.is_relative() => current_dir.join(path),
+        _ => path.to_path_buf(),
+    };
+    let path = absolute(path);
+    let base = absolute(base);
+
+    let mut path_components = path.components().peekable();
+    let mut base_components = base.components().peekable();
+    match (path_components.peek(), base_components.peek()) {
+        (Some(Component::Prefix(a)), Some(Component::Prefix(b))) if a != b => return path,
+        _ => (),
+    }
+    while let (Some(a), Some(b)) = (path_components.peek(), base_components.peek()) {
+        if a != b {
+            break;
+        }
+        path_components.next();
+        base_components.next();
+    }
+
+    let mut relative: PathBuf = base_components
+        .filter(|component| *component != Component::CurDir)
+        .map(|_| Component::ParentDir)
+        .collect();
+    relative.extend(path_components);
+    if relative.as_os_str().is_empty() {
+        relative.push(Component::CurDir);
+    }
+    relative
+}
+
+/// Serialization of a ``PathBuf`` in its portable form.
+#[cfg(feature = "use-serde")]
+pub(crate) mod serde_path {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::path::{Path, PathBuf};
+
+    pub(crate) fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::portable(path))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PathBuf, D::Error> {
+        let portable = String::deserialize(deserializer)?;
+        Ok(super::native(&portable))
+    }
+}
+
+/// Serialization of an ``Option<PathBuf>`` in its portable form.
+#[cfg(feature = "use-serde")]
+pub(crate) mod serde_option_path {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::path::PathBuf;
+
+    pub(crate) fn serialize<S: Serializer>(
+        path: &Option<PathBuf>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match path {
+            Some(path) => serializer.serialize_some(&super::portable(path)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<PathBuf>, D::Error> {
+        let portable = Option::<String>::deserialize(deserializer)?;
+        Ok(portable.map(|portable| super::native(&portable)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portable() {
+        let path: PathBuf = ["target", "preexplorer", "data", "my_id.txt"]
+            .iter()
+            .collect();
+        assert_eq!(super::portable(&path), "target/preexplorer/data/my_id.txt");
+        assert_eq!(super::portable(Path::new("/tmp/plots")), "/tmp/plots");
+    }
+
+    #[cfg(feature = "use-serde")]
+    #[test]
+    fn native() {
+        let path: PathBuf = ["target", "preexplorer", "data", "my_id.txt"]
+            .iter()
+            .collect();
+        assert_eq!(super::native("target\\preexplorer\\data\\my_id.txt"), path);
+        assert_eq!(super::native("target/preexplorer/data/my_id.txt"), path);
+        if !cfg!(windows) {
+            assert_eq!(
+                super::native("target/back\\slash.txt"),
+                Path::new("target/back\\slash.txt")
+            );
+        }
+    }
+
+    #[test]
+    fn relative_paths() {
+        assert_eq!(
+            relative(
+                Path::new("/runs/preexplorer/data/my_id.txt"),
+                Path::new("/runs/preexplorer/plots")
+            ),
+            Path::new("../data/my_id.txt")
+        );
+        assert_eq!(
+            relative(
+                Path::new("target/preexplorer/plots/my_id.png"),
+                Path::new("target/preexplorer/plots")
+            ),
+            Path::new("my_id.png")
+        );
+        assert_eq!(
+            relative(Path::new("/runs"), Path::new("/runs")),
+            Path::new(".")
+        );
+    }
+}
@@ -1,19 +1,25 @@
 // Structs
-use crate::constants::PLOT_DIR;
+use crate::constants::PLOT_FOLDER;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // Traits
 use getset::Getters;
 
+pub mod publication;
 pub mod style;
 
+pub use publication::*;
 pub use style::*;
 
 #[derive(Getters, Debug, PartialOrd, PartialEq, Clone)]
 #[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
 #[getset(get = "pub")]
 pub(crate) struct PlotConfiguration {
+    #[cfg_attr(
+        feature = "use-serde",
+        serde(with = "crate::configuration::path::serde_path")
+    )]
     path_buf: PathBuf,
     title: Option<String>,
     logx: Option<f64>,
@@ -31,6 +37,15 @@ pub(crate) struct PlotConfiguration {
     style: Style,
     dashtype: Option<usize>,
     pause: Option<f64>,
+    terminal: Option<String>,
+    #[cfg_attr(
+        feature = "use-serde",
+        serde(with = "crate::configuration::path::serde_option_path")
+    )]
+    output: Option<PathBuf>,
+    wait: bool,
+    publication: Option<Publication>,
+    relative_paths: bool,
 }
 
 impl PlotConfiguration {
@@ -39,6 +54,16 @@ impl PlotConfiguration {
         self
     }
 
+    /// Changes the directory of the plot script, keeping its file name.
+    pub(crate) fn set_dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        let mut path_buf = dir.as_ref().to_path_buf();
+        if let Some(file_name) = self.path_buf.file_name() {
+            path_buf.push(file_name);
+        }
+        self.path_buf = path_buf;
+        self
+    }
+
     pub(crate) fn opening_plot_script(&self) -> String {
         let mut gnuplot_script = String::new();
         gnuplot_script += "unset key\n";
@@ -50,39 +75,35 @@ impl PlotConfiguration {
     pub(crate) fn opening_plot_script_comparison(&self) -> String {
         let mut gnuplot_script = String::new();
 
-        match self.title() {
-            Some(title) => {
-                gnuplot_script += &format!("set title \"{}\"\n", title);
-            }
-            None => {
-                gnuplot_script += "set title \"\"\n";
-            }
+        if let Some(terminal) = self.effective_terminal() {
+            gnuplot_script += &format!("set terminal {}\n", terminal);
         }
-
-        match self.labelx() {
-            Some(labelx) => {
-                gnuplot_script += &format!("set xlabel \"{}\"\n", labelx);
-            }
-            None => {
-                gnuplot_script += "set xlabel \"\"\n";
-            }
+        if let Some(output) = self.effective_output() {
+            gnuplot_script += &format!("set output {}\n", self.script_path(&output));
         }
 
-        match self.labely() {
-            Some(labely) => {
-                gnuplot_script += &format!("set ylabel \"{}\"\n", labely);
+        // In publication mode, the title is the caption of the figure
+        match (self.title(), self.publication()) {
+            (Some(title), None) => {
+                gnuplot_script += &format!("set title {}\n", crate::gnuplot::text(title));
             }
-            None => {
-                gnuplot_script += "set ylabel \"\"\n";
+            _ => {
+                gnuplot_script += "set title \"\"\n";
             }
         }
 
-        match self.labelz() {
-            Some(labelz) => {
-                gnuplot_script += &format!("set zlabel \"{}\"\n", labelz);
-            }
-            None => {
-                gnuplot_script += "set zlabel \"\"\n";
+        for (axis, label) in [
+            ("x", self.labelx()),
+            ("y", self.labely()),
+            ("z", self.labelz()),
+        ] {
+            match label {
+                Some(label) => {
+                    gnuplot_script += &format!("set {}label {}\n", axis, self.text_string(label));
+                }
+                None => {
+                    gnuplot_script += &format!("set {}label \"\"\n", axis);
+                }
             }
         }
 
@@ -146,10 +167,35 @@ impl PlotConfiguration {
         gnuplot_script
     }
 
+    /// Label, logarithmic scale and range of the colorbar, from those of the z axis,
+    /// for plots in two dimensions colored by ``linecolor palette``.
+    pub(crate) fn colorbar_plot_script(&self) -> String {
+        let mut gnuplot_script = String::new();
+
+        if let Some(labelz) = self.labelz() {
+            gnuplot_script += &format!("set cblabel {}\n", self.text_string(labelz));
+        }
+        if let Some(logz) = &self.logz() {
+            if *logz <= 0.0 {
+                gnuplot_script += "set logscale cb\n";
+            } else {
+                gnuplot_script += &format!("set logscale cb {}\n", logz);
+            }
+        }
+        if let Some(rangez) = &self.rangez() {
+            gnuplot_script += &format!("set cbrange [{}:{}]\n", rangez.0, rangez.1);
+        }
+
+        gnuplot_script
+    }
+
     pub(crate) fn ending_plot_script(&self) -> String {
         let mut gnuplot_script = String::new();
 
-        if let Some(pause) = &self.pause() {
+        // Pausing makes no sense when plotting into a file
+        if self.effective_output().is_some() {
+            gnuplot_script += "unset output\n";
+        } else if let Some(pause) = &self.pause() {
             gnuplot_script += &format!("pause {}", pause);
         }
 
@@ -232,6 +278,35 @@ impl PlotConfiguration {
         self.pause = pause.into();
         self
     }
+    pub(crate) fn set_terminal<T>(&mut self, terminal: T) -> &mut Self
+    where
+        T: Into<Option<String>>,
+    {
+        self.terminal = terminal.into();
+        self
+    }
+    pub(crate) fn set_output<T>(&mut self, output: T) -> &mut Self
+    where
+        T: Into<Option<PathBuf>>,
+    {
+        self.output = output.into();
+        self
+    }
+    pub(crate) fn set_wait(&mut self, wait: bool) -> &mut Self {
+        self.wait = wait;
+        self
+    }
+    pub(crate) fn set_publication<T>(&mut self, publication: T) -> &mut Self
+    where
+        T: Into<Option<Publication>>,
+    {
+        self.publication = publication.into();
+        self
+    }
+    pub(crate) fn set_relative_paths(&mut self, relative_paths: bool) -> &mut Self {
+        self.relative_paths = relative_paths;
+        self
+    }
     pub(crate) fn set_id<S: AsRef<OsStr>>(&mut self, id: S) -> &mut Self {
         if let Some(extension) = self.path_buf.clone().extension() {
             self.path_buf.set_file_name(id);
@@ -245,11 +320,95 @@ impl PlotConfiguration {
     pub(crate) fn extension(&self) -> Option<&OsStr> {
         self.path_buf.extension()
     }
+    /// Output path used when plotting into a file without an explicit output.
+    ///
+    /// It is located next to the plot script, with an extension given by the terminal.
+    pub(crate) fn default_output(&self) -> PathBuf {
+        let terminal = self.effective_terminal().unwrap_or(DEFAULT_TERMINAL);
+        self.path_buf.with_extension(terminal_extension(terminal))
+    }
+    /// Gnuplot string of a path in the plot script: relative to the directory of the
+    /// plot script, if so configured, or as it is.
+    pub(crate) fn script_path(&self, path: &Path) -> String {
+        match (self.relative_paths, self.path_buf.parent()) {
+            (true, Some(dir)) => {
+                crate::gnuplot::path(&crate::configuration::path::relative(path, dir))
+            }
+            _ => crate::gnuplot::path(path),
+        }
+    }
+    /// Gnuplot string of a text in the plot script: LaTeX text in publication mode,
+    /// or shown as it is.
+    pub(crate) fn text_string(&self, text: &str) -> String {
+        match self.publication() {
+            Some(_) => latex_string(text),
+            None => crate::gnuplot::text(text),
+        }
+    }
+    /// Python expression of a path in the matplotlib script, which is next to the plot
+    /// script: relative to its directory, if so configured, or as it is.
+    pub(crate) fn python_path(&self, path: &Path) -> String {
+        match (self.relative_paths, self.path_buf.parent()) {
+            (true, Some(dir)) => format!(
+                "here({})",
+                crate::matplotlib::path(&crate::configuration::path::relative(path, dir))
+            ),
+            _ => crate::matplotlib::path(path),
+        }
+    }
+    /// Path in the Vega-Lite specification, which is next to the plot script, with ``/``
+    /// as separator: relative to its directory, if so configured, or as it is.
+    pub(crate) fn vega_path(&self, path: &Path) -> String {
+        match (self.relative_paths, self.path_buf.parent()) {
+            (true, Some(dir)) => crate::configuration::path::portable(
+                &crate::configuration::path::relative(path, dir),
+            ),
+            _ => crate::configuration::path::portable(path),
+        }
+    }
+    /// Terminal of the plot script: the chosen one or, in publication mode, its LaTeX terminal.
+    pub(crate) fn effective_terminal(&self) -> Option<&str> {
+        match (self.terminal(), self.publication()) {
+            (Some(terminal), _) => Some(terminal),
+            (None, Some(publication)) => Some(publication.terminal()),
+            (None, None) => None,
+        }
+    }
+    /// Output of the plot script: the chosen one or, in publication mode, the default one.
+    pub(crate) fn effective_output(&self) -> Option<PathBuf> {
+        match (self.output(), self.publication()) {
+            (Some(output), _) => Some(output.clone()),
+            (None, Some(_)) => Some(self.default_output()),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Terminal used when plotting into a file without an explicit terminal.
+pub(crate) const DEFAULT_TERMINAL: &str = "pngcairo";
+
+/// File extension of the output for a gnuplot terminal (which may include options).
+pub(crate) fn terminal_extension(terminal: &str) -> &str {
+    let name = terminal
+        .split_whitespace()
+        .next()
+        .unwrap_or(DEFAULT_TERMINAL);
+    match name {
+        "png" | "pngcairo" => "png",
+        "jpeg" => "jpg",
+        "svg" => "svg",
+        "pdf" | "pdfcairo" => "pdf",
+        "eps" | "epscairo" | "postscript" => "eps",
+        "dumb" => "txt",
+        "cairolatex" | "epslatex" | "tikz" | "lua" => "tex",
+        "canvas" => "html",
+        _ => name,
+    }
 }
 
 impl Default for PlotConfiguration {
     fn default() -> PlotConfiguration {
-        let mut path_buf: PathBuf = PLOT_DIR.iter().collect();
+        let mut path_buf: PathBuf = crate::root_dir().join(PLOT_FOLDER);
         path_buf.push("none");
         path_buf.set_extension("gnu");
         let title = None;
@@ -268,6 +427,11 @@ impl Default for PlotConfiguration {
         let ticsy = Some(String::from(""));
         let ticsz = Some(String::from(""));
         let pause = Some(-1.0);
+        let terminal = None;
+        let output = None;
+        let wait = false;
+        let publication = None;
+        let relative_paths = false;
 
         PlotConfiguration {
             path_buf,
@@ -287,6 +451,11 @@ impl Default for PlotConfiguration {
             ticsy,
             ticsz,
             pause,
+            terminal,
+            output,
+            wait,
+            publication,
+            relative_paths,
         }
     }
 }
@@ -301,17 +470,108 @@ mod tests {
         assert_eq!(plot_config.pause(), &Some(2.));
     }
 
+    #[test]
+    fn output() {
+        let mut plot_config = PlotConfiguration::default();
+        plot_config.set_id("output_test");
+        assert!(plot_config.ending_plot_script().starts_with("pause"));
+
+        plot_config
+            .set_terminal(String::from("svg size 800,600"))
+            .set_output(PathBuf::from("my_plot.svg"));
+        let opening = plot_config.opening_plot_script();
+        assert!(opening.contains("set terminal svg size 800,600\n"));
+        assert!(opening.contains("set output \"my_plot.svg\"\n"));
+        assert_eq!(plot_config.ending_plot_script(), "unset output\n");
+    }
+
+    #[test]
+    fn default_output() {
+        let mut plot_config = PlotConfiguration::default();
+        plot_config.set_id("output_test");
+        assert_eq!(
+            plot_config.default_output().file_name().unwrap().to_str(),
+            Some("output_test.png")
+        );
+        plot_config.set_terminal(String::from("dumb size 80,25"));
+        assert_eq!(
+            plot_config.default_output().file_name().unwrap().to_str(),
+            Some("output_test.txt")
+        );
+    }
+
+    #[test]
+    fn publication() {
+        let mut plot_config = PlotConfiguration::default();
+        plot_config
+            .set_id("publication_test")
+            .set_title(String::from("Error"))
+            .set_labely(String::from("$\\epsilon_n$"))
+            .set_publication(Publication::Tikz);
+        let gnuplot_script = plot_config.opening_plot_script_comparison();
+
+        assert!(gnuplot_script.starts_with("set terminal tikz color\nset output "));
+        assert!(gnuplot_script.contains("publication_test.tex\"\nset title \"\"\n"));
+        assert!(gnuplot_script.contains("set ylabel \"$\\\\epsilon_n$\"\n"));
+        assert_eq!(plot_config.ending_plot_script(), "unset output\n");
+    }
+
+    #[test]
+    fn relative_paths() {
+        let mut plot_config = PlotConfiguration::default();
+        plot_config
+            .set_dir("runs/plots")
+            .set_id("relative_test")
+            .set_output(PathBuf::from("runs/plots/relative_test.png"));
+        assert_eq!(
+            plot_config.script_path(Path::new("runs/data/relative_test.txt")),
+            "\"runs/data/relative_test.txt\""
+        );
+
+        plot_config.set_relative_paths(true);
+        assert_eq!(
+            plot_config.script_path(Path::new("runs/data/relative_test.txt")),
+            "\"../data/relative_test.txt\""
+        );
+        assert_eq!(
+            plot_config.python_path(Path::new("runs/data/relative_test.txt")),
+            "here(\"../data/relative_test.txt\")"
+        );
+        assert_eq!(
+            plot_config.vega_path(Path::new("runs/data/relative_test.json")),
+            "../data/relative_test.json"
+        );
+        assert!(plot_config
+            .opening_plot_script()
+            .contains("set output \"relative_test.png\"\n"));
+    }
+
     #[cfg(feature = "use-serde")]
     #[test]
     fn serde() -> Result<(), ron::Error> {
-        // Serializing
-        let plot_config = PlotConfiguration::default();
+        let mut plot_config = PlotConfiguration::default();
+        plot_config
+            .set_dir(["runs", "plots"].iter().collect::<PathBuf>())
+            .set_id("serde_test")
+            .set_output(
+                ["runs", "plots", "serde_test.png"]
+                    .iter()
+                    .collect::<PathBuf>(),
+            )
+            .set_relative_paths(true);
+
+        // Serializing, with the same paths on every platform
         let string = ron::ser::to_string(&plot_config)?;
-        assert_eq!(string, "(path_buf:\"target\\\\preexplorer\\\\plots\\\\none.gnu\",title:None,logx:None,logy:None,labelx:None,labely:None,rangex:None,rangey:None,ticsx:Some(\"\"),ticsy:Some(\"\"),style:Default,dashtype:None,pause:Some(-1))");
+        assert!(string.starts_with("(path_buf:\"runs/plots/serde_test.gnu\",title:None,"));
+        assert!(string.contains("output:Some(\"runs/plots/serde_test.png\")"));
+        assert!(string.ends_with("relative_paths:true)"));
+
         // Deserializing
-        let string = "(path_buf:\"target\\\\preexplorer\\\\plots\\\\none.gnu\",title:None,logx:None,logy:None,labelx:None,labely:None,rangex:None,rangey:None,ticsx:Some(\"\"),ticsy:Some(\"\"),style:Default,dashtype:None,pause:Some(-1))";
-        let style: PlotConfiguration = ron::de::from_str(string)?;
-        assert_eq!(style, PlotConfiguration::default());
+        let deserialized: PlotConfiguration = ron::de::from_str(&string)?;
+        assert_eq!(deserialized, plot_config);
+        let windows_string = string.replace("runs/plots/", "runs\\\\plots\\\\");
+        let deserialized: PlotConfiguration = ron::de::from_str(&windows_string)?;
+        assert_eq!(deserialized, plot_config);
 
         Ok(())
     }
@@ -0,0 +1,187 @@
+//! Publication modes: gnuplot terminals whose text is typeset by LaTeX.
+
+// Structs
+use std::path::Path;
+
+// Traits
+use core::convert::TryFrom;
+use std::str::FromStr;
+use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString, EnumVariantNames, IntoStaticStr};
+
+/// LaTeX terminals of gnuplot, for figures that drop straight into a document.
+///
+/// Text (title, labels and tics) is left to LaTeX, so it matches the fonts of the
+/// document and can include math, like ``"$\\alpha^2$"``.
+///
+/// # Remarks
+///
+/// There are ``From<>`` implementations for ease of use.
+#[derive(
+    Debug,
+    PartialOrd,
+    PartialEq,
+    Clone,
+    Copy,
+    Display,
+    AsRefStr,
+    EnumCount,
+    EnumIter,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    Default,
+)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Publication {
+    /// Graphics in a pdf file, text in a tex file "cairolatex" or 0
+    #[default]
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "cairolatex")]
+    Cairolatex,
+    /// Graphics in an eps file, text in a tex file "epslatex" or 1
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "epslatex")]
+    Epslatex,
+    /// Everything drawn by TikZ, in a single tex file "tikz" or 2
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "tikz")]
+    Tikz,
+}
+
+impl Publication {
+    /// Gnuplot terminal, with options.
+    pub fn terminal(&self) -> &'static str {
+        match self {
+            Publication::Cairolatex => "cairolatex pdf color",
+            Publication::Epslatex => "epslatex color",
+            Publication::Tikz => "tikz color",
+        }
+    }
+
+    /// LaTeX packages needed to include the figure.
+    pub fn packages(&self) -> &'static [&'static str] {
+        match self {
+            Publication::Cairolatex | Publication::Epslatex => &["graphicx", "color"],
+            Publication::Tikz => &["gnuplot-lua-tikz"],
+        }
+    }
+
+    /// LaTeX snippet of a figure environment including the output of gnuplot,
+    /// with the title as caption and labelled after ``id``.
+    pub(crate) fn figure_snippet(&self, output: &Path, title: Option<&str>, id: &str) -> String {
+        let input = crate::configuration::path::portable(output);
+        let packages: Vec<String> = self
+            .packages()
+            .iter()
+            .map(|package| format!("\\usepackage{{{}}}", package))
+            .collect();
+
+        let mut latex_snippet = String::new();
+        latex_snippet += &format!(
+            "% Figure generated by preexplorer, drawn by gnuplot with the {} terminal.\n",
+            self
+        );
+        latex_snippet += &format!("% It needs {} in the preamble.\n", packages.join(" and "));
+        latex_snippet += "\\begin{figure}\n";
+        latex_snippet += "  \\centering\n";
+        latex_snippet += &format!("  \\input{{{}}}\n", input);
+        if let Some(title) = title {
+            latex_snippet += &format!("  \\caption{{{}}}\n", latex_text(title));
+        }
+        latex_snippet += &format!("  \\label{{fig:{}}}\n", id);
+        latex_snippet += "\\end{figure}\n";
+
+        latex_snippet
+    }
+}
+
+impl TryFrom<&str> for Publication {
+    type Error = strum::ParseError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Publication::from_str(s)
+    }
+}
+
+impl From<u32> for Publication {
+    fn from(s: u32) -> Self {
+        match s {
+            0 => Publication::Cairolatex,
+            1 => Publication::Epslatex,
+            2 => Publication::Tikz,
+            _ => Publication::Cairolatex,
+        }
+    }
+}
+
+/// Text for LaTeX: special characters outside math (between ``$``) are escaped,
+/// unless they are already escaped. Commands, like ``\textbf{...}``, are kept.
+pub(crate) fn latex_text(text: &str) -> String {
+    let mut latex = String::with_capacity(text.len());
+    let mut math = false;
+    let mut escaped = false;
+    for c in text.chars() {
+        match c {
+            _ if escaped => {
+                escaped = false;
+                latex.push(c);
+            }
+            '\\' => {
+                escaped = true;
+                latex.push(c);
+            }
+            '$' => {
+                math = !math;
+                latex.push(c);
+            }
+            '_' | '%' | '&' | '#' if !math => {
+                latex.push('\\');
+                latex.push(c);
+            }
+            '^' if !math => latex += "\\^{}",
+            '~' if !math => latex += "\\~{}",
+            _ => latex.push(c),
+        }
+    }
+    latex
+}
+
+/// Gnuplot string of LaTeX text.
+pub(crate) fn latex_string(text: &str) -> String {
+    crate::gnuplot::quote(&latex_text(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let publication = Publication::from_str("TikZ").unwrap();
+        assert_eq!(publication, Publication::Tikz);
+        assert_eq!(Publication::default().terminal(), "cairolatex pdf color");
+    }
+
+    #[test]
+    fn latex() {
+        assert_eq!(latex_text("run_1: 50% & more"), "run\\_1: 50\\% \\& more");
+        assert_eq!(latex_text("error $\\epsilon_n^2$"), "error $\\epsilon_n^2$");
+        assert_eq!(latex_text("already \\_ escaped"), "already \\_ escaped");
+        assert_eq!(latex_string("Bob's $\\alpha$"), "\"Bob's $\\\\alpha$\"");
+    }
+
+    #[test]
+    fn figure_snippet() {
+        let snippet = Publication::Tikz.figure_snippet(
+            Path::new("plots/my_plot.tex"),
+            Some("Error of run_1"),
+            "my_plot",
+        );
+        assert_eq!(
+            snippet,
+            "% Figure generated by preexplorer, drawn by gnuplot with the tikz terminal.\n\
+             % It needs \\usepackage{gnuplot-lua-tikz} in the preamble.\n\
+             \\begin{figure}\n  \\centering\n  \\input{plots/my_plot.tex}\n\
+             \x20 \\caption{Error of run\\_1}\n  \\label{fig:my_plot}\n\\end{figure}\n"
+        );
+    }
+}
@@ -22,10 +22,12 @@ use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString, EnumVariantNames
     EnumString,
     EnumVariantNames,
     IntoStaticStr,
+    Default,
 )]
 #[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Style {
     /// Default "default" or 0
+    #[default]
     #[strum(ascii_case_insensitive)]
     #[strum(serialize = "lines")]
     Default,
@@ -92,12 +94,6 @@ impl From<u32> for Style {
     }
 }
 
-impl Default for Style {
-    fn default() -> Self {
-        Style::Default
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
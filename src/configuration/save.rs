@@ -1,18 +1,27 @@
-use crate::constants::DATA_DIR;
+use crate::constants::DATA_FOLDER;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // Traits
 use getset::Getters;
 
+pub mod format;
+
+pub use format::*;
+
 #[derive(Getters, Debug, PartialOrd, PartialEq, Clone)]
 #[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
 #[getset(get = "pub")]
 pub(crate) struct SaveConfiguration {
+    #[cfg_attr(
+        feature = "use-serde",
+        serde(with = "crate::configuration::path::serde_path")
+    )]
     path_buf: PathBuf,
     header: bool,
     date: chrono::DateTime<chrono::Local>,
     id: Option<String>,
+    format: DataFormat,
 }
 
 impl SaveConfiguration {
@@ -20,6 +29,15 @@ impl SaveConfiguration {
         self.path_buf.set_extension(extension);
         self
     }
+    /// Changes the directory of the data file, keeping its file name.
+    pub(crate) fn set_dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        let mut path_buf = dir.as_ref().to_path_buf();
+        if let Some(file_name) = self.path_buf.file_name() {
+            path_buf.push(file_name);
+        }
+        self.path_buf = path_buf;
+        self
+    }
     pub(crate) fn set_header(&mut self, header: bool) -> &mut Self {
         self.header = header;
         self
@@ -28,6 +46,12 @@ impl SaveConfiguration {
         self.date = date;
         self
     }
+    /// Changes the format of the data file, together with its extension.
+    pub(crate) fn set_format(&mut self, format: DataFormat) -> &mut Self {
+        self.path_buf.set_extension(format.extension());
+        self.format = format;
+        self
+    }
     pub(crate) fn set_id(&mut self, id: String) -> &mut Self {
         if let Some(extension) = self.path_buf.clone().extension() {
             self.path_buf.set_file_name(&id);
@@ -53,18 +77,43 @@ impl SaveConfiguration {
 
 impl Default for SaveConfiguration {
     fn default() -> Self {
-        let mut path_buf: PathBuf = DATA_DIR.iter().collect();
+        let mut path_buf: PathBuf = crate::root_dir().join(DATA_FOLDER);
         path_buf.push("none");
         path_buf.set_extension("txt");
         let header = true;
-        let date = chrono::Local::now();
+        let date = crate::run::date().unwrap_or_else(chrono::Local::now);
         let id = None;
+        let format = DataFormat::default();
 
         SaveConfiguration {
             path_buf,
             header,
             date,
             id,
+            format,
         }
     }
 }
+
+#[cfg(all(test, feature = "use-serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() -> Result<(), ron::Error> {
+        let mut save_config = SaveConfiguration::default();
+        save_config
+            .set_dir(["runs", "data"].iter().collect::<PathBuf>())
+            .set_id(String::from("serde_test"));
+
+        // Serializing, with the same path on every platform
+        let string = ron::ser::to_string(&save_config)?;
+        assert!(string.starts_with("(path_buf:\"runs/data/serde_test.txt\",header:true,"));
+
+        // Deserializing
+        let deserialized: SaveConfiguration = ron::de::from_str(&string)?;
+        assert_eq!(deserialized, save_config);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,643 @@
+//! Formats for saving data.
+
+// Structs
+use crate::errors::PreexplorerError;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+// Traits
+use crate::traits::Saveable;
+use core::convert::TryFrom;
+use std::io::Write;
+use std::str::FromStr;
+use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString, EnumVariantNames, IntoStaticStr};
+
+/// Formats in which data files can be saved.
+///
+/// # Remarks
+///
+/// Gnuplot can not read JSON, so the JSON lines (or JSON) file is saved next to the
+/// data file (with extension ``jsonl``, or ``json``), which is still written as gnuplot text.
+///
+/// CSV and JSON only keep the data: the title, id and date are only written in gnuplot text.
+/// Blocks of rows, like the rows of a grid, are numbered in an additional last column, ``block``.
+/// In CSV, they are also separated by a blank line, which gnuplot needs to read grids.
+///
+/// There are ``From<>`` implementations for ease of use.
+#[derive(
+    Debug,
+    PartialOrd,
+    PartialEq,
+    Clone,
+    Copy,
+    Display,
+    AsRefStr,
+    EnumCount,
+    EnumIter,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    Default,
+)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataFormat {
+    /// Whitespace separated columns "gnuplot" or "txt" or 0
+    #[default]
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "gnuplot", serialize = "txt")]
+    Gnuplot,
+    /// Comma separated columns with a header row of column names "csv" or 1
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "csv")]
+    Csv,
+    /// One JSON object per row, with column names as keys "jsonl" or "jsonlines" or 2
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "jsonl", serialize = "jsonlines")]
+    JsonLines,
+    /// One JSON array of objects, with column names as keys "json" or 3
+    ///
+    /// Browsers, and so Vega-Lite specifications, can read it directly.
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "json")]
+    Json,
+}
+
+impl DataFormat {
+    /// Extension of the data file read by gnuplot.
+    pub(crate) fn extension(&self) -> &'static str {
+        match self {
+            DataFormat::Csv => "csv",
+            DataFormat::Gnuplot | DataFormat::JsonLines | DataFormat::Json => "txt",
+        }
+    }
+
+    /// Separator of columns in the data file read by gnuplot, if it is not whitespace.
+    pub(crate) fn separator(&self) -> Option<char> {
+        match self {
+            DataFormat::Csv => Some(','),
+            DataFormat::Gnuplot | DataFormat::JsonLines | DataFormat::Json => None,
+        }
+    }
+
+    /// Extension of the JSON file saved next to the data file, if any.
+    pub(crate) fn json_extension(&self) -> Option<&'static str> {
+        match self {
+            DataFormat::JsonLines => Some("jsonl"),
+            DataFormat::Json => Some("json"),
+            DataFormat::Gnuplot | DataFormat::Csv => None,
+        }
+    }
+
+    /// Paths of the files written by ``write`` in ``path``.
+    pub(crate) fn paths(&self, path: &Path) -> Vec<PathBuf> {
+        let mut paths = vec![path.to_path_buf()];
+        if let Some(extension) = self.json_extension() {
+            paths.push(path.with_extension(extension));
+        }
+        paths
+    }
+
+    /// Gnuplot commands to read data files in this format.
+    pub(crate) fn plot_script(&self) -> String {
+        match self.separator() {
+            Some(_) => String::from("set datafile separator comma\n"),
+            None => String::new(),
+        }
+    }
+
+    /// Option of ``set table`` so that gnuplot tables can be read back in this format.
+    pub(crate) fn table_separator(&self) -> &'static str {
+        match self.separator() {
+            Some(_) => " separator comma",
+            None => "",
+        }
+    }
+
+    /// Writes the data of ``item`` in ``path``, creating its directory if needed.
+    /// The data is streamed through a buffer, without building it in memory.
+    pub(crate) fn write<S>(&self, item: &S, path: &Path) -> Result<(), PreexplorerError>
+    where
+        S: Saveable + ?Sized,
+    {
+        let mut writer = DataWriter::create(*self, item, path)?;
+        item.write_data(&mut writer)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Data of ``item`` as a JSON array of objects, with column names as keys.
+pub(crate) fn json_values<S>(item: &S) -> String
+where
+    S: Saveable + ?Sized,
+{
+    let mut json = Vec::new();
+    let mut converter = Converter::new(&mut json, item.column_names(), Target::Json);
+    item.write_data(&mut converter)
+        .and_then(|_| converter.finish())
+        .expect("Writing into memory should not fail.");
+    drop(converter);
+    String::from_utf8_lossy(&json).trim_end().to_string()
+}
+
+impl TryFrom<&str> for DataFormat {
+    type Error = strum::ParseError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        DataFormat::from_str(s)
+    }
+}
+
+impl From<u32> for DataFormat {
+    fn from(s: u32) -> Self {
+        match s {
+            0 => DataFormat::Gnuplot,
+            1 => DataFormat::Csv,
+            2 => DataFormat::JsonLines,
+            3 => DataFormat::Json,
+            _ => DataFormat::Gnuplot,
+        }
+    }
+}
+
+/// Names of all columns, completing the given ones with ``column_4``, ``column_5``, etc.
+pub(crate) fn all_column_names(column_names: &[String], columns: usize) -> Vec<String> {
+    (0..columns.max(column_names.len()))
+        .map(|i| match column_names.get(i) {
+            Some(name) => name.clone(),
+            None => format!("column_{}", i + 1),
+        })
+        .collect()
+}
+
+/// Writer of the data files of a struct, in a given format, from gnuplot text.
+pub(crate) struct DataWriter {
+    data: Converter<BufWriter<File>>,
+    json: Option<Converter<BufWriter<File>>>,
+}
+
+impl DataWriter {
+    /// Creates the data files in ``path``, and the directory if needed, and writes the header.
+    pub(crate) fn create<S>(
+        format: DataFormat,
+        item: &S,
+        path: &Path,
+    ) -> Result<DataWriter, PreexplorerError>
+    where
+        S: Saveable + ?Sized,
+    {
+        if let Some(data_dir_path) = path.parent() {
+            std::fs::create_dir_all(data_dir_path)?;
+        }
+
+        let target = match format {
+            DataFormat::Gnuplot | DataFormat::JsonLines | DataFormat::Json => Target::Gnuplot,
+            DataFormat::Csv => Target::Csv,
+        };
+        let file = BufWriter::new(File::create(path)?);
+        let data = Converter::new(file, item.column_names(), target);
+
+        let json = match format.json_extension() {
+            Some(extension) => {
+                let json_path = path.with_extension(extension);
+                let file = BufWriter::new(File::create(&json_path)?);
+                let target = match format {
+                    DataFormat::Json => Target::Json,
+                    _ => Target::JsonLines,
+                };
+                Some(Converter::new(file, item.column_names(), target))
+            }
+            None => None,
+        };
+
+        let mut writer = DataWriter { data, json };
+        if item.header() {
+            if let Some(title) = item.title() {
+                writeln!(writer, "# {}", title)?;
+            }
+            if let Some(id) = item.id() {
+                writeln!(writer, "# {}", id)?;
+            }
+            writeln!(writer, "# {}", item.date())?;
+        }
+        Ok(writer)
+    }
+
+    /// Converts the last line, if it does not end with a new line, and flushes.
+    pub(crate) fn finish(&mut self) -> std::io::Result<()> {
+        self.data.finish()?;
+        if let Some(json) = &mut self.json {
+            json.finish()?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for DataWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.data.write_all(buf)?;
+        if let Some(json) = &mut self.json {
+            json.write_all(buf)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.data.flush()?;
+        if let Some(json) = &mut self.json {
+            json.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Target of a [Converter].
+enum Target {
+    /// Keeps everything as it is.
+    Gnuplot,
+    /// Adds a header row of column names before the first row and skips comments.
+    /// Blank lines are kept, since gnuplot reads grids in blocks separated by them.
+    Csv,
+    /// Skips comments and blank lines.
+    JsonLines,
+    /// Like ``JsonLines``, inside an array, which is closed when finishing.
+    Json,
+}
+
+/// Converts gnuplot text to other formats line by line, as it is written.
+///
+/// Fields are separated by whitespace, unless they are quoted as gnuplot strings.
+/// Comments are skipped and blocks of rows, separated by blank lines, are numbered
+/// in an additional last column, ``block``.
+struct Converter<W: Write> {
+    writer: W,
+    column_names: Vec<String>,
+    target: Target,
+    line: Vec<u8>,
+    rows: usize,
+    block: usize,
+    blank_lines: usize,
+}
+
+impl<W: Write> Converter<W> {
+    fn new(writer: W, column_names: Vec<String>, target: Target) -> Self {
+        Converter {
+            writer,
+            column_names,
+            target,
+            line: Vec::new(),
+            rows: 0,
+            block: 0,
+            blank_lines: 0,
+        }
+    }
+
+    /// Converts the last line, if it does not end with a new line, and flushes.
+    fn finish(&mut self) -> std::io::Result<()> {
+        if !self.line.is_empty() {
+            let line = std::mem::take(&mut self.line);
+            self.convert(&String::from_utf8_lossy(&line))?;
+        }
+        if let Target::Json = self.target {
+            match self.rows {
+                0 => writeln!(self.writer, "[]")?,
+                _ => writeln!(self.writer, "\n]")?,
+            }
+        }
+        self.writer.flush()
+    }
+
+    fn convert(&mut self, line: &str) -> std::io::Result<()> {
+        if let Target::Gnuplot = self.target {
+            return writeln!(self.writer, "{}", line);
+        }
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            return Ok(());
+        }
+        if trimmed.is_empty() {
+            if self.rows > 0 {
+                self.blank_lines += 1;
+            }
+            return Ok(());
+        }
+
+        let blank_lines = std::mem::take(&mut self.blank_lines);
+        if blank_lines > 0 {
+            self.block += 1;
+        }
+        let fields = split_fields(trimmed);
+        let mut names = all_column_names(&self.column_names, fields.len());
+        names.push(String::from("block"));
+        self.rows += 1;
+        match self.target {
+            Target::Gnuplot => Ok(()),
+            Target::Csv => {
+                if self.rows == 1 {
+                    let names: Vec<String> = names.iter().map(|name| csv_field(name)).collect();
+                    writeln!(self.writer, "{}", names.join(","))?;
+                }
+                for _ in 0..blank_lines {
+                    writeln!(self.writer)?;
+                }
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(field, quoted)| match (field.is_empty(), quoted) {
+                        (true, true) => String::from("\"\""),
+                        _ => csv_field(field),
+                    })
+                    .collect();
+                writeln!(self.writer, "{},{}", fields.join(","), self.block)
+            }
+            Target::JsonLines | Target::Json => {
+                let mut entries: Vec<String> = names
+                    .iter()
+                    .zip(&fields)
+                    .map(|(name, (field, quoted))| {
+                        format!("{}:{}", json_string(name), json_value(field, *quoted))
+                    })
+                    .collect();
+                entries.push(format!("\"block\":{}", self.block));
+                match self.target {
+                    Target::Json => {
+                        let opening = if self.rows == 1 { "[\n" } else { ",\n" };
+                        write!(self.writer, "{}{{{}}}", opening, entries.join(","))
+                    }
+                    _ => writeln!(self.writer, "{{{}}}", entries.join(",")),
+                }
+            }
+        }
+    }
+}
+
+impl<W: Write> Write for Converter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Target::Gnuplot = self.target {
+            return self.writer.write(buf);
+        }
+        for &byte in buf {
+            if byte == b'\n' {
+                let line = std::mem::take(&mut self.line);
+                self.convert(&String::from_utf8_lossy(&line))?;
+                self.line = line;
+                self.line.clear();
+            } else {
+                self.line.push(byte);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Splits a row of gnuplot text into its fields, telling which ones were quoted.
+///
+/// Quoted fields are read as gnuplot reads data files, up to the next double quote
+/// and without escapes, as written by ``crate::gnuplot::datafile_string``.
+fn split_fields(row: &str) -> Vec<(String, bool)> {
+    let mut fields = Vec::new();
+    let mut chars = row.trim().chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut field = String::new();
+        if c != '"' {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                field.push(c);
+                chars.next();
+            }
+            fields.push((field, false));
+            continue;
+        }
+        chars.next();
+        for c in chars.by_ref() {
+            if c == '"' {
+                break;
+            }
+            field.push(c);
+        }
+        fields.push((field, true));
+    }
+    fields
+}
+
+/// Splits a row of gnuplot text into its fields, unquoting quoted fields.
+pub(crate) fn split_gnuplot(row: &str) -> Vec<String> {
+    split_fields(row)
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect()
+}
+
+/// Quotes a CSV field if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) || field.trim() != field {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Splits a CSV row into its fields.
+pub(crate) fn split_csv(row: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            c => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+/// Writes unquoted numbers as they are and everything else as a JSON string.
+fn json_value(field: &str, quoted: bool) -> String {
+    if !quoted && is_json_number(field) {
+        field.to_string()
+    } else {
+        json_string(field)
+    }
+}
+
+/// Checks the JSON grammar for numbers: ``-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?``.
+fn is_json_number(s: &str) -> bool {
+    fn digits(s: &[u8]) -> usize {
+        s.iter().take_while(|c| c.is_ascii_digit()).count()
+    }
+    let s = s.strip_prefix('-').unwrap_or(s).as_bytes();
+    let integer = digits(s);
+    if integer == 0 || (integer > 1 && s[0] == b'0') {
+        return false;
+    }
+    let mut rest = &s[integer..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let fraction_digits = digits(fraction);
+        if fraction_digits == 0 {
+            return false;
+        }
+        rest = &fraction[fraction_digits..];
+    }
+    if let Some(exponent) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or_else(|| exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+        let exponent_digits = digits(exponent);
+        if exponent_digits == 0 {
+            return false;
+        }
+        rest = &exponent[exponent_digits..];
+    }
+    rest.is_empty()
+}
+
+/// Quotes a string as a JSON string literal.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(target: Target, data: &str, column_names: &[&str]) -> String {
+        let column_names = column_names.iter().map(|name| name.to_string()).collect();
+        let mut converted = Vec::new();
+        let mut converter = Converter::new(&mut converted, column_names, target);
+        converter.write_all(data.as_bytes()).unwrap();
+        converter.finish().unwrap();
+        drop(converter);
+        String::from_utf8(converted).unwrap()
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(DataFormat::from_str("CSV").unwrap(), DataFormat::Csv);
+        assert_eq!(
+            DataFormat::from_str("jsonl").unwrap(),
+            DataFormat::JsonLines
+        );
+        assert_eq!(DataFormat::default(), DataFormat::Gnuplot);
+    }
+
+    #[test]
+    fn csv() {
+        let data = "# Title\n0\t1\t2\n1\t3\t4\n\n2\t5\t6";
+        let csv = convert(Target::Csv, data, &["x", "y"]);
+        assert_eq!(csv, "x,y,column_3,block\n0,1,2,0\n1,3,4,0\n\n2,5,6,1\n");
+        let csv = convert(Target::Csv, "0\t\"a b, 'c'\"\t\"\"\n", &["x"]);
+        assert_eq!(csv, "x,column_2,column_3,block\n0,\"a b, 'c'\",\"\",0\n");
+        assert_eq!(split_csv("1, \"a,\"\"b\"\"\",3"), vec!["1", "a,\"b\"", "3"]);
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(
+            split_fields(" 1\t\"a b\"  \"c\\n`\" "),
+            vec![
+                (String::from("1"), false),
+                (String::from("a b"), true),
+                (String::from("c\\n`"), true)
+            ]
+        );
+        let label = "tab\tand \"quotes\"";
+        assert_eq!(
+            split_gnuplot(&format!("0 {}", crate::gnuplot::datafile_string(label))),
+            vec!["0", "tab\tand 'quotes'"]
+        );
+    }
+
+    #[test]
+    fn json_strings() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(json_string("a \"quote\"\\\n"), "\"a \\\"quote\\\"\\\\\\n\"");
+        assert_eq!(json_string("\r\u{1}"), "\"\\r\\u0001\"");
+    }
+
+    #[test]
+    fn json_lines() {
+        let json = convert(Target::JsonLines, "0\t1.5\n\n1\tNaN\n", &["index", "value"]);
+        assert_eq!(
+            json,
+            "{\"index\":0,\"value\":1.5,\"block\":0}\n{\"index\":1,\"value\":\"NaN\",\"block\":1}\n"
+        );
+        assert!(is_json_number("-1.5e-3"));
+        assert!(!is_json_number(".5"));
+        assert!(!is_json_number("01"));
+        assert!(!is_json_number("inf"));
+        assert_eq!(json_value("", true), "\"\"");
+        assert_eq!(json_value("1", true), "\"1\"");
+    }
+
+    #[test]
+    fn json() {
+        let json = convert(Target::Json, "# Title\n0\t1.5\n\n1\t\"a b\"\n", &["x"]);
+        assert_eq!(
+            json,
+            "[\n{\"x\":0,\"column_2\":1.5,\"block\":0},\n{\"x\":1,\"column_2\":\"a b\",\"block\":1}\n]\n"
+        );
+        assert_eq!(convert(Target::Json, "", &[]), "[]\n");
+        assert_eq!(
+            json_values(&crate::Sequence::new(vec![3])),
+            "[\n{\"index\":0,\"value\":3,\"block\":0}\n]"
+        );
+    }
+
+    #[test]
+    fn save() -> Result<(), PreexplorerError> {
+        use crate::prelude::*;
+        let root = std::env::temp_dir().join("preexplorer_data_format");
+        let mut seq = pre::Sequence::new(vec![0.5, 1.5]);
+        seq.set_id("data_format_test").set_root_dir(&root);
+
+        seq.set_data_format(DataFormat::Csv).unwrap().save()?;
+        let csv = std::fs::read_to_string(seq.data_path())?;
+        assert_eq!(csv, "index,value,block\n0,0.5,0\n1,1.5,0\n");
+        let loaded = pre::Sequence::<f64>::load(seq.data_path())?;
+        assert_eq!(loaded.data_format(), DataFormat::Csv);
+        assert_eq!(loaded.plotable_data(), seq.plotable_data());
+        assert!(seq.plot_script().contains("set datafile separator comma\n"));
+
+        seq.set_data_format(DataFormat::JsonLines).unwrap().save()?;
+        let json = std::fs::read_to_string(seq.data_path().with_extension("jsonl"))?;
+        assert_eq!(
+            json,
+            "{\"index\":0,\"value\":0.5,\"block\":0}\n{\"index\":1,\"value\":1.5,\"block\":0}\n"
+        );
+        assert_eq!(
+            pre::Sequence::<f64>::load(seq.data_path())?.data_format(),
+            DataFormat::Gnuplot
+        );
+        Ok(())
+    }
+}
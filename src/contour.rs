@@ -1,11 +1,19 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
+use core::ops::Add;
+
+pub mod comparison;
+
+pub use comparison::Contours;
 
 /// 3-dimensional surface by plotting constant z slices, called contours, on a 2-dimensional format.
 ///
 /// Check out [`Contour` documentation] of gnuplot for more options.
 ///
+/// # Remarks
+///
+/// Vega-Lite has no contours: its specifications draw the values as a heatmap.
 ///
 /// # Examples
 ///
@@ -17,6 +25,31 @@ use core::fmt::Display;
 /// pre::Contour::new(0..10, 0..5, values).plot("my_identifier").unwrap();
 /// ```
 ///
+/// Only labeled isolines, at given levels.
+/// ```no_run
+/// use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// let values = iproduct!(0..10, 0..5).map(|(x, y)| x * y);
+/// pre::Contour::new(0..10, 0..5, values)
+///     .set_levels(vec![5, 10, 20])
+///     .set_isoline_labels(true)
+///     .set_isolines_only(true)
+///     .plot("my_identifier")
+///     .unwrap();
+/// ```
+///
+/// Compare [Contour] structs.
+/// ```no_run
+/// use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// pre::Contours::new(vec![
+///     pre::Contour::new(0..10, 0..5, iproduct!(0..10, 0..5).map(|(x, y)| x + y)),
+///     pre::Contour::new(0..10, 0..5, iproduct!(0..10, 0..5).map(|(x, y)| x * y)),
+///     ])
+///     .plot("my_identifier").unwrap();
+/// ```
+///
+/// [Contour]: struct.Contour.html
 /// [`Contour` documentation]: http://gnuplot.info/docs_5.5/loc10902.html
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,6 +62,7 @@ where
     xs: Vec<T>,
     ys: Vec<S>,
     values: Vec<U>,
+    isolines: Isolines,
     config: crate::configuration::Configuration,
 }
 
@@ -79,11 +113,85 @@ where
             xs,
             ys,
             values,
+            isolines: Isolines::default(),
             config,
         }
     }
+
+    /// Sets the values of the contours.
+    ///
+    /// # Default
+    ///
+    /// The levels are chosen by gnuplot, or matplotlib.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use itertools::iproduct;
+    /// use preexplorer::prelude::*;
+    /// let values = iproduct!(0..10, 0..5).map(|(x, y)| x + y);
+    /// let mut contour = pre::Contour::new(0..10, 0..5, values);
+    /// contour.set_levels(vec![2.5, 5., 7.5]);
+    /// ```
+    pub fn set_levels<I, V>(&mut self, levels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<f64>,
+    {
+        self.isolines.levels = Some(Levels::Values(
+            levels.into_iter().map(|level| level.into()).collect(),
+        ));
+        self
+    }
+
+    /// Sets the number of contours, at levels evenly spaced and chosen by gnuplot,
+    /// which might draw a few more or less to get round values.
+    ///
+    /// # Default
+    ///
+    /// The number of levels is chosen by gnuplot, or matplotlib.
+    pub fn set_number_of_levels(&mut self, number: usize) -> &mut Self {
+        self.isolines.levels = Some(Levels::Number(number));
+        self
+    }
+
+    /// Choose whether each contour is labeled by its value on the plot,
+    /// instead of in the key.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``.
+    pub fn set_isoline_labels(&mut self, labels: bool) -> &mut Self {
+        self.isolines.labels = labels;
+        self
+    }
+
+    /// Choose whether only the contours are drawn, seen from above,
+    /// instead of also the surface in three dimensions.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: the surface is drawn.
+    pub fn set_isolines_only(&mut self, only: bool) -> &mut Self {
+        self.isolines.only = only;
+        self
+    }
 }
 
+impl<T, S, U> Add for Contour<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    type Output = crate::Contours<T, S, U>;
+
+    fn add(self, other: crate::Contour<T, S, U>) -> crate::Contours<T, S, U> {
+        let mut cmp = self.into();
+        cmp += other;
+        cmp
+    }
+}
 
 impl<T, S, U> Configurable for Contour<T, S, U>
 where
@@ -105,25 +213,32 @@ where
     S: Display + Clone,
     U: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.xs.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
         for i in 0..self.xs.len() {
             for j in 0..self.ys.len() {
-                plotable_data.push_str(&format!(
-                    "{}\t{}\t{}\n",
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}",
                     self.xs[i],
                     self.ys[j],
                     self.values[i * self.ys.len() + j]
-                ));
+                )?;
             }
-            plotable_data.push_str("\n");
+            writeln!(writer)?;
         }
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["x", "y", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -136,15 +251,66 @@ where
     fn plot_script(&self) -> String {
         let mut gnuplot_script = self.opening_plot_script();
 
-        gnuplot_script += "set surface # unset to plot only isolines\n";
-        gnuplot_script += "set contour\n";
-        gnuplot_script += &format!("splot {:?} using 1:2:3 with {}\n", self.data_path(), self.style());
+        gnuplot_script += &self.isolines.plot_script();
+        gnuplot_script += &format!(
+            "splot {}\n",
+            self.isolines.plot_element(
+                &self.config.script_path(self.data_path()),
+                self.style(),
+                None,
+                None
+            )
+        );
         gnuplot_script += &self.ending_plot_script();
 
         gnuplot_script
     }
 }
 
+impl<T, S, U> Matplotlib for Contour<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        python_script += &format!(
+            "contour(ax, table({}){})\n",
+            self.config.python_path(self.data_path()),
+            self.isolines.matplotlib_arguments()
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S, U> VegaLite for Contour<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        // Vega-Lite has no contours: the surface is drawn as a heatmap
+        let view = format!(
+            "{},\n{}",
+            crate::vega::size(),
+            crate::vega::heatmap_view(&self.config, &data)
+        );
+
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
 impl<T> From<ndarray::Array2<T>> for Contour<usize, usize, T>
 where
     T: Display + Clone,
@@ -159,3 +325,124 @@ where
         Contour::new(xs, ys, values)
     }
 }
+
+/// Levels of the contours.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Levels {
+    Number(usize),
+    Values(Vec<f64>),
+}
+
+/// Options of the contours, shared by all contour plots in a comparison.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Isolines {
+    pub(crate) levels: Option<Levels>,
+    pub(crate) labels: bool,
+    pub(crate) only: bool,
+}
+
+impl Isolines {
+    /// Gnuplot commands setting up the contours, before ``splot``.
+    pub(crate) fn plot_script(&self) -> String {
+        let mut gnuplot_script = String::new();
+        if self.only {
+            gnuplot_script += "unset surface\n";
+            gnuplot_script += "set view map\n";
+        } else {
+            gnuplot_script += "set surface\n";
+        }
+        gnuplot_script += "set contour\n";
+        match &self.levels {
+            Some(Levels::Number(number)) => {
+                gnuplot_script += &format!("set cntrparam levels {}\n", number)
+            }
+            Some(Levels::Values(values)) => {
+                let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+                gnuplot_script += &format!("set cntrparam levels discrete {}\n", values.join(","))
+            }
+            None => (),
+        }
+        gnuplot_script
+    }
+
+    /// Elements of a gnuplot ``splot`` command drawing the contours of the values
+    /// saved in ``path`` with the legend ``title``, both gnuplot strings, and their labels.
+    pub(crate) fn plot_element(
+        &self,
+        path: &str,
+        style: &crate::configuration::plot::style::Style,
+        dashtype: Option<usize>,
+        title: Option<&str>,
+    ) -> String {
+        let mut element = format!("{} using 1:2:3 with {}", path, style);
+        if let Some(title) = title {
+            element += &format!(" title {}", title);
+        }
+        if let Some(dashtype) = dashtype {
+            element += &format!(" dashtype {}", dashtype);
+        }
+        if self.labels {
+            element += &format!(", {} using 1:2:3 notitle nosurface with labels", path);
+        }
+        element
+    }
+
+    /// Keyword arguments of the ``contour`` helper of matplotlib scripts.
+    pub(crate) fn matplotlib_arguments(&self) -> String {
+        let mut arguments = match &self.levels {
+            Some(Levels::Number(number)) => format!(", levels={}", number),
+            Some(Levels::Values(values)) => {
+                let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+                format!(", levels=[{}]", values.join(", "))
+            }
+            None => String::new(),
+        };
+        if self.labels {
+            arguments += ", labels=True";
+        }
+        arguments
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_script() {
+        let mut contour = Contour::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        contour.set_id("contour_test");
+        let script = contour.plot_script();
+        assert!(script.contains("set surface\nset contour\nsplot "));
+        assert!(!script.contains("cntrparam"));
+        assert!(script.contains("contour_test.txt\" using 1:2:3 with lines\n"));
+
+        contour
+            .set_levels(vec![1.5, 2.5])
+            .set_isoline_labels(true)
+            .set_isolines_only(true);
+        let script = contour.plot_script();
+        assert!(script.contains(
+            "unset surface\nset view map\nset contour\nset cntrparam levels discrete 1.5,2.5\n"
+        ));
+        assert!(script.contains("contour_test.txt\" using 1:2:3 notitle nosurface with labels\n"));
+
+        contour.set_number_of_levels(4);
+        assert!(contour.plot_script().contains("set cntrparam levels 4\n"));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut contour = Contour::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        contour
+            .set_levels(vec![2, 3])
+            .set_isoline_labels(true)
+            .set_id("contour_matplotlib_test");
+        let script = contour.matplotlib_script();
+
+        assert!(script.contains("contour_matplotlib_test.txt\"), levels=[2, 3], labels=True)\n"));
+    }
+}
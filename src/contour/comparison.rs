@@ -0,0 +1,470 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::PathBuf;
+
+// Traits
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::fmt::Display;
+use core::ops::{Add, AddAssign};
+
+/// Comparison counter part of [Contour] struct.
+///
+/// # Remarks
+///
+/// All contour plots are drawn in the same axes, or each in its own panel with
+/// [set_multiplot], with the levels, labels and isolines-only mode of the comparison,
+/// not of each contour plot. A comparison made from a contour plot, with ``From`` or ``Add``,
+/// starts with those of that contour plot.
+///
+/// # Examples
+///
+/// Quick plot.
+/// ```no_run
+/// # use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// let many_contours = (0..3).map(|i| pre::Contour::new(0..10, 0..5, iproduct!(0..10, 0..5).map(move |(x, y)| x * y + 10 * i)));
+/// pre::Contours::new(many_contours).plot("my_identifier").unwrap();
+/// ```
+///
+/// [Contour]: struct.Contour.html
+/// [set_multiplot]: struct.Contours.html#method.set_multiplot
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    data_set: Vec<crate::Contour<T, S, U>>,
+    isolines: crate::contour::Isolines,
+    multiplot: bool,
+    config: crate::configuration::Configuration,
+}
+
+impl<T, S, U> Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    pub fn new<I>(data_set: I) -> Contours<T, S, U>
+    where
+        I: IntoIterator<Item = crate::Contour<T, S, U>>,
+    {
+        let config = crate::configuration::Configuration::default();
+        let data_set = data_set
+            .into_iter()
+            .collect::<Vec<crate::Contour<T, S, U>>>();
+        Contours {
+            data_set,
+            isolines: crate::contour::Isolines::default(),
+            multiplot: false,
+            config,
+        }
+    }
+
+    /// Sets the values of the contours. See [Contour::set_levels].
+    ///
+    /// [Contour::set_levels]: struct.Contour.html#method.set_levels
+    pub fn set_levels<I, V>(&mut self, levels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<f64>,
+    {
+        self.isolines.levels = Some(crate::contour::Levels::Values(
+            levels.into_iter().map(|level| level.into()).collect(),
+        ));
+        self
+    }
+
+    /// Sets the number of contours. See [Contour::set_number_of_levels].
+    ///
+    /// [Contour::set_number_of_levels]: struct.Contour.html#method.set_number_of_levels
+    pub fn set_number_of_levels(&mut self, number: usize) -> &mut Self {
+        self.isolines.levels = Some(crate::contour::Levels::Number(number));
+        self
+    }
+
+    /// Choose whether each contour is labeled by its value on the plot.
+    /// See [Contour::set_isoline_labels].
+    ///
+    /// [Contour::set_isoline_labels]: struct.Contour.html#method.set_isoline_labels
+    pub fn set_isoline_labels(&mut self, labels: bool) -> &mut Self {
+        self.isolines.labels = labels;
+        self
+    }
+
+    /// Choose whether only the contours are drawn. See [Contour::set_isolines_only].
+    ///
+    /// [Contour::set_isolines_only]: struct.Contour.html#method.set_isolines_only
+    pub fn set_isolines_only(&mut self, only: bool) -> &mut Self {
+        self.isolines.only = only;
+        self
+    }
+
+    /// Choose whether each contour plot is drawn in its own panel, titled by its legend,
+    /// instead of all in the same axes.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: contour plots are overlaid.
+    pub fn set_multiplot(&mut self, multiplot: bool) -> &mut Self {
+        self.multiplot = multiplot;
+        self
+    }
+}
+
+impl<T, S, U> From<crate::Contour<T, S, U>> for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn from(contour: crate::Contour<T, S, U>) -> Self {
+        let isolines = contour.isolines.clone();
+        let mut cmp = Contours::new(vec![contour]);
+        cmp.isolines = isolines;
+        cmp
+    }
+}
+
+impl<T, S, U> Add<crate::Contour<T, S, U>> for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: crate::Contour<T, S, U>) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<T, S, U> Add for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<T, S, U> AddAssign<crate::Contour<T, S, U>> for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn add_assign(&mut self, other: crate::Contour<T, S, U>) {
+        self.data_set.push(other);
+    }
+}
+
+impl<T, S, U> AddAssign for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn add_assign(&mut self, mut other: Self) {
+        self.data_set.append(&mut other.data_set);
+    }
+}
+
+impl<T, S, U> Configurable for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn configuration_mut(&mut self) -> &mut crate::configuration::Configuration {
+        &mut self.config
+    }
+    fn configuration(&self) -> &crate::configuration::Configuration {
+        &self.config
+    }
+}
+
+impl<T, S, U> Saveable for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for contour in self.data_set.iter() {
+            contour.write_data(writer)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn save_with_id<W: Display>(&self, id: W) -> Result<&Self, PreexplorerError> {
+        for (counter, contour) in self.data_set.iter().enumerate() {
+            let inner_id = format!("{}_{}", id, counter);
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(contour, &inner_path)?;
+        }
+        Ok(self)
+    }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
+}
+
+impl<T, S, U> Plotable for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn plot_script(&self) -> String {
+        let id = self.checked_id();
+        let mut gnuplot_script = self.config.opening_plot_script_comparison();
+        gnuplot_script += &self.isolines.plot_script();
+
+        if self.multiplot {
+            let rows = (self.data_set.len() as f64).sqrt().ceil();
+            let columns = (self.data_set.len() as f64 / rows).ceil();
+            let overall_title: &str = self.title().map(|s| s.as_str()).unwrap_or("");
+            gnuplot_script += &format!(
+                "set multiplot layout {},{} rowsfirst downwards title {}\n",
+                rows,
+                columns,
+                self.config.text_string(overall_title)
+            );
+        } else {
+            gnuplot_script += "splot ";
+        }
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, contour) in self.data_set.iter().enumerate() {
+            let inner_id = format!("{}_{}", id, counter);
+            let mut inner_path = self.data_path().to_path_buf();
+            if let Some(extension) = self.data_extension() {
+                inner_path.set_file_name(&inner_id);
+                inner_path.set_extension(extension);
+            } else {
+                inner_path.set_file_name(&inner_id);
+            }
+            let legend = match contour.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let contour_style = match style {
+                crate::configuration::plot::style::Style::Default => contour.style(),
+                _ => style,
+            };
+            let inner_path = self.config.script_path(&inner_path);
+
+            if self.multiplot {
+                gnuplot_script += &format!("set title {}\n", self.config.text_string(&legend));
+                gnuplot_script += &format!(
+                    "splot {}\n",
+                    self.isolines.plot_element(
+                        &inner_path,
+                        contour_style,
+                        contour.dashtype(),
+                        None
+                    )
+                );
+                continue;
+            }
+
+            let dashtype = match contour.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            gnuplot_script += &self.isolines.plot_element(
+                &inner_path,
+                contour_style,
+                Some(dashtype),
+                Some(&self.config.text_string(&legend)),
+            );
+            gnuplot_script += ", ";
+
+            if counter < self.data_set.len() - 1 {
+                gnuplot_script += "\\\n";
+            }
+        }
+        if self.multiplot {
+            gnuplot_script += "unset multiplot\n";
+        } else {
+            gnuplot_script += "\n";
+        }
+        gnuplot_script += &self.ending_plot_script();
+
+        gnuplot_script
+    }
+}
+
+impl<T, S, U> Matplotlib for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = if self.multiplot {
+            self.config
+                .opening_matplotlib_script_multiplot(self.data_set.len())
+        } else {
+            self.config.opening_matplotlib_script()
+        };
+
+        for (counter, contour) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match contour.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            if self.multiplot {
+                python_script += &format!(
+                    "ax = axes[{}]\nax.set_title({})\ncontour(ax, table({}){})\n",
+                    counter,
+                    crate::matplotlib::python_string(&legend),
+                    self.config.python_path(&inner_path),
+                    self.isolines.matplotlib_arguments(),
+                );
+            } else {
+                python_script += &format!(
+                    "contour(ax, table({}){}, colors=\"C{}\", label={})\n",
+                    self.config.python_path(&inner_path),
+                    self.isolines.matplotlib_arguments(),
+                    counter,
+                    crate::matplotlib::python_string(&legend),
+                );
+            }
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S, U> VegaLite for Contours<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    /// Each contour plot as a heatmap in its own view, since Vega-Lite has no contours.
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut views = Vec::new();
+
+        for (counter, contour) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                contour,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match contour.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            views.push(format!(
+                "{{\"title\": {}, {}, {}}}",
+                crate::configuration::save::format::json_string(&legend),
+                crate::vega::size(),
+                crate::vega::heatmap_view(&self.config, &data),
+            ));
+        }
+
+        let rows = (self.data_set.len() as f64).sqrt().ceil();
+        let columns = (self.data_set.len() as f64 / rows).ceil();
+        let view = format!(
+            "\"columns\": {},\n\"concat\": [\n{}\n],\n\"resolve\": {{\"scale\": {{\"color\": \"independent\"}}}}",
+            columns,
+            views.join(",\n"),
+        );
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_contour() {
+        let mut first = crate::Contour::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        first.set_levels(vec![1.5, 2.5]).set_isoline_labels(true);
+        let contours = Contours::from(first.clone());
+        assert_eq!(contours.isolines, first.isolines);
+
+        let second = crate::Contour::new(0..2, 0..2, vec![4, 3, 2, 1]);
+        let mut contours = first.clone() + second;
+        contours.set_id("contours_from_test");
+        assert_eq!(contours.isolines, first.isolines);
+        assert!(contours
+            .plot_script()
+            .contains("set cntrparam levels discrete 1.5,2.5\n"));
+    }
+
+    #[test]
+    fn plot_script() {
+        let mut first = crate::Contour::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        first.set_title("first");
+        let mut contours = first + crate::Contour::new(0..2, 0..2, vec![4, 3, 2, 1]);
+        contours.set_id("contours_test").set_number_of_levels(3);
+        let script = contours.plot_script();
+
+        assert!(script.contains("set contour\nset cntrparam levels 3\nsplot "));
+        assert!(!script.contains("unset multiplot"));
+        assert!(script.contains(
+            "contours_test_0.txt\" using 1:2:3 with lines title \"first\" noenhanced dashtype 1, \\\n"
+        ));
+        assert!(script.contains(
+            "contours_test_1.txt\" using 1:2:3 with lines title \"1\" noenhanced dashtype 2, \n"
+        ));
+
+        contours.set_multiplot(true).set_isoline_labels(true);
+        let script = contours.plot_script();
+        assert!(script.contains("set multiplot layout 2,1 rowsfirst downwards"));
+        assert!(script.contains("set title \"1\" noenhanced\nsplot "));
+        assert!(script.contains("using 1:2:3 with lines, "));
+        assert!(
+            script.contains("contours_test_1.txt\" using 1:2:3 notitle nosurface with labels\n")
+        );
+
+        contours.set_output("contours_test.png");
+        let script = contours.plot_script();
+        assert!(script.ends_with("with labels\nunset multiplot\nunset output\n"));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let contour = crate::Contour::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        let mut contours = contour.clone() + contour;
+        contours
+            .set_levels(vec![2])
+            .set_id("contours_matplotlib_test");
+        let script = contours.matplotlib_script();
+        assert!(script.contains("fig, ax = plt.subplots()\n"));
+        assert!(script.contains("levels=[2], colors=\"C1\", label=\"1\")\n"));
+
+        contours.set_multiplot(true);
+        let script = contours.matplotlib_script();
+        assert!(script.contains("ax = axes[1]\nax.set_title(\"1\")\ncontour(ax, table("));
+    }
+}
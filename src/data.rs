@@ -2,14 +2,29 @@
 use crate::errors::PreexplorerError;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::convert::TryInto;
 use core::fmt::Display;
 
+pub mod role;
+
+pub use role::Role;
+
 /// Generic multi-dimensional data.
 ///
 /// # Remarks
 ///
-/// It should be used with the [plot_later] command, writting the perfect
+/// Columns can be named, with [set_column_names], and given a [Role], with [set_role].
+/// Once a column is the ``y`` of the plot, the default plot is
+/// - points in three dimensions, if a column is ``z``,
+/// - error bars, if a column is ``yerr``,
+/// - points colored by the palette, if a column is ``color``,
+/// - the style of the plot, otherwise,
+///
+/// with the labels of a ``label`` column, if any, and the index of each row as ``x``,
+/// if no column is ``x``.
+///
+/// Without a ``y`` column, it should be used with the [plot_later] command, writting the perfect
 /// plot script by interacting with gnuplot directly.
 ///
 /// # Examples
@@ -30,6 +45,23 @@ use core::fmt::Display;
 ///     .unwrap();
 /// ```
 ///
+/// Plot measurements with their errors.
+/// ```no_run
+/// # use preexplorer::prelude::*;
+/// let data = vec![0., 1.1, 0.1, 1., 2.3, 0.2, 2., 1.7, 0.1];
+///
+/// pre::Data::new(data, 3)
+///     .set_column_names(["time", "value", "error"])
+///     .set_role(0, "x").unwrap()
+///     .set_role(1, "y").unwrap()
+///     .set_role(2, pre::Role::YErr).unwrap()
+///     .plot("my_identifier")
+///     .unwrap();
+/// ```
+///
+/// [set_column_names]: struct.Data.html#method.set_column_names
+/// [set_role]: struct.Data.html#method.set_role
+/// [Role]: enum.Role.html
 /// [plot_later]: trait.Plotable.html#method.plot_later
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -40,6 +72,8 @@ where
     data: Vec<T>,
     config: crate::configuration::Configuration,
     dim: usize,
+    names: Vec<String>,
+    roles: Vec<Option<Role>>,
 }
 
 impl<T> Data<T>
@@ -61,7 +95,97 @@ where
     {
         let data: Vec<T> = data.into_iter().collect();
         let config = crate::configuration::Configuration::default();
-        Data { data, config, dim }
+        Data {
+            data,
+            config,
+            dim,
+            names: Vec::new(),
+            roles: vec![None; dim],
+        }
+    }
+
+    /// Names the columns, in order. Missing names are ``column_1``, ``column_2``, etc.
+    ///
+    /// # Panics
+    ///
+    /// If there are more names than columns.
+    pub fn set_column_names<I>(&mut self, names: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        let names: Vec<String> = names.into_iter().map(|name| name.to_string()).collect();
+        if names.len() > self.dim {
+            panic!(
+                "There are {} names for {} columns: there can be one per column at most.",
+                names.len(),
+                self.dim
+            );
+        }
+        self.names = names;
+        self
+    }
+
+    /// Gives a role to the column ``column``, counting from zero, in the default plot.
+    /// Each role is given to one column at most: any other column with this role loses it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no such column or no such role, [PreexplorerError::Role] is returned.
+    ///
+    /// # Examples
+    ///
+    /// Points in three dimensions, from columns in any order.
+    /// ```
+    /// use preexplorer::prelude::*;
+    /// let mut data = pre::Data::new(vec![3, 1, 2, 6, 4, 5], 3);
+    /// data.set_role(1, "x").unwrap()
+    ///     .set_role(2, "y").unwrap()
+    ///     .set_role(0, pre::Role::Z).unwrap();
+    /// assert_eq!(data.role(0), Some(pre::Role::Z));
+    /// ```
+    ///
+    /// [PreexplorerError::Role]: enum.PreexplorerError.html#variant.Role
+    pub fn set_role<R>(&mut self, column: usize, role: R) -> Result<&mut Self, PreexplorerError>
+    where
+        R: TryInto<Role>,
+        R::Error: Display,
+    {
+        if column >= self.dim {
+            return Err(PreexplorerError::Role(format!(
+                "there is no column {} in data with {} columns",
+                column, self.dim
+            )));
+        }
+        let role = role
+            .try_into()
+            .map_err(|e| PreexplorerError::Role(e.to_string()))?;
+        for other in self.roles.iter_mut() {
+            if *other == Some(role) {
+                *other = None;
+            }
+        }
+        self.roles[column] = Some(role);
+        Ok(self)
+    }
+
+    /// Role of the column ``column``, counting from zero, if any.
+    pub fn role(&self, column: usize) -> Option<Role> {
+        self.roles.get(column).copied().flatten()
+    }
+
+    /// Column with ``role`` in the plot script, counting from one, if any.
+    fn column(&self, role: Role) -> Option<usize> {
+        self.roles
+            .iter()
+            .position(|other| *other == Some(role))
+            .map(|i| i + 1)
+    }
+
+    /// Name of the column ``column``, counting from one, as a field of Vega-Lite.
+    fn field(&self, column: usize) -> String {
+        crate::configuration::save::format::all_column_names(&self.names, self.dim)[column - 1]
+            .clone()
     }
 }
 
@@ -82,20 +206,17 @@ impl<T> Saveable for Data<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
-
-        let mut counter = 0;
-        for value in self.data.clone() {
-            raw_data.push_str(&format!("{}\t", value));
-            counter += 1;
-            if counter == self.dim {
-                counter = 0;
-                raw_data.push('\n');
-            }
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for row in self.data.chunks(self.dim.max(1)) {
+            let row: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+            writeln!(writer, "{}", row.join("\t"))?;
         }
 
-        raw_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.names.clone()
     }
 }
 
@@ -103,35 +224,265 @@ impl<T> Plotable for Data<T>
 where
     T: Display + Clone,
 {
-    /// Calls [plot_later] method and retunrs error since generic data
-    /// should be plotted by hand interacting with gnuplot.
+    /// Plots the data if a column has the role ``y``. Otherwise, calls [plot_later] method
+    /// and returns error since generic data should be plotted by hand interacting with gnuplot.
     ///
     /// [plot_later]: trait.Plotable.html#method.plot_later
     fn plot<S: Display>(&mut self, id: S) -> Result<&mut Self, PreexplorerError> {
+        if self.column(Role::Y).is_some() {
+            let id = id.to_string();
+            self.set_id(id.clone());
+            let gnuplot_script = self.plot_script();
+            self.plot_with_script(id, gnuplot_script)?;
+            return Ok(self);
+        }
         self.plot_later(id)?;
 
-        let message = format!("Tried to plot general data: do it directly with gnuplot. A preliminar gnuplot script is located in {:?}", self.plot_path());
-        Err(PreexplorerError::Plotting(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            message,
-        )))
+        let message = format!("Tried to plot general data: give a column the role y with set_role, or do it directly with gnuplot. A preliminar gnuplot script is located in {:?}", self.plot_path());
+        Err(PreexplorerError::Plotting(std::io::Error::other(message)))
     }
 
     fn plot_script(&self) -> String {
         let mut gnuplot_script = self.opening_plot_script();
 
-        gnuplot_script +=
-            "\n# Visit http://www.gnuplotting.org and search for the correct plotting command!\n";
-        gnuplot_script += "\n# To get the plot, run the following command:";
-        gnuplot_script += &format!("\n# gnuplot {:?} \n\n", self.plot_path());
-        gnuplot_script += &format!("plot {:?} \n", self.data_path());
+        let y = match self.column(Role::Y) {
+            Some(y) => y,
+            None => {
+                gnuplot_script += "\n# Visit http://www.gnuplotting.org and search for the correct plotting command!\n";
+                gnuplot_script += "\n# To get the plot, run the following command:";
+                gnuplot_script += &format!("\n# gnuplot {:?} \n\n", self.plot_path());
+                gnuplot_script += &format!("plot {} \n", self.config.script_path(self.data_path()));
+                gnuplot_script += &self.ending_plot_script();
+                return gnuplot_script;
+            }
+        };
+
+        // Column 0 is the index of each row
+        let mut position = format!("{}:{}", self.column(Role::X).unwrap_or(0), y);
+        let mut using = position.clone();
+        let command = match self.column(Role::Z) {
+            Some(z) => {
+                position += &format!(":{}", z);
+                using = position.clone();
+                "splot"
+            }
+            None => "plot",
+        };
+        let mut style = match (self.column(Role::Z), self.column(Role::YErr)) {
+            (None, Some(yerr)) => {
+                using += &format!(":{}", yerr);
+                String::from("yerrorbars")
+            }
+            (Some(_), _) => String::from("points"),
+            (None, None) if self.column(Role::Color).is_some() => String::from("points"),
+            (None, None) => self.style().to_string(),
+        };
+        if let Some(color) = self.column(Role::Color) {
+            using += &format!(":{}", color);
+            style += " linecolor palette";
+        }
 
+        let path = self.config.script_path(self.data_path());
+        gnuplot_script += &format!(
+            "{} {} using {} with {} dashtype {}",
+            command,
+            path,
+            using,
+            style,
+            self.dashtype().unwrap_or(1)
+        );
+        if let Some(label) = self.column(Role::Label) {
+            gnuplot_script += &format!(
+                ", {} using {}:(stringcolumn({})) with labels noenhanced left offset character 1,0 notitle",
+                path, position, label
+            );
+        }
+        gnuplot_script += "\n";
         gnuplot_script += &self.ending_plot_script();
 
         gnuplot_script
     }
 }
 
+impl<T> Matplotlib for Data<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let y = match self.column(Role::Y) {
+            Some(y) => y,
+            None => {
+                let mut python_script = self.opening_matplotlib_script();
+                python_script +=
+                    "# Visit https://matplotlib.org/stable/gallery and search for the correct plotting command!\n";
+                python_script += &format!(
+                    "data = table({})\n",
+                    self.config.python_path(self.data_path())
+                );
+                if self.dim > 1 {
+                    python_script +=
+                        "ax.plot(data[:, 0], data[:, 1], marker=\"+\", linestyle=\"none\")\n";
+                } else {
+                    python_script += "ax.plot(data[:, 0], marker=\"+\", linestyle=\"none\")\n";
+                }
+                python_script += &self.ending_matplotlib_script();
+                return python_script;
+            }
+        };
+
+        let mut python_script = match self.column(Role::Z) {
+            Some(_) => self.config.opening_matplotlib_script_3d(),
+            None => self.opening_matplotlib_script(),
+        };
+        python_script += &format!(
+            "data = rows({})\n",
+            self.config.python_path(self.data_path())
+        );
+        python_script += &match self.column(Role::X) {
+            Some(x) => format!("x = column(data, {})\n", x - 1),
+            None => String::from("x = np.arange(len(data))\n"),
+        };
+        python_script += &format!("y = column(data, {})\n", y - 1);
+        let mut position = String::from("x, y");
+        if let Some(z) = self.column(Role::Z) {
+            python_script += &format!("z = column(data, {})\n", z - 1);
+            position += ", z";
+        }
+
+        let color = self.column(Role::Color);
+        match (self.column(Role::Z), self.column(Role::YErr), color) {
+            (None, Some(yerr), _) => {
+                python_script += &format!(
+                    "ax.errorbar(x, y, yerr=column(data, {}), fmt=\"+\", color=\"C0\")\n",
+                    yerr - 1
+                )
+            }
+            (Some(_), _, _) | (None, None, Some(_)) => {
+                let colors = match color {
+                    Some(color) => format!(
+                        ", c=column(data, {}){}",
+                        color - 1,
+                        crate::matplotlib::norm_arguments(self.configuration())
+                    ),
+                    None => String::new(),
+                };
+                python_script +=
+                    &format!("paths = ax.scatter({}, marker=\"+\"{})\n", position, colors);
+                if color.is_some() {
+                    python_script += "colorbar = fig.colorbar(paths, ax=ax)\n";
+                    if let Some(label) = self.labelz() {
+                        python_script += &format!(
+                            "colorbar.set_label({})\n",
+                            crate::matplotlib::python_string(label)
+                        );
+                    }
+                }
+            }
+            (None, None, None) => {
+                python_script += &format!(
+                    "draw(ax, x, y, \"{}\", {}, color=\"C0\")\n",
+                    self.style(),
+                    self.dashtype().unwrap_or(1)
+                )
+            }
+        }
+        if let Some(label) = self.column(Role::Label) {
+            python_script += &format!(
+                "for point, row in zip(zip({}), data):\n    ax.text(*point, \" \" + row[{}])\n",
+                position,
+                label - 1
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Data<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let index = "\"transform\": [{\"window\": [{\"op\": \"row_number\", \"as\": \"index\"}]}]";
+
+        let y = match self.column(Role::Y) {
+            Some(y) => y,
+            None => {
+                let (x, y) = match self.dim {
+                    1 => (String::from("index"), self.field(1)),
+                    _ => (self.field(1), self.field(2)),
+                };
+                let layer = format!(
+                    "{{\"data\": {}, {}, \"mark\": {}, \"encoding\": {{{}, {}}}}}",
+                    data,
+                    index,
+                    crate::vega::mark(&crate::configuration::plot::style::Style::Points, 1),
+                    crate::vega::x(&self.config, &x),
+                    crate::vega::y(&self.config, &y),
+                );
+                return crate::vega::layered_spec(&self.config, &[layer]);
+            }
+        };
+
+        let x = match self.column(Role::X) {
+            Some(x) => self.field(x),
+            None => String::from("index"),
+        };
+        let position = vec![
+            crate::vega::x(&self.config, &x),
+            crate::vega::y(&self.config, &self.field(y)),
+        ];
+        let mut encoding = position.clone();
+        let color = self.column(Role::Color).or_else(|| self.column(Role::Z));
+        let mark = match (color, self.column(Role::YErr)) {
+            (Some(color), _) => {
+                encoding.push(crate::vega::z(&self.config, &self.field(color)));
+                crate::vega::mark(&crate::configuration::plot::style::Style::Points, 1)
+            }
+            (None, Some(_)) => {
+                crate::vega::mark(&crate::configuration::plot::style::Style::Points, 1)
+            }
+            (None, None) => crate::vega::mark(self.style(), self.dashtype().unwrap_or(1)),
+        };
+
+        let mut layers = vec![format!(
+            "{{\"data\": {}, {}, \"mark\": {}, \"encoding\": {{{}}}}}",
+            data,
+            index,
+            mark,
+            encoding.join(", ")
+        )];
+        if let (None, Some(yerr)) = (self.column(Role::Z), self.column(Role::YErr)) {
+            layers.push(format!(
+                "{{\"data\": {}, {}, \"mark\": \"errorbar\", \"encoding\": {{{}, \"yError\": {{\"field\": {}}}}}}}",
+                data,
+                index,
+                position.join(", "),
+                crate::configuration::save::format::json_string(&self.field(yerr))
+            ));
+        }
+        if let Some(label) = self.column(Role::Label) {
+            layers.push(format!(
+                "{{\"data\": {}, {}, \"mark\": {{\"type\": \"text\", \"align\": \"left\", \"dx\": 5}}, \
+                \"encoding\": {{{}, \"text\": {{\"field\": {}}}}}}}",
+                data,
+                index,
+                position.join(", "),
+                crate::configuration::save::format::json_string(&self.field(label))
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +492,81 @@ mod tests {
         Data::new([1], 1);
     }
 
+    #[test]
+    fn plotable_data() {
+        let data = Data::new([1, 2, 3, 4, 5, 6], 3);
+        assert_eq!(data.plotable_data(), "1\t2\t3\n4\t5\t6\n");
+    }
+
+    #[test]
+    fn set_role() {
+        let mut data = Data::new([1, 2, 3], 3);
+        data.set_role(0, "y").unwrap().set_role(2, Role::Y).unwrap();
+        assert_eq!(data.role(0), None);
+        assert_eq!(data.role(2), Some(Role::Y));
+        assert!(data.set_role(1, "w").is_err());
+        assert!(matches!(
+            data.set_role(3, "x"),
+            Err(PreexplorerError::Role(_))
+        ));
+    }
+
+    #[test]
+    fn plot_script() {
+        let mut data = Data::new([0., 1.1, 0.1, 1., 2.3, 0.2], 3);
+        data.set_role(0, "x").unwrap();
+        data.set_id("data_test");
+        assert!(data.plot_script().contains("\nplot \""));
+
+        data.set_role(1, "y").unwrap().set_role(2, "yerr").unwrap();
+        assert!(data
+            .plot_script()
+            .contains("data_test.txt\" using 1:2:3 with yerrorbars dashtype 1\n"));
+
+        data.set_role(2, "z").unwrap().set_role(0, "label").unwrap();
+        assert!(data
+            .plot_script()
+            .contains("data_test.txt\" using 0:2:3 with points dashtype 1, \""));
+        assert!(data
+            .plot_script()
+            .contains("data_test.txt\" using 0:2:3:(stringcolumn(1)) with labels"));
+        assert!(data.plot_script().contains("\nsplot \""));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut data = Data::new([0., 1.1, 0.1, 1., 2.3, 0.2], 3);
+        data.set_role(1, "y")
+            .unwrap()
+            .set_role(2, "color")
+            .unwrap()
+            .set_labelz("error")
+            .set_id("data_matplotlib_test");
+        let script = data.matplotlib_script();
+
+        assert!(script.contains("x = np.arange(len(data))\ny = column(data, 1)\n"));
+        assert!(script.contains("paths = ax.scatter(x, y, marker=\"+\", c=column(data, 2))\n"));
+        assert!(script.contains("colorbar.set_label(\"error\")\n"));
+    }
+
+    #[test]
+    fn vega_lite_spec() {
+        let mut data = Data::new([0., 1.1, 0.1, 1., 2.3, 0.2], 3);
+        data.set_column_names(["time", "value", "error"])
+            .set_role(0, "x")
+            .unwrap()
+            .set_role(1, "y")
+            .unwrap()
+            .set_role(2, "yerr")
+            .unwrap()
+            .set_id("data_vega_lite_test");
+        let spec = data.vega_lite_spec();
+
+        assert!(spec.contains("{\"time\":0,\"value\":1.1,\"error\":0.1,\"block\":0}"));
+        assert!(spec.contains("\"mark\": \"errorbar\""));
+        assert!(spec.contains("\"yError\": {\"field\": \"error\"}"));
+    }
+
     #[cfg(feature = "use-serde")]
     #[test]
     fn serde() -> Result<(), ron::Error> {
@@ -148,7 +574,8 @@ mod tests {
         // Serializing
         let string = ron::ser::to_string(&data)?;
         // Deserializing
-        let style: Data<_> = ron::de::from_str(string)?;
+        let deserialized: Data<i32> = ron::de::from_str(&string)?;
+        assert_eq!(deserialized, data);
         Ok(())
     }
 }
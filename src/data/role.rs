@@ -0,0 +1,74 @@
+//! Roles of the columns of generic data in its default plot.
+
+// Traits
+use core::convert::TryFrom;
+use std::str::FromStr;
+use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString, EnumVariantNames, IntoStaticStr};
+
+/// What a column of [Data] means, so that it can be plotted.
+///
+/// # Remarks
+///
+/// There is a ``TryFrom<&str>`` implementation for ease of use.
+///
+/// [Data]: struct.Data.html
+#[derive(
+    Debug,
+    PartialOrd,
+    PartialEq,
+    Clone,
+    Copy,
+    Display,
+    AsRefStr,
+    EnumCount,
+    EnumIter,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    /// Horizontal position "x"
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "x")]
+    X,
+    /// Vertical position "y"
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "y")]
+    Y,
+    /// Error of the vertical position "yerr" or "yerror"
+    #[strum(ascii_case_insensitive)]
+    #[strum(to_string = "yerr", serialize = "yerror")]
+    YErr,
+    /// Height, in three dimensions "z"
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "z")]
+    Z,
+    /// Value mapped to the palette of colors "color" or "colour"
+    #[strum(ascii_case_insensitive)]
+    #[strum(to_string = "color", serialize = "colour")]
+    Color,
+    /// Text written next to each point "label"
+    #[strum(ascii_case_insensitive)]
+    #[strum(serialize = "label")]
+    Label,
+}
+
+impl TryFrom<&str> for Role {
+    type Error = strum::ParseError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Role::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Role::from_str("Colour").unwrap(), Role::Color);
+        assert_eq!(Role::try_from("yerror").unwrap(), Role::YErr);
+        assert_eq!(Role::YErr.to_string(), "yerr");
+    }
+}
@@ -1,7 +1,13 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::Path;
+
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 // Structs
 pub use comparison::Densities;
@@ -185,6 +191,38 @@ where
     }
 }
 
+impl<T> Density<T>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    /// Reads a ``Density<T>`` from a data file written by it,
+    /// restoring title, id and date from the header.
+    ///
+    /// # Remarks
+    ///
+    /// Only the realizations are saved, so the options of what to plot
+    /// (cdf, pdf, cloud and bins) are set to their default values.
+    ///
+    /// # Examples
+    ///
+    /// Plotting later in another binary.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// let den = pre::Density::<f64>::load("target/preexplorer/data/my_identifier.txt").unwrap();
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Density<T>, PreexplorerError> {
+        let file = crate::load::DataFile::read(path)?;
+        let realizations = file
+            .rows()
+            .map(|row| crate::load::parse_entry(row, 0))
+            .collect::<Result<Vec<T>, _>>()?;
+        let mut den = Density::new(realizations);
+        file.restore_header(&mut den);
+        Ok(den)
+    }
+}
+
 impl<T> Add for Density<T>
 where
     T: Display + Clone,
@@ -214,17 +252,23 @@ impl<T> Saveable for Density<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.realizations.is_empty() {
             eprintln!("Warning: There are no realizations.");
         }
 
-        let mut raw_data = String::new();
-        for value in self.realizations.clone() {
-            raw_data.push_str(&format!("{}\n", value));
+        for value in self.realizations.iter() {
+            writeln!(writer, "{}", value)?;
         }
-        raw_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["realization"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -249,15 +293,18 @@ where
 
         gnuplot_script += "plot ";
         if self.cloud() {
-            gnuplot_script += &format!("{:?} using 1:(0.25*rand(0)-.35)", self.data_path(),);
+            gnuplot_script += &format!(
+                "{} using 1:(0.25*rand(0)-.35)",
+                self.config.script_path(self.data_path()),
+            );
             if self.pdf() || self.cdf() || self.bins() {
                 gnuplot_script += ", \\\n\t ";
             }
         }
         if self.pdf() {
             gnuplot_script += &format!(
-                "{:?} using 1:(1./{}) smooth kdensity with {} dashtype {}",
-                self.data_path(),
+                "{} using 1:(1./{}) smooth kdensity with {} dashtype {}",
+                self.config.script_path(self.data_path()),
                 self.realizations.len(),
                 self.style(),
                 dashtype,
@@ -267,15 +314,18 @@ where
             }
         }
         if self.cdf() {
-            gnuplot_script += &format!("{:?} using 1:(1.) smooth cnorm", self.data_path(),);
+            gnuplot_script += &format!(
+                "{} using 1:(1.) smooth cnorm",
+                self.config.script_path(self.data_path()),
+            );
             if self.bins() {
                 gnuplot_script += ", \\\n\t ";
             }
         }
         if self.bins() {
             gnuplot_script += &format!(
-                "{:?} using 1:(1./{}) bins with boxes",
-                self.data_path(),
+                "{} using 1:(1./{}) bins with boxes",
+                self.config.script_path(self.data_path()),
                 self.realizations.len()
             );
         }
@@ -286,3 +336,142 @@ where
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for Density<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+        python_script += "ax.axhline(0, color=\"black\", linewidth=0.5)\n";
+        python_script += "ax.axvline(0, color=\"black\", linewidth=0.5)\n";
+        python_script += &format!(
+            "values = table({})[:, 0]\n",
+            self.config.python_path(self.data_path())
+        );
+
+        // Ploting cloud, pdf, cdf and/or bins
+        if self.cloud() {
+            python_script += "ax.plot(values, 0.25 * np.random.rand(len(values)) - 0.35, marker=\".\", linestyle=\"none\", color=\"C0\")\n";
+        }
+        if self.pdf() {
+            python_script += &format!(
+                "grid, density = kde(values)\ndraw(ax, grid, density, \"{}\", {}, color=\"C1\")\n",
+                self.style(),
+                self.dashtype().unwrap_or(1),
+            );
+        }
+        if self.cdf() {
+            python_script += "ax.plot(np.sort(values), np.arange(1, len(values) + 1) / len(values), drawstyle=\"steps-post\", color=\"C2\")\n";
+        }
+        if self.bins() {
+            python_script += "ax.hist(values, weights=np.full(len(values), 1. / len(values)), alpha=0.5, color=\"C3\")\n";
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Density<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let x = crate::vega::x(&self.config, "realization");
+        let mut layers = Vec::new();
+
+        // Cloud, pdf, cdf and/or bins
+        if self.cloud() {
+            layers.push(format!(
+                "{{\"data\": {}, \"transform\": [{{\"calculate\": \"0.25 * random() - 0.35\", \"as\": \"cloud\"}}], \
+                \"mark\": {}, \"encoding\": {{{}, {}}}}}",
+                data,
+                crate::vega::mark(&crate::configuration::plot::style::Style::Points, 1),
+                x,
+                crate::vega::y(&self.config, "cloud"),
+            ));
+        }
+        if self.pdf() {
+            layers.push(crate::vega::density_layer(
+                &self.config,
+                &data,
+                self.style(),
+                self.dashtype().unwrap_or(1),
+                None,
+            ));
+        }
+        if self.cdf() {
+            layers.push(format!(
+                "{{\"data\": {}, \"transform\": [{{\"window\": [{{\"op\": \"cume_dist\", \"as\": \"cdf\"}}], \"sort\": [{{\"field\": \"realization\"}}]}}], \
+                \"mark\": {}, \"encoding\": {{{}, {}}}}}",
+                data,
+                crate::vega::mark(&crate::configuration::plot::style::Style::Steps, 1),
+                x,
+                crate::vega::y(&self.config, "cdf"),
+            ));
+        }
+        if self.bins() {
+            layers.push(format!(
+                "{{\"data\": {}, \"transform\": [\
+                    {{\"bin\": true, \"field\": \"realization\", \"as\": [\"bin_start\", \"bin_end\"]}}, \
+                    {{\"aggregate\": [{{\"op\": \"count\", \"as\": \"count\"}}], \"groupby\": [\"bin_start\", \"bin_end\"]}}, \
+                    {{\"joinaggregate\": [{{\"op\": \"sum\", \"field\": \"count\", \"as\": \"total\"}}]}}, \
+                    {{\"calculate\": \"datum.count / datum.total\", \"as\": \"frequency\"}}], \
+                \"mark\": {{\"type\": \"rect\", \"opacity\": 0.5}}, \"encoding\": {{\
+                    {}, \"x2\": {{\"field\": \"bin_end\"}}, {}, \"y2\": {{\"datum\": 0}}}}}}",
+                data,
+                crate::vega::x(&self.config, "bin_start"),
+                crate::vega::y(&self.config, "frequency"),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
+
+impl<T> Density<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    /// Point cloud, probability density, cummulative distribution and bins to draw,
+    /// according to ``flags`` (in that order), in the given color.
+    pub(crate) fn series(
+        &self,
+        flags: (bool, bool, bool, bool),
+        color: usize,
+    ) -> Vec<crate::figure::Series> {
+        let realizations: Vec<f64> = self
+            .realizations
+            .iter()
+            .map(|value| value.to_f64().unwrap_or(f64::NAN))
+            .collect();
+        crate::figure::density_series(&realizations, flags, self.style(), color)
+    }
+}
+
+impl<T> Figurable for Density<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    /// Point cloud, probability density, cummulative distribution and/or bins.
+    ///
+    /// # Remarks
+    ///
+    /// The density is estimated with a gaussian kernel and the number of bins
+    /// follows Sturges' rule.
+    fn figure(&self) -> crate::figure::Figure {
+        let flags = (self.cloud(), self.pdf(), self.cdf(), self.bins());
+        let layers = self
+            .series(flags, 0)
+            .into_iter()
+            .map(crate::figure::Layer::Series);
+        crate::figure::Figure::new(&self.config, layers)
+    }
+}
@@ -1,10 +1,13 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 /// Comparison counter part of [Density] struct.
 ///
@@ -44,6 +47,31 @@ where
     }
 }
 
+impl<T> Densities<T>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    /// Reads a ``Densities<T>`` from the data files written by it,
+    /// where ``path`` is the data path of the comparison, e.g. ``target/preexplorer/data/my_identifier.txt``.
+    /// The data of each density is read from the files ``my_identifier_0.txt``, ``my_identifier_1.txt``, etc.
+    /// See [Density::load] for more.
+    ///
+    /// [Density::load]: struct.Density.html#method.load
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Densities<T>, PreexplorerError> {
+        let path = path.as_ref();
+        let data_set = crate::load::comparison_paths(path)?
+            .iter()
+            .map(crate::Density::load)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut comparison = Densities::new(data_set);
+        if let Some(id) = path.file_stem() {
+            comparison.set_id(id.to_string_lossy());
+        }
+        Ok(comparison)
+    }
+}
+
 impl<T> From<crate::Density<T>> for Densities<T>
 where
     T: Display + Clone,
@@ -111,23 +139,28 @@ impl<T> Saveable for Densities<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for density in self.data_set.iter() {
-            raw_data += &density.plotable_data();
-            raw_data += "\n";
+            density.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
         for (counter, density) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            density.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(density, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl<T> Plotable for Densities<T>
@@ -169,11 +202,11 @@ where
             };
 
             gnuplot_script += &format!(
-                "{:?} using 1:({}) smooth kdensity with {} title \"{}\" dashtype {}, ",
-                inner_path,
+                "{} using 1:({}) smooth kdensity with {} title {} dashtype {}, ",
+                self.config.script_path(&inner_path),
                 1. / density.realizations.len() as f64,
                 distribution_style,
-                legend,
+                self.config.text_string(&legend),
                 dashtype,
             );
             if counter < self.data_set.len() - 1 {
@@ -186,3 +219,124 @@ where
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for Densities<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, density) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match density.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let density_style = match style {
+                crate::configuration::plot::style::Style::Default => density.style(),
+                _ => style,
+            };
+            let dashtype = match density.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            python_script += &format!(
+                "values = table({})[:, 0]\ngrid, density = kde(values)\ndraw(ax, grid, density, \"{}\", {}, color=\"C{}\", label={})\n",
+                self.config.python_path(&inner_path),
+                density_style,
+                dashtype,
+                counter,
+                crate::matplotlib::python_string(&legend)
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Densities<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, density) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                density,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match density.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let density_style = match style {
+                crate::configuration::plot::style::Style::Default => density.style(),
+                _ => style,
+            };
+            let dashtype = match density.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.push(crate::vega::density_layer(
+                &self.config,
+                &data,
+                density_style,
+                dashtype,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
+
+impl<T> Densities<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    /// Probability density of each density to draw.
+    pub(crate) fn series(&self) -> Vec<crate::figure::Series> {
+        let mut series = Vec::new();
+        for (counter, density) in self.data_set.iter().enumerate() {
+            series.extend(
+                density
+                    .series((false, true, false, false), counter)
+                    .into_iter()
+                    .map(|s| {
+                        crate::figure::comparison_series(s, self.style(), density.title(), counter)
+                    }),
+            );
+        }
+        series
+    }
+}
+
+impl<T> Figurable for Densities<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    /// Probability density of each density.
+    fn figure(&self) -> crate::figure::Figure {
+        let layers = self.series().into_iter().map(crate::figure::Layer::Series);
+        crate::figure::Figure::new(&self.config, layers)
+    }
+}
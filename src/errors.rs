@@ -10,4 +10,94 @@ pub enum PreexplorerError {
     Plotting(#[source] std::io::Error),
     #[error("Removing error: {1}")]
     Removing(#[source] std::io::Error, String),
+    #[error("Gnuplot not found: is it installed and in the PATH?")]
+    GnuplotNotFound(#[source] std::io::Error),
+    #[error("Gnuplot script error in line {line}: {message}")]
+    GnuplotScript { line: usize, message: String },
+    #[error("Gnuplot exited with {status}: {stderr}")]
+    GnuplotExit {
+        status: std::process::ExitStatus,
+        stderr: String,
+    },
+    #[error("Run error: {0}")]
+    Run(String),
+    #[error("Reading error.")]
+    Reading(#[source] std::io::Error),
+    #[error("Parsing error in line {line}: {message}")]
+    Parsing { line: usize, message: String },
+    #[error("Rendering error: {0}")]
+    Rendering(String),
+    #[error("Role error: {0}")]
+    Role(String),
+}
+
+impl PreexplorerError {
+    /// Construct the error of a failed gnuplot run from its standard error.
+    ///
+    /// Gnuplot reports script errors as ``"script.gnu" line 3: message``
+    /// (older versions include a comma before ``line``).
+    pub(crate) fn from_gnuplot_stderr(status: std::process::ExitStatus, stderr: String) -> Self {
+        for text in stderr.lines() {
+            if let Some(index) = text.find(" line ") {
+                let rest = &text[index + " line ".len()..];
+                if let Some((number, message)) = rest.split_once(':') {
+                    if let Ok(line) = number.trim().parse() {
+                        let message = message.trim().to_string();
+                        return PreexplorerError::GnuplotScript { line, message };
+                    }
+                }
+            }
+        }
+        PreexplorerError::GnuplotExit { status, stderr }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn failure() -> std::process::ExitStatus {
+        use std::os::unix::process::ExitStatusExt;
+        std::process::ExitStatus::from_raw(1 << 8)
+    }
+
+    #[cfg(windows)]
+    fn failure() -> std::process::ExitStatus {
+        use std::os::windows::process::ExitStatusExt;
+        std::process::ExitStatus::from_raw(1)
+    }
+
+    #[test]
+    fn gnuplot_script_error() {
+        let stderr = "\nplot \"data.txt\" wit lines\n                ^\n\"target/preexplorer/plots/test.gnu\" line 12: unrecognized plot option\n\n";
+        match PreexplorerError::from_gnuplot_stderr(failure(), stderr.to_string()) {
+            PreexplorerError::GnuplotScript { line, message } => {
+                assert_eq!(line, 12);
+                assert_eq!(message, "unrecognized plot option");
+            }
+            e => panic!("Unexpected error: {:?}", e),
+        }
+
+        let stderr = "\"test.gnu\", line 3: undefined variable: foo\n";
+        match PreexplorerError::from_gnuplot_stderr(failure(), stderr.to_string()) {
+            PreexplorerError::GnuplotScript { line, message } => {
+                assert_eq!(line, 3);
+                assert_eq!(message, "undefined variable: foo");
+            }
+            e => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn gnuplot_exit_error() {
+        let stderr = "Segmentation fault\n";
+        match PreexplorerError::from_gnuplot_stderr(failure(), stderr.to_string()) {
+            PreexplorerError::GnuplotExit { status, stderr } => {
+                assert!(!status.success());
+                assert_eq!(stderr, "Segmentation fault\n");
+            }
+            e => panic!("Unexpected error: {:?}", e),
+        }
+    }
 }
@@ -0,0 +1,463 @@
+//! Backend-neutral description of plots.
+//!
+//! A [Figure] has the title and axes of a plot, and layers of data to draw in it.
+//! Structs that implement [Figurable] produce it, and any [Backend] can draw it.
+//!
+//! # Examples
+//!
+//! A struct of your own, drawn by a backend of your own.
+//! ```
+//! use preexplorer::prelude::*;
+//! use preexplorer::figure::{Figure, Layer, Series};
+//!
+//! struct Walk {
+//!     steps: Vec<f64>,
+//!     config: pre::Configuration,
+//! }
+//!
+//! impl Configurable for Walk {
+//!     fn configuration_mut(&mut self) -> &mut pre::Configuration {
+//!         &mut self.config
+//!     }
+//!     fn configuration(&self) -> &pre::Configuration {
+//!         &self.config
+//!     }
+//! }
+//!
+//! impl pre::Figurable for Walk {
+//!     fn figure(&self) -> Figure {
+//!         let points = self.steps.iter().enumerate().map(|(i, y)| (i as f64, *y));
+//!         let series = Series::new(points, &pre::Style::Default, 0);
+//!         Figure::new(self.configuration(), vec![Layer::Series(series)])
+//!     }
+//! }
+//!
+//! /// Lists the points of each series, one per line.
+//! struct Listing;
+//!
+//! impl pre::Backend for Listing {
+//!     type Output = String;
+//!     fn draw(&self, figure: &Figure) -> Result<String, pre::errors::PreexplorerError> {
+//!         let mut listing = figure.title.clone().unwrap_or_default();
+//!         for series in figure.series() {
+//!             for (x, y) in series.points.iter() {
+//!                 listing += &format!("\n{} {}", x, y);
+//!             }
+//!         }
+//!         Ok(listing)
+//!     }
+//! }
+//!
+//! let mut walk = Walk { steps: vec![0., 1., 0.5], config: pre::Configuration::default() };
+//! walk.set_title("Walk");
+//! assert_eq!(walk.plot_with(&Listing).unwrap(), "Walk\n0 0\n1 1\n2 0.5");
+//! // Built-in backends draw it too
+//! assert!(walk.plot_with(&pre::Gnuplot).unwrap().contains("set title \"Walk\" noenhanced"));
+//! assert!(walk.plot_with(&pre::Text::new(40, 10)).is_ok());
+//! ```
+//!
+//! [Figure]: struct.Figure.html
+//! [Figurable]: ../trait.Figurable.html
+//! [Backend]: ../trait.Backend.html
+
+// Structs
+use crate::configuration::plot::style::Style;
+use crate::configuration::Configuration;
+
+// Traits
+use crate::traits::Configurable;
+
+/// Number of points where densities are estimated.
+const DENSITY_POINTS: usize = 200;
+
+/// Title, axes and layers of a plot.
+///
+/// # Remarks
+///
+/// Series share the axes of the figure, while each grid is drawn in its own panel.
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct Figure {
+    pub title: Option<String>,
+    pub x: Axis,
+    pub y: Axis,
+    pub z: Axis,
+    pub layers: Vec<Layer>,
+}
+
+impl Figure {
+    /// Figure with the title and axes of a configuration.
+    pub fn new<I>(config: &Configuration, layers: I) -> Self
+    where
+        I: IntoIterator<Item = Layer>,
+    {
+        Figure {
+            title: config.title().cloned(),
+            x: Axis {
+                label: config.labelx().cloned(),
+                range: config.rangex(),
+                log: config.logx(),
+            },
+            y: Axis {
+                label: config.labely().cloned(),
+                range: config.rangey(),
+                log: config.logy(),
+            },
+            z: Axis {
+                label: config.labelz().cloned(),
+                range: config.rangez(),
+                log: config.logz(),
+            },
+            layers: layers.into_iter().collect(),
+        }
+    }
+
+    /// Series layers, in order.
+    pub fn series(&self) -> Vec<&Series> {
+        self.layers
+            .iter()
+            .filter_map(|layer| match layer {
+                Layer::Series(series) => Some(series),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Grid layers, in order.
+    pub fn grids(&self) -> Vec<&Grid> {
+        self.layers
+            .iter()
+            .filter_map(|layer| match layer {
+                Layer::Grid(grid) => Some(grid),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Label, range and logarithmic base of an axis.
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct Axis {
+    pub label: Option<String>,
+    pub range: Option<(f64, f64)>,
+    pub log: Option<f64>,
+}
+
+impl Axis {
+    /// Base of the logarithmic scale, if any, where a non-positive base means 10, as in gnuplot.
+    pub fn log_base(&self) -> Option<f64> {
+        self.log.map(|base| if base > 0. { base } else { 10. })
+    }
+}
+
+/// Data drawn in a figure.
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub enum Layer {
+    /// Points in the plane.
+    Series(Series),
+    /// Values over points in the plane.
+    Grid(Grid),
+}
+
+/// Points drawn with the same style, dash and color.
+///
+/// Colors are indices, so that backends choose their own palette.
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct Series {
+    pub title: Option<String>,
+    pub points: Vec<(f64, f64)>,
+    pub style: Style,
+    pub dashtype: Option<usize>,
+    pub color: usize,
+}
+
+impl Series {
+    pub fn new<I>(points: I, style: &Style, color: usize) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        Series {
+            title: None,
+            points: points.into_iter().collect(),
+            style: style.clone(),
+            dashtype: None,
+            color,
+        }
+    }
+}
+
+/// Values over points of the plane, drawn as colored cells.
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct Grid {
+    pub title: Option<String>,
+    pub points: Vec<(f64, f64, f64)>,
+}
+
+impl Grid {
+    pub fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64, f64)>,
+    {
+        Grid {
+            title: None,
+            points: points.into_iter().collect(),
+        }
+    }
+}
+
+/// Series of the ``counter``-th struct of a comparison: with the style of the comparison,
+/// unless it is the default, with the title of the struct, or its position, as legend,
+/// and with a different dash if it has none.
+pub(crate) fn comparison_series(
+    mut series: Series,
+    style: &Style,
+    title: Option<&String>,
+    counter: usize,
+) -> Series {
+    if *style != Style::Default {
+        series.style = style.clone();
+    }
+    series.title = Some(title.cloned().unwrap_or_else(|| counter.to_string()));
+    series.dashtype.get_or_insert(counter + 1);
+    series
+}
+
+/// Series of a density: point cloud, probability density, cummulative distribution
+/// and bins, according to ``flags`` (in that order).
+pub(crate) fn density_series(
+    realizations: &[f64],
+    flags: (bool, bool, bool, bool),
+    style: &Style,
+    color: usize,
+) -> Vec<Series> {
+    let (cloud, pdf, cdf, bins) = flags;
+    let mut sorted: Vec<f64> = realizations
+        .iter()
+        .copied()
+        .filter(|x| x.is_finite())
+        .collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len() as f64;
+    let mut series = Vec::new();
+    if sorted.is_empty() {
+        return series;
+    }
+
+    if cloud {
+        // Deterministic jitter, as in gnuplot's 0.25 * rand(0) - 0.35
+        let points = realizations
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x, 0.25 * ((i as f64 * 0.618_034) % 1.) - 0.35));
+        series.push(Series::new(points, &Style::Points, color));
+    }
+    if pdf {
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let bandwidth = match 1.06 * variance.sqrt() * n.powf(-0.2) {
+            h if h > 0. => h,
+            _ => 1.,
+        };
+        let start = sorted[0] - 3. * bandwidth;
+        let step = (sorted[sorted.len() - 1] + 3. * bandwidth - start) / DENSITY_POINTS as f64;
+        let points = (0..=DENSITY_POINTS).map(|i| {
+            let x = start + i as f64 * step;
+            let density = sorted
+                .iter()
+                .map(|r| (-0.5 * ((x - r) / bandwidth).powi(2)).exp())
+                .sum::<f64>()
+                / (n * bandwidth * (2. * std::f64::consts::PI).sqrt());
+            (x, density)
+        });
+        series.push(Series::new(points, style, color));
+    }
+    if cdf {
+        let points = sorted
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x, (i + 1) as f64 / n));
+        series.push(Series::new(points, &Style::Steps, color));
+    }
+    if bins {
+        // Sturges' rule
+        let number = (n.log2().ceil() as usize + 1).max(1);
+        let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+        let width = if max > min {
+            (max - min) / number as f64
+        } else {
+            1.
+        };
+        let mut counts = vec![0.; number];
+        for x in sorted.iter() {
+            let index = (((x - min) / width) as usize).min(number - 1);
+            counts[index] += 1. / n;
+        }
+        let points = counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + (i as f64 + 0.5) * width, count));
+        series.push(Series::new(points, &Style::Boxes, color));
+    }
+    series
+}
+
+/// Smallest and largest values, that are positive if ``log``.
+pub(crate) fn bounds<I: IntoIterator<Item = f64>>(values: I, log: bool) -> (f64, f64) {
+    let (min, max) = values
+        .into_iter()
+        .filter(|v| v.is_finite() && (!log || *v > 0.))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+    match (min.is_finite(), log) {
+        (false, false) => (0., 1.),
+        (false, true) => (1., 10.),
+        (true, false) if min == max => (min - 1., max + 1.),
+        (true, true) if min == max => (min / 2., max * 2.),
+        _ => (min, max),
+    }
+}
+
+/// Vertical bounds of series, that are positive if ``log``.
+///
+/// Otherwise, they include zero if it is close, or if bars or impulses are drawn,
+/// since they start from zero.
+pub(crate) fn y_bounds(series: &[&Series], log: bool) -> (f64, f64) {
+    let values = series.iter().flat_map(|s| s.points.iter().map(|p| p.1));
+    let (min, max) = bounds(values, log);
+    if log {
+        return (min, max);
+    }
+    let bars = series
+        .iter()
+        .any(|s| matches!(s.style, Style::Impulses | Style::Boxes));
+    let close = 0.05 * (max - min);
+    match (min, max) {
+        (min, max) if min > 0. && (bars || min < close) => (0., max),
+        (min, max) if max < 0. && (bars || -max < close) => (min, 0.),
+        bounds => bounds,
+    }
+}
+
+/// Smallest gap between different values, one if there is none.
+pub(crate) fn cell_size<I: IntoIterator<Item = f64>>(values: I) -> f64 {
+    let mut values: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .filter(|gap| *gap > 0.)
+        .fold(None, |min: Option<f64>, gap| {
+            Some(min.map_or(gap, |m| m.min(gap)))
+        })
+        .unwrap_or(1.)
+}
+
+/// Points joined as gnuplot does for step-like styles.
+pub(crate) fn step_points(points: &[(f64, f64)], style: &Style) -> Vec<(f64, f64)> {
+    let mut steps = Vec::with_capacity(2 * points.len());
+    match style {
+        Style::Steps => {
+            for (i, &(x, y)) in points.iter().enumerate() {
+                if i > 0 {
+                    steps.push((x, points[i - 1].1));
+                }
+                steps.push((x, y));
+            }
+        }
+        Style::Fsteps => {
+            for (i, &(x, y)) in points.iter().enumerate() {
+                if i > 0 {
+                    steps.push((points[i - 1].0, y));
+                }
+                steps.push((x, y));
+            }
+        }
+        _ => {
+            let half = cell_size(points.iter().map(|p| p.0)) / 2.;
+            for &(x, y) in points.iter() {
+                steps.push((x - half, y));
+                steps.push((x + half, y));
+            }
+        }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        let points = [(0., 1.), (1., 2.), (2., 0.)];
+        assert_eq!(
+            step_points(&points, &Style::Steps),
+            vec![(0., 1.), (1., 1.), (1., 2.), (2., 2.), (2., 0.)]
+        );
+        assert_eq!(
+            step_points(&points, &Style::Fsteps),
+            vec![(0., 1.), (0., 2.), (1., 2.), (1., 0.), (2., 0.)]
+        );
+    }
+
+    #[test]
+    fn density() {
+        let series = density_series(
+            &[0., 1., 1., 2.],
+            (true, true, true, true),
+            &Style::Default,
+            0,
+        );
+        assert_eq!(series.len(), 4);
+        assert_eq!(series[0].points.len(), 4);
+        assert_eq!(series[2].points.last(), Some(&(2., 1.)));
+        let total: f64 = series[3].points.iter().map(|p| p.1).sum();
+        assert!((total - 1.).abs() < 1e-12);
+
+        // Integral of the estimated density
+        let pdf = &series[1].points;
+        let integral: f64 = pdf.windows(2).map(|w| (w[1].0 - w[0].0) * w[0].1).sum();
+        assert!((integral - 1.).abs() < 1e-2);
+    }
+
+    #[test]
+    fn vertical_bounds() {
+        let series = |values: &[f64], style: Style| {
+            Series::new(
+                values.iter().enumerate().map(|(i, &v)| (i as f64, v)),
+                &style,
+                0,
+            )
+        };
+        assert_eq!(
+            y_bounds(&[&series(&[2., 3.], Style::Lines)], false),
+            (2., 3.)
+        );
+        assert_eq!(
+            y_bounds(&[&series(&[2., 3.], Style::Boxes)], false),
+            (0., 3.)
+        );
+        assert_eq!(
+            y_bounds(&[&series(&[0.01, 3.], Style::Lines)], false),
+            (0., 3.)
+        );
+        assert_eq!(
+            y_bounds(&[&series(&[-3., -0.01], Style::Lines)], false),
+            (-3., 0.)
+        );
+        assert_eq!(
+            y_bounds(&[&series(&[0.01, 3.], Style::Lines)], true),
+            (0.01, 3.)
+        );
+    }
+}
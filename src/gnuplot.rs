@@ -0,0 +1,257 @@
+// Structs
+use crate::errors::PreexplorerError;
+use crate::figure::{Axis, Figure};
+
+// Traits
+use crate::traits::Backend;
+
+/// [Backend] writing figures as self-contained gnuplot scripts, with the data inlined.
+///
+/// # Remarks
+///
+/// Different from [Plotable::plot_script], the script does not read any data file,
+/// so it can be moved around freely. Data is included as gnuplot datablocks, which
+/// need gnuplot 5 or newer. Run it with ``gnuplot -p`` to keep the window open.
+///
+/// # Examples
+///
+/// Sharing a quick plot as a single file.
+/// ```
+/// use preexplorer::prelude::*;
+/// let script = pre::Sequence::new(vec![1, 4, 9])
+///     .plot_with(&pre::Gnuplot)
+///     .unwrap();
+/// assert!(script.contains("$series_0 << EOD\n0\t1\n1\t4\n2\t9\nEOD\n"));
+/// ```
+///
+/// [Backend]: trait.Backend.html
+/// [Plotable::plot_script]: trait.Plotable.html#tymethod.plot_script
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Gnuplot;
+
+impl Backend for Gnuplot {
+    type Output = String;
+
+    fn draw(&self, figure: &Figure) -> Result<String, PreexplorerError> {
+        let series = figure.series();
+        let grids = figure.grids();
+        let mut gnuplot_script = String::new();
+
+        // Axes
+        gnuplot_script += &axis_script("x", &figure.x);
+        gnuplot_script += &axis_script("y", &figure.y);
+        if !grids.is_empty() {
+            gnuplot_script += &axis_script("cb", &figure.z);
+        }
+
+        // Data
+        for (counter, s) in series.iter().enumerate() {
+            gnuplot_script += &format!("$series_{} << EOD\n", counter);
+            for (x, y) in s.points.iter() {
+                gnuplot_script += &format!("{}\t{}\n", x, y);
+            }
+            gnuplot_script += "EOD\n";
+        }
+        for (counter, grid) in grids.iter().enumerate() {
+            gnuplot_script += &format!("$grid_{} << EOD\n", counter);
+            for (i, (x, y, z)) in grid.points.iter().enumerate() {
+                // Blocks of equal x, for pm3d
+                if i > 0 && grid.points[i - 1].0 != *x {
+                    gnuplot_script += "\n";
+                }
+                gnuplot_script += &format!("{}\t{}\t{}\n", x, y, z);
+            }
+            gnuplot_script += "EOD\n";
+        }
+        gnuplot_script += "\n";
+
+        // Plots
+        let panels = grids.len() + !series.is_empty() as usize;
+        let title = figure.title.as_deref().unwrap_or("");
+        if panels > 1 {
+            let rows = (panels as f64).sqrt().ceil();
+            let columns = (panels as f64 / rows).ceil();
+            gnuplot_script += &format!(
+                "set multiplot layout {},{} rowsfirst downwards title {}\n",
+                rows,
+                columns,
+                text(title)
+            );
+        } else if figure.title.is_some() {
+            gnuplot_script += &format!("set title {}\n", text(title));
+        }
+        if !series.is_empty() {
+            gnuplot_script += "plot ";
+            for (counter, s) in series.iter().enumerate() {
+                if counter > 0 {
+                    gnuplot_script += ", \\\n\t";
+                }
+                let legend = match &s.title {
+                    Some(legend) => format!("title {}", text(legend)),
+                    None => String::from("notitle"),
+                };
+                gnuplot_script += &format!(
+                    "$series_{} using 1:2 with {} {} dashtype {} linecolor {}",
+                    counter,
+                    s.style,
+                    legend,
+                    s.dashtype.unwrap_or(1),
+                    s.color + 1,
+                );
+            }
+            gnuplot_script += "\n";
+        }
+        for (counter, grid) in grids.iter().enumerate() {
+            if panels > 1 {
+                let legend = grid.title.as_deref().unwrap_or("");
+                gnuplot_script += &format!("set title {}\n", text(legend));
+            }
+            gnuplot_script += "set pm3d map\n";
+            gnuplot_script += &format!("splot $grid_{} using 1:2:3 notitle\n", counter);
+        }
+        if panels > 1 {
+            gnuplot_script += "unset multiplot\n";
+        }
+
+        Ok(gnuplot_script)
+    }
+}
+
+/// Gnuplot string of a text, double-quoted.
+///
+/// Backslashes, double quotes and control characters (like newlines) are escaped,
+/// as well as backquotes, which would otherwise run the enclosed text as a shell command.
+pub(crate) fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '\\' => quoted += "\\\\",
+            '"' => quoted += "\\\"",
+            '\n' => quoted += "\\n",
+            '\t' => quoted += "\\t",
+            '\r' => quoted += "\\r",
+            '`' => quoted += "\\140",
+            c if c.is_ascii_control() => quoted += &format!("\\{:03o}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Field of a data file holding a text, double-quoted.
+///
+/// The data file reader of gnuplot takes the text between double quotes as it is,
+/// without escapes, so double quotes become single quotes and line breaks become spaces.
+pub(crate) fn datafile_string(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .map(|c| match c {
+            '"' => '\'',
+            '\n' | '\r' => ' ',
+            c => c,
+        })
+        .collect();
+    format!("\"{}\"", text)
+}
+
+/// Gnuplot string of a path, with ``/`` as separator on every platform.
+pub(crate) fn path(path: &std::path::Path) -> String {
+    quote(&crate::configuration::path::portable(path))
+}
+
+/// Gnuplot string of a text shown as it is: quoted and without enhanced text mode,
+/// where ``@``, ``^``, ``_``, ``&``, ``~`` and braces have a special meaning.
+pub(crate) fn text(text: &str) -> String {
+    format!("{} noenhanced", quote(text))
+}
+
+/// Label, logarithmic scale and range of an axis.
+fn axis_script(name: &str, axis: &Axis) -> String {
+    let mut gnuplot_script = String::new();
+    if let Some(label) = &axis.label {
+        gnuplot_script += &format!("set {}label {}\n", name, text(label));
+    }
+    if let Some(base) = axis.log {
+        gnuplot_script += &format!("set logscale {} {}\n", name, base);
+    }
+    if let Some((min, max)) = axis.range {
+        gnuplot_script += &format!("set {}range [{}:{}]\n", name, min, max);
+    }
+    gnuplot_script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn series() {
+        let mut first = pre::Sequence::new(vec![1, 2]);
+        first.set_title("first");
+        let mut seqs = first + pre::Sequence::new(vec![3]);
+        seqs.set_title("Comparison").set_logy(2).set_rangex(0., 1.);
+        let script = seqs.plot_with(&Gnuplot).unwrap();
+        assert_eq!(
+            script,
+            "set xrange [0:1]\n\
+             set logscale y 2\n\
+             $series_0 << EOD\n0\t1\n1\t2\nEOD\n\
+             $series_1 << EOD\n0\t3\nEOD\n\
+             \n\
+             set title \"Comparison\" noenhanced\n\
+             plot $series_0 using 1:2 with lines title \"first\" noenhanced dashtype 1 linecolor 1, \\\n\t\
+             $series_1 using 1:2 with lines title \"1\" noenhanced dashtype 2 linecolor 2\n"
+        );
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(
+            quote("C:\\Users\\me\\data.txt"),
+            "\"C:\\\\Users\\\\me\\\\data.txt\""
+        );
+        assert_eq!(quote("two\nlines"), "\"two\\nlines\"");
+        assert_eq!(quote("`rm -rf ~`"), "\"\\140rm -rf ~\\140\"");
+        assert_eq!(quote("bell\u{7}"), "\"bell\\007\"");
+        assert_eq!(text("x_1^2 @ {a}"), "\"x_1^2 @ {a}\" noenhanced");
+        assert_eq!(
+            path(std::path::Path::new("runs/\"quoted\"/data.txt")),
+            "\"runs/\\\"quoted\\\"/data.txt\""
+        );
+    }
+
+    #[test]
+    fn tricky_strings() {
+        let mut first = pre::Sequence::new(vec![1, 2]);
+        first.set_title("it's `date`");
+        let mut seqs = first + pre::Sequence::new(vec![3]);
+        seqs.set_id("tricky_strings_test")
+            .set_title("Results in \"C:\\runs\"\nsecond line")
+            .set_labelx("x_i^2 @home");
+        let script = seqs.plot_script();
+
+        assert!(script
+            .contains("set title \"Results in \\\"C:\\\\runs\\\"\\nsecond line\" noenhanced\n"));
+        assert!(script.contains("set xlabel \"x_i^2 @home\" noenhanced\n"));
+        assert!(script.contains("title \"it's \\140date\\140\" noenhanced dashtype 1"));
+    }
+
+    #[test]
+    fn grids() {
+        let heatmap = pre::Heatmap::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        let script = (heatmap.clone() + heatmap).plot_with(&Gnuplot).unwrap();
+        assert!(script.contains("$grid_1 << EOD\n0\t0\t1\n0\t1\t2\n\n1\t0\t3\n1\t1\t4\nEOD\n"));
+        assert!(
+            script.contains("set multiplot layout 2,1 rowsfirst downwards title \"\" noenhanced\n")
+        );
+        assert!(script.contains(
+            "set title \"0\" noenhanced\nset pm3d map\nsplot $grid_0 using 1:2:3 notitle\n"
+        ));
+        assert!(script.ends_with("unset multiplot\n"));
+    }
+}
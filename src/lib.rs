@@ -12,11 +12,15 @@
 //! and does not need Rust computing power.
 //! Therefore, once you achieve the data in Rust, save it, have a quick glance, and
 //! leave a simple gnuplot-script to start the fine tunning of your perfect plot.
+//! If you fine-tune in Python, leave a matplotlib script instead, see [Matplotlib].
 //!
 //! # Remarks
 //!
 //! All data will be saved under the folder "target\\preexplorer\\data" in the main directory.
 //! Plot scripts are saved under the foleder "target\\preexplorer\\plots".
+//! The root directory "target\\preexplorer" can be changed for the whole program
+//! with [set_root_dir] or the environment variable ``PREEXPLORER_ROOT_DIR``,
+//! and for a single struct with the ``set_root_dir`` method of [Configurable].
 //!
 //! Recall that you will need to [install gnuplot](http://www.gnuplot.info/download.html)
 //! to use the crate at its full potential.
@@ -74,31 +78,69 @@
 //! Check out the [gallery] for more.
 //!
 //! [gallery]: https://github.com/rasa200/preexplorer#gallery
+//! [set_root_dir]: fn.set_root_dir.html
+//! [Configurable]: trait.Configurable.html
+//! [Matplotlib]: trait.Matplotlib.html
 //!
 //! # Optional features
 //!
 //! - **`use-serde`** — Enables [serde](https://crates.io/crates/serde) support.
+//! - **`plotters`** — Enables [Render], drawing png and svg images directly from Rust
+//!   with [plotters](https://crates.io/crates/plotters), without gnuplot.
+//!
+//! [Render]: trait.Render.html
 
+/// Archives with everything needed to plot elsewhere.
+mod bundle;
 /// Struct with all configurations for saving and ploting.
 mod configuration;
+/// 3-dimensional surface.
+mod contour;
 /// Generic multi-dimensional data. Not automatically ploted.
 mod data;
 /// Histograms or realizations of the same variable. Empirical densities.
 mod density;
 /// Errors wrapper from writting data.
 pub mod errors;
+pub mod figure;
+/// Self-contained gnuplot scripts from figures.
+mod gnuplot;
+/// Reading data files back.
+mod load;
+/// Standalone Python scripts plotting saved data with matplotlib.
+mod matplotlib;
 /// (Squared) Matrices.
 mod matrix;
 /// Time-series, indexed by a subset of R.
 mod process;
+/// Appending data to data files while it is produced.
+mod recorder;
+/// Drawing images directly from Rust.
+#[cfg(feature = "plotters")]
+mod render;
+/// Single HTML files collecting many plots.
+mod report;
+/// Per-run directories with a manifest of everything produced.
+mod run;
+/// Unordered points in two or three dimensions.
+mod scatter;
 /// Process indexed by 1, 2, 3, ...
 mod sequence;
-/// 3-dimensional surface.
-mod contour;
+/// Rotatable surfaces of values in a grid.
+mod surface;
+/// Points in the two-dimensional simplex.
+mod ternary;
+/// Drawing plots with characters, in the terminal.
+mod text;
 /// Traits for easy use or self implmentation.
 pub mod traits;
-/// All you ussually need.
+/// Arrows of vectors in a grid.
+mod vector_field;
+/// Vega-Lite specifications of plots, for browsers.
+mod vega;
 pub mod prelude {
+    //! All you ussually need.
+    //!
     //! Easily start preexploring you results.
     //!
     //! # Examples
@@ -125,23 +167,97 @@ pub mod prelude {
     //! ```
     pub use crate as pre;
     pub use crate::traits::*;
+    #[cfg(feature = "plotters")]
+    pub use crate::Render;
+    pub use crate::TextPlot;
 }
 
 /// Directory paths.
 mod constants {
-    /// Path the data directory.
+    /// Default path of the root directory, where all artifacts are saved.
+    pub const ROOT_DIR: [&str; 2] = [r"target", "preexplorer"];
+    /// Environment variable that, if set, overrides the default root directory.
+    pub const ROOT_DIR_VAR: &str = "PREEXPLORER_ROOT_DIR";
+    /// Name of the data directory inside the root directory.
+    pub(crate) const DATA_FOLDER: &str = "data";
+    /// Name of the plot scripts directory inside the root directory.
+    pub(crate) const PLOT_FOLDER: &str = "plots";
+    /// Default path the data directory.
     pub const DATA_DIR: [&str; 3] = [r"target", "preexplorer", "data"];
-    /// Path the plot scripts directory.
+    /// Default path the plot scripts directory.
     pub const PLOT_DIR: [&str; 3] = [r"target", "preexplorer", "plots"];
 }
 
 /// Overall, generic functions of the crate
 mod functions {
+    use crate::constants::{ROOT_DIR, ROOT_DIR_VAR};
+    use crate::errors::PreexplorerError;
+    use std::path::{Path, PathBuf};
+    use std::sync::RwLock;
+
+    /// Root directory set from code, which has priority over the environment variable.
+    static ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+    /// Sets the root directory, where all artifacts are saved, for the whole program.
+    /// Passing ``None`` goes back to the default behaviour.
+    ///
+    /// # Remarks
+    ///
+    /// Only structs created afterwards are affected. To change the root directory of a
+    /// single struct, see the ``set_root_dir`` method of the [Configurable] trait.
+    ///
+    /// # Examples
+    ///
+    /// Saving all artifacts of a run in a results volume.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// pre::set_root_dir("/results/experiment_1");
+    /// (0..10).preexplore().plot("my_identifier").unwrap();
+    /// assert_eq!(pre::root_dir(), std::path::Path::new("/results/experiment_1"));
+    /// ```
+    ///
+    /// [Configurable]: trait.Configurable.html
+    pub fn set_root_dir<T, P>(root: T)
+    where
+        T: Into<Option<P>>,
+        P: AsRef<Path>,
+    {
+        let root: Option<P> = root.into();
+        replace_root_dir(root.map(|r| r.as_ref().to_path_buf()));
+    }
+
+    /// Sets the root directory from code, returning the previous one.
+    pub(crate) fn replace_root_dir(root: Option<PathBuf>) -> Option<PathBuf> {
+        std::mem::replace(&mut *ROOT.write().unwrap(), root)
+    }
+
+    /// Lock for tests that change the root directory of the whole program.
+    #[cfg(test)]
+    pub(crate) static ROOT_DIR_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    /// Root directory where all artifacts are saved.
+    ///
+    /// In order of priority, it is
+    /// 1. the one given by [set_root_dir],
+    /// 2. the value of the environment variable ``PREEXPLORER_ROOT_DIR``,
+    /// 3. ``target/preexplorer``.
+    ///
+    /// [set_root_dir]: fn.set_root_dir.html
+    pub fn root_dir() -> PathBuf {
+        if let Some(root) = ROOT.read().unwrap().as_ref() {
+            return root.clone();
+        }
+        match std::env::var_os(ROOT_DIR_VAR) {
+            Some(root) if !root.is_empty() => PathBuf::from(root),
+            _ => ROOT_DIR.iter().collect(),
+        }
+    }
 
     /// Removes generated artifacts
     ///
     /// Inspired by the [`cargo clean`] command, this methods removes all
-    /// artifacts from the target directory that `preexplorer` has generated in the past.
+    /// artifacts from the root directory that `preexplorer` has generated in the past.
+    /// See [root_dir] for the current root directory.
     ///
     /// # Remarks
     ///
@@ -154,16 +270,17 @@ mod functions {
     /// This function will return an error in the following situations,
     /// but is not limited to just these cases:
     /// - The user lacks permissions to perform [`std::fs::metadata`] call
-    /// on the relevant paths.
+    ///   on the relevant paths.
     /// - Either [`std::fs::remove_file`] or [`std::fs::remove_dir`] errors.
     ///
     /// [`cargo clean`]: https://doc.rust-lang.org/cargo/commands/cargo-clean.html
     /// [`std::fs::metadata`]: https://doc.rust-lang.org/std/fs/fn.metadata.html
     /// [`std::fs::remove_file`]: https://doc.rust-lang.org/std/fs/fn.remove_file.html
     /// [`std::fs::remove_dir`]: https://doc.rust-lang.org/std/fs/fn.remove_dir.html
+    /// [root_dir]: fn.root_dir.html
     pub fn clean() -> Result<(), crate::errors::PreexplorerError> {
-        let path = "./target/preexplorer";
-        match std::fs::metadata(path) {
+        let path = root_dir();
+        match std::fs::metadata(&path) {
             Err(e) => match e.kind() {
                 std::io::ErrorKind::NotFound => Ok(()),
                 _ => Err(crate::errors::PreexplorerError::Removing(
@@ -179,21 +296,126 @@ mod functions {
             }),
         }
     }
+
+    /// Runs gnuplot on a script.
+    ///
+    /// If ``wait`` is true, it waits for gnuplot to finish and reports its failures,
+    /// otherwise it only reports failures to start gnuplot.
+    /// When waiting, the output of gnuplot is captured and only used to build errors.
+    /// If ``from_dir`` is true, gnuplot runs from the directory of the script,
+    /// which is needed for scripts with relative paths.
+    pub(crate) fn run_gnuplot(
+        script: &Path,
+        wait: bool,
+        from_dir: bool,
+    ) -> Result<(), PreexplorerError> {
+        let mut command = std::process::Command::new("gnuplot");
+        match (from_dir, script.parent(), script.file_name()) {
+            (true, Some(dir), Some(file_name)) if !dir.as_os_str().is_empty() => {
+                command.current_dir(dir).arg(file_name);
+            }
+            _ => {
+                command.arg(script);
+            }
+        }
+        let spawn_error = |e: std::io::Error| match e.kind() {
+            std::io::ErrorKind::NotFound => PreexplorerError::GnuplotNotFound(e),
+            _ => PreexplorerError::Plotting(e),
+        };
+
+        if !wait {
+            command.spawn().map_err(spawn_error)?;
+            return Ok(());
+        }
+
+        let output = command
+            .stdin(std::process::Stdio::null())
+            .output()
+            .map_err(spawn_error)?;
+        if output.status.success() {
+            // Warnings of successful runs are discarded
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            Err(PreexplorerError::from_gnuplot_stderr(output.status, stderr))
+        }
+    }
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::prelude::*;
+
+        #[test]
+        fn root_dir_priority() -> Result<(), PreexplorerError> {
+            let _lock = ROOT_DIR_TEST_LOCK.lock().unwrap();
+            let default_root: PathBuf = ROOT_DIR.iter().collect();
+            let env_root = std::env::temp_dir().join("preexplorer_env_root");
+            std::env::set_var(ROOT_DIR_VAR, &env_root);
+            assert_eq!(root_dir(), env_root);
+
+            let code_root = std::env::temp_dir().join("preexplorer_code_root");
+            set_root_dir(&code_root);
+            assert_eq!(root_dir(), code_root);
+
+            // Saving and cleaning respect the root directory
+            (0..10).preexplore().save_with_id("root_dir_test")?;
+            assert!(code_root.join("data").join("root_dir_test.txt").exists());
+            clean()?;
+            assert!(!code_root.exists());
+
+            set_root_dir::<_, PathBuf>(None);
+            assert_eq!(root_dir(), env_root);
+            std::env::remove_var(ROOT_DIR_VAR);
+            assert_eq!(root_dir(), default_root);
+            Ok(())
+        }
+
+        #[test]
+        fn comparison_root_dir() -> Result<(), PreexplorerError> {
+            let root = std::env::temp_dir().join("preexplorer_comparison_root");
+            let mut seqs = (0..10).preexplore() + (0..5).preexplore();
+            seqs.set_root_dir(&root)
+                .plot_later("comparison_root_test")?;
+
+            assert!(root
+                .join("data")
+                .join("comparison_root_test_0.txt")
+                .exists());
+            assert!(root
+                .join("data")
+                .join("comparison_root_test_1.txt")
+                .exists());
+            assert!(root.join("plots").join("comparison_root_test.gnu").exists());
+            std::fs::remove_dir_all(root)?;
+            Ok(())
+        }
+    }
 }
 
-pub use self::configuration::{Configuration, Style};
-pub use self::constants::{DATA_DIR, PLOT_DIR};
-pub use self::data::Data;
+pub use self::configuration::{save::DataFormat, Configuration, Publication, Style};
+pub use self::constants::{DATA_DIR, PLOT_DIR, ROOT_DIR, ROOT_DIR_VAR};
+pub use self::contour::{Contour, Contours};
+pub use self::data::{Data, Role};
 pub use self::density::{Densities, Density};
 pub use self::functions::*;
+pub use self::gnuplot::Gnuplot;
 pub use self::matrix::{Heatmap, Heatmaps};
-pub use self::contour::{Contour};
 pub use self::process::{
     Process, ProcessBin, ProcessBins, ProcessError, ProcessErrors, ProcessViolin, ProcessViolins,
     Processes,
 };
+pub use self::recorder::Recorder;
+#[cfg(feature = "plotters")]
+pub use self::render::{Image, Render};
+pub use self::report::Report;
+pub use self::run::Run;
+pub use self::scatter::{Scatter, Scatters};
 pub use self::sequence::{
     Sequence, SequenceBin, SequenceBins, SequenceError, SequenceErrors, SequenceViolin,
     SequenceViolins, Sequences,
 };
+pub use self::surface::{Surface, Surfaces};
+pub use self::ternary::{Ternaries, Ternary};
+pub use self::text::{Text, TextPlot};
 pub use self::traits::*;
+pub use self::vector_field::VectorField;
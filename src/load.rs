@@ -0,0 +1,255 @@
+// Structs
+use crate::configuration::save::format::{split_csv, split_gnuplot, DataFormat};
+use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
+
+// Traits
+use crate::traits::Configurable;
+use core::fmt::Display;
+use std::str::FromStr;
+
+/// Contents of a data file written by [Saveable].
+///
+/// [Saveable]: trait.Saveable.html
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct DataFile {
+    pub(crate) title: Option<String>,
+    pub(crate) id: Option<String>,
+    pub(crate) date: Option<chrono::DateTime<chrono::Local>>,
+    pub(crate) format: DataFormat,
+    /// Blocks separated by blank lines, each with its rows (and their line numbers) of columns.
+    pub(crate) blocks: Vec<Vec<(usize, Vec<String>)>>,
+}
+
+impl DataFile {
+    /// Reads and splits a data file.
+    pub(crate) fn read<P: AsRef<Path>>(path: P) -> Result<DataFile, PreexplorerError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(PreexplorerError::Reading)?;
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let format = match path.extension().and_then(|s| s.to_str()) {
+            Some(extension) => DataFormat::from_str(extension).unwrap_or_default(),
+            None => DataFormat::default(),
+        };
+        Ok(DataFile::parse(&contents, stem, format))
+    }
+
+    /// Splits the contents of a data file with the given file stem.
+    ///
+    /// The header is made of the title, the id and the date, in this order,
+    /// where the title and the id might be missing. When only one of them is present,
+    /// it is taken to be the id if it coincides with the file stem.
+    ///
+    /// In CSV, which has no header, the row of column names is skipped
+    /// and blocks are given by the last column.
+    pub(crate) fn parse(contents: &str, stem: &str, format: DataFormat) -> DataFile {
+        let mut header = Vec::new();
+        let mut blocks = Vec::new();
+        let mut block = Vec::new();
+        let mut column_names = format.separator().is_some();
+        let mut block_index = None;
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let trimmed = line.trim();
+            if let Some(comment) = trimmed.strip_prefix('#') {
+                if blocks.is_empty() && block.is_empty() {
+                    header.push(comment.strip_prefix(' ').unwrap_or(comment).to_string());
+                }
+            } else if trimmed.is_empty() {
+                if !block.is_empty() {
+                    blocks.push(std::mem::take(&mut block));
+                }
+            } else if column_names {
+                column_names = false;
+            } else {
+                let columns = match format.separator() {
+                    Some(_) => {
+                        let mut columns = split_csv(trimmed);
+                        let index = columns.pop();
+                        if block_index.is_some() && index != block_index && !block.is_empty() {
+                            blocks.push(std::mem::take(&mut block));
+                        }
+                        block_index = index;
+                        columns
+                    }
+                    None => split_gnuplot(trimmed),
+                };
+                block.push((line_number, columns));
+            }
+        }
+        if !block.is_empty() {
+            blocks.push(block);
+        }
+
+        let date = header.last().and_then(|last| parse_date(last));
+        if date.is_some() {
+            header.pop();
+        }
+        let (title, id) = match header.len() {
+            0 => (None, None),
+            1 if header[0] == stem => (None, header.pop()),
+            1 => (header.pop(), None),
+            _ => (Some(header[0].clone()), Some(header[1].clone())),
+        };
+
+        DataFile {
+            title,
+            id,
+            date,
+            format,
+            blocks,
+        }
+    }
+
+    /// Restores title, id, date and data format in the configuration of a struct.
+    pub(crate) fn restore_header<C: Configurable>(&self, item: &mut C) {
+        if self.format != item.data_format() {
+            let _ = item.set_data_format(self.format);
+        }
+        if let Some(title) = &self.title {
+            item.set_title(title);
+        }
+        if let Some(id) = &self.id {
+            item.set_id(id);
+        }
+        if let Some(date) = self.date {
+            item.set_date(date);
+        }
+    }
+
+    /// All rows, regardless of the block they belong to.
+    pub(crate) fn rows(&self) -> impl Iterator<Item = &(usize, Vec<String>)> {
+        self.blocks.iter().flatten()
+    }
+}
+
+/// Parses the entry ``column`` of a row.
+pub(crate) fn parse_entry<T>(
+    row: &(usize, Vec<String>),
+    column: usize,
+) -> Result<T, PreexplorerError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    let (line, columns) = row;
+    let entry = columns
+        .get(column)
+        .ok_or_else(|| PreexplorerError::Parsing {
+            line: *line,
+            message: format!("expected at least {} columns", column + 1),
+        })?;
+    entry.parse().map_err(|e| PreexplorerError::Parsing {
+        line: *line,
+        message: format!("could not parse {:?}: {}", entry, e),
+    })
+}
+
+/// Paths of the data files of a comparison, ``{id}_0``, ``{id}_1``, etc.,
+/// where ``path`` is the data path of the comparison.
+pub(crate) fn comparison_paths<P: AsRef<Path>>(path: P) -> Result<Vec<PathBuf>, PreexplorerError> {
+    let path = path.as_ref();
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let mut paths = Vec::new();
+    loop {
+        let mut inner_path = path.with_file_name(format!("{}_{}", stem, paths.len()));
+        if let Some(extension) = path.extension() {
+            inner_path.set_extension(extension);
+        }
+        if !inner_path.exists() {
+            break;
+        }
+        paths.push(inner_path);
+    }
+
+    if paths.is_empty() {
+        let message = format!("No data files of the form {}_0, {}_1, etc.", stem, stem);
+        return Err(PreexplorerError::Reading(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            message,
+        )));
+    }
+    Ok(paths)
+}
+
+/// Parses dates as written in headers.
+fn parse_date(s: &str) -> Option<chrono::DateTime<chrono::Local>> {
+    chrono::DateTime::parse_from_str(s.trim(), "%Y-%m-%d %H:%M:%S%.f %:z")
+        .ok()
+        .map(|date| date.with_timezone(&chrono::Local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header() {
+        let date = chrono::Local::now();
+        let contents = format!("# My title\n# my_id\n# {}\n0\t1\n1\t2\n", date);
+        let file = DataFile::parse(&contents, "my_id", DataFormat::Gnuplot);
+        assert_eq!(file.title, Some(String::from("My title")));
+        assert_eq!(file.id, Some(String::from("my_id")));
+        assert_eq!(file.date, Some(date));
+
+        let contents = format!("# my_id\n# {}\n0\t1\n", date);
+        let file = DataFile::parse(&contents, "my_id", DataFormat::Gnuplot);
+        assert_eq!(file.title, None);
+        assert_eq!(file.id, Some(String::from("my_id")));
+
+        let contents = format!("# My legend\n# {}\n0\t1\n", date);
+        let file = DataFile::parse(&contents, "my_id_0", DataFormat::Gnuplot);
+        assert_eq!(file.title, Some(String::from("My legend")));
+        assert_eq!(file.id, None);
+
+        let file = DataFile::parse("0\t1\n", "my_id", DataFormat::Gnuplot);
+        assert_eq!(file.title, None);
+        assert_eq!(file.id, None);
+        assert_eq!(file.date, None);
+    }
+
+    #[test]
+    fn blocks() {
+        let file = DataFile::parse(
+            "# my_id\n0\t1\t2\n0\t2\t3\n\n1\t1\t4\n\n\n1\t2\t5\n",
+            "my_id",
+            DataFormat::Gnuplot,
+        );
+        assert_eq!(file.blocks.len(), 3);
+        assert_eq!(file.blocks[0].len(), 2);
+        assert_eq!(
+            file.blocks[2][0],
+            (8, vec!["1".into(), "2".into(), "5".into()])
+        );
+        assert_eq!(file.rows().count(), 4);
+    }
+
+    #[test]
+    fn csv() {
+        let contents = "x,y,value,block\n0,1,2,0\n0,2,3,0\n1,1,4,1\n1,2,\"a b\",1\n";
+        let file = DataFile::parse(contents, "my_id", DataFormat::Csv);
+        assert_eq!(file.id, None);
+        assert_eq!(file.blocks.len(), 2);
+        assert_eq!(
+            file.blocks[0][0],
+            (2, vec!["0".into(), "1".into(), "2".into()])
+        );
+        assert_eq!(file.blocks[1][1].1[2], "a b");
+    }
+
+    #[test]
+    fn entries() {
+        let row = (3, vec![String::from("1.5"), String::from("a")]);
+        assert_eq!(parse_entry::<f64>(&row, 0).unwrap(), 1.5);
+        match parse_entry::<f64>(&row, 1) {
+            Err(PreexplorerError::Parsing { line, .. }) => assert_eq!(line, 3),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+        assert!(parse_entry::<f64>(&row, 2).is_err());
+    }
+}
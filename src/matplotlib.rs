@@ -0,0 +1,459 @@
+// Structs
+use crate::configuration::plot::style::Style;
+use crate::configuration::Configuration;
+use std::path::Path;
+
+// Traits
+use crate::traits::Configurable;
+
+/// Imports and helper functions shared by all matplotlib scripts.
+const PRELUDE: &str = r##"#!/usr/bin/env python3
+# Matplotlib script generated by preexplorer, reading the saved data files.
+import csv
+import os
+import shlex
+
+import matplotlib.pyplot as plt
+import numpy as np
+from matplotlib import colors, ticker
+
+# Dash patterns, in the order of gnuplot dashtypes
+DASHES = ["-", "--", ":", "-.", (0, (5, 2, 1, 2, 1, 2))]
+# Draw style of gnuplot step styles
+STEPS = {"steps": "steps-post", "fsteps": "steps-pre", "histeps": "steps-mid"}
+# Markers of gnuplot point styles
+MARKERS = {"points": "+", "dots": "."}
+
+
+def here(path):
+    """Path relative to the directory of this script."""
+    return os.path.join(os.path.dirname(os.path.abspath(__file__)), path)
+
+
+def load(path):
+    """Blocks of rows of numbers in a data file, separated by blank lines."""
+    blocks = [[]]
+    with open(path) as data_file:
+        for line in data_file:
+            line = line.strip()
+            if not line:
+                if blocks[-1]:
+                    blocks.append([])
+                continue
+            if line.startswith("#"):
+                continue
+            try:
+                blocks[-1].append([float(value) for value in line.replace(",", " ").split()])
+            except ValueError:
+                pass  # Names of the columns
+    return [np.array(block) for block in blocks if block]
+
+
+def table(path):
+    """All rows of numbers in a data file."""
+    blocks = load(path)
+    return np.concatenate(blocks) if blocks else np.empty((0, 3))
+
+
+def rows(path):
+    """All rows of a data file as lists of strings, without comments nor names of the columns."""
+    with open(path) as data_file:
+        lines = [line for line in data_file if line.strip() and not line.lstrip().startswith("#")]
+    if path.endswith(".csv"):
+        return list(csv.reader(lines))[1:]
+    return [shlex.split(line) for line in lines]
+
+
+def column(data, i):
+    """Values of a column of rows, as numbers."""
+    return np.array([float(row[i]) for row in data])
+
+
+def draw(ax, x, y, style="lines", dashtype=1, **kwargs):
+    """Draws a series with a gnuplot style."""
+    linestyle = DASHES[(dashtype - 1) % len(DASHES)]
+    if style == "points":
+        return ax.plot(x, y, marker="+", linestyle="none", **kwargs)
+    if style == "dots":
+        return ax.plot(x, y, marker=".", markersize=1, linestyle="none", **kwargs)
+    if style == "linespoints":
+        return ax.plot(x, y, marker="+", linestyle=linestyle, **kwargs)
+    if style == "impulses":
+        return ax.vlines(x, 0, y, linestyles=linestyle, **kwargs)
+    if style == "boxes":
+        width = np.min(np.diff(x)) if len(x) > 1 else 1.
+        return ax.bar(x, y, width=width, alpha=0.5, **kwargs)
+    return ax.plot(x, y, linestyle=linestyle, drawstyle=STEPS.get(style, "default"), **kwargs)
+
+
+def kde(values, points=200):
+    """Gaussian kernel density estimate, with Silverman's rule of thumb as bandwidth."""
+    bandwidth = 1.06 * np.std(values) * len(values) ** -0.2 or 1.
+    grid = np.linspace(values.min() - 3 * bandwidth, values.max() + 3 * bandwidth, points)
+    density = np.exp(-0.5 * ((grid[:, None] - values[None, :]) / bandwidth) ** 2).sum(axis=1)
+    return grid, density / (len(values) * bandwidth * np.sqrt(2 * np.pi))
+
+
+def bins(ax, x, values, binwidth, **kwargs):
+    """Histogram of the values, normalized and drawn horizontally from x."""
+    start = np.floor(values.min() / binwidth) * binwidth
+    edges = np.arange(start, values.max() + binwidth, binwidth)
+    if len(edges) < 2:
+        edges = np.array([start, start + binwidth])
+    density, edges = np.histogram(values, bins=edges, density=True)
+    return ax.barh(edges[:-1], density, height=binwidth, left=x, align="edge", alpha=0.5, **kwargs)
+
+
+def violins(ax, blocks, **kwargs):
+    """Densities of the values (second column) of each block, drawn symmetrically around
+    its first column, and normalized so that the widest has width one."""
+    densities = [kde(block[:, 1]) for block in blocks]
+    renormalize = 2 * max(density.max() for _, density in densities)
+    for block, (grid, density) in zip(blocks, densities):
+        x = block[0, 0]
+        ax.fill_betweenx(grid, x - density / renormalize, x + density / renormalize, alpha=0.5, **kwargs)
+        kwargs.pop("label", None)
+
+
+def heatmap(fig, ax, data, label=None, **kwargs):
+    """Colored grid of the values (third column) in each point of the first two columns."""
+    x, y = np.unique(data[:, 0]), np.unique(data[:, 1])
+    z = data[:, 2].reshape(len(x), len(y)).T
+    mesh = ax.pcolormesh(x, y, z, shading="nearest", **kwargs)
+    colorbar = fig.colorbar(mesh, ax=ax)
+    if label is not None:
+        colorbar.set_label(label)
+    return mesh
+
+
+def contour(ax, data, levels=None, labels=False, label=None, **kwargs):
+    """Isolines of the values (third column) over the points of the first two columns, at the
+    given levels or number of levels, labeled by their value if ``labels``."""
+    lines = ax.tricontour(data[:, 0], data[:, 1], data[:, 2], levels=levels, **kwargs)
+    if labels:
+        ax.clabel(lines)
+    if label is not None:
+        ax.plot([], [], color=kwargs.get("colors"), label=label)
+    return lines
+
+
+def surface(ax, blocks, pm3d=False, hidden=False, contours=False, **kwargs):
+    """Surface of the values (third column) over the grid of the first two columns, with one
+    block per value of the first column: colored by the values, as a wireframe with hidden
+    lines removed, or as a wireframe. Contours are projected on the base of the axes."""
+    x, y, z = (np.array([block[:, i] for block in blocks]) for i in range(3))
+    if pm3d:
+        kwargs.pop("color", None)
+        drawn = ax.plot_surface(x, y, z, cmap="viridis", **kwargs)
+    elif hidden:
+        color = kwargs.pop("color", "C0")
+        drawn = ax.plot_surface(x, y, z, color="white", edgecolor=color, shade=False, **kwargs)
+    else:
+        drawn = ax.plot_wireframe(x, y, z, **kwargs)
+    if contours:
+        ax.contour(x, y, z, zdir="z", offset=ax.get_zlim()[0])
+    return drawn
+
+
+def quiver(fig, ax, data, normalize=False, scale=1., magnitude=False, label=None, **kwargs):
+    """Arrows of the vectors (third and fourth columns) at the points of the first two columns,
+    of the same length if ``normalize``, multiplied by ``scale`` and colored by their magnitude
+    (fifth column) if ``magnitude``."""
+    x, y, u, v, norm = (data[:, i] for i in range(5))
+    if normalize:
+        lengths = np.where(norm > 0, norm, 1.)
+        u, v = u / lengths, v / lengths
+    arguments = [x, y, scale * u, scale * v]
+    if magnitude:
+        arguments.append(norm)
+        vmin, vmax = kwargs.pop("vmin", None), kwargs.pop("vmax", None)
+        kwargs.setdefault("norm", colors.Normalize(vmin, vmax))
+    arrows = ax.quiver(*arguments, angles="xy", scale_units="xy", scale=1, **kwargs)
+    if magnitude:
+        colorbar = fig.colorbar(arrows, ax=ax)
+        if label is not None:
+            colorbar.set_label(label)
+    return arrows
+
+
+def scatter(fig, ax, data, dimension, style="points", sizes=None, values=None, texts=None, colorbar=True, **kwargs):
+    """Unordered points in the first columns of the rows, with sizes, color values and texts
+    from the given columns, if any. The colorbar, if any, is labeled by ``colorbar``."""
+    points = np.array([[float(value) for value in row[:dimension]] for row in data]).reshape(-1, dimension)
+    if ax.name == "3d" and dimension == 2:
+        points = np.column_stack([points, np.zeros(len(points))])
+    kwargs.setdefault("marker", MARKERS.get(style, "o"))
+    if sizes is not None:
+        scale = np.array([float(row[sizes]) for row in data])
+        kwargs["s"] = (plt.rcParams["lines.markersize"] * scale) ** 2
+    if values is not None:
+        kwargs.pop("color", None)
+        kwargs["c"] = np.array([float(row[values]) for row in data])
+    paths = ax.scatter(*points.T, **kwargs)
+    if values is not None and colorbar is not False:
+        bar = fig.colorbar(paths, ax=ax)
+        if isinstance(colorbar, str):
+            bar.set_label(colorbar)
+    if texts is not None:
+        for point, row in zip(points, data):
+            ax.text(*point, " " + row[texts])
+    return paths
+
+"##;
+
+/// Opening of a matplotlib script: imports, helper functions and a figure with ``panels`` axes,
+/// with the title, labels, logarithmic scales, ranges and tics of the configuration.
+///
+/// The axes are available as ``ax``, if there is only one, and as the list ``axes``.
+pub(crate) fn opening_script(config: &Configuration, panels: usize) -> String {
+    opening(config, panels, false)
+}
+
+/// Opening of a matplotlib script with three-dimensional axes ``ax``, with the title,
+/// labels, logarithmic scales, ranges and tics of the configuration, also for the z axis.
+pub(crate) fn opening_script_3d(config: &Configuration) -> String {
+    opening(config, 1, true)
+}
+
+fn opening(config: &Configuration, panels: usize, three_d: bool) -> String {
+    let mut python_script = String::from(PRELUDE);
+
+    let mut axes = vec![
+        (
+            "x",
+            config.labelx(),
+            config.logx(),
+            config.rangex(),
+            config.ticsx(),
+        ),
+        (
+            "y",
+            config.labely(),
+            config.logy(),
+            config.rangey(),
+            config.ticsy(),
+        ),
+    ];
+    if three_d {
+        axes.push((
+            "z",
+            config.labelz(),
+            config.logz(),
+            config.rangez(),
+            config.ticsz(),
+        ));
+    }
+    let mut settings = Vec::new();
+    for (name, label, log, range, tics) in axes {
+        if let Some(label) = label {
+            settings.push(format!("ax.set_{}label({})", name, python_string(label)));
+        }
+        match log {
+            Some(base) if base > 0. => {
+                settings.push(format!("ax.set_{}scale(\"log\", base={})", name, base))
+            }
+            Some(_) => settings.push(format!("ax.set_{}scale(\"log\")", name)),
+            None => (),
+        }
+        if let Some((min, max)) = range {
+            settings.push(format!("ax.set_{}lim({}, {})", name, min, max));
+        }
+        match tics.map(|tics| tics.trim().parse::<f64>()) {
+            None => settings.push(format!("ax.set_{}ticks([])", name)),
+            Some(Ok(step)) if step > 0. => settings.push(format!(
+                "ax.{}axis.set_major_locator(ticker.MultipleLocator({}))",
+                name, step
+            )),
+            Some(_) => (),
+        }
+    }
+
+    if panels > 1 {
+        let rows = (panels as f64).sqrt().ceil();
+        let columns = (panels as f64 / rows).ceil();
+        python_script += &format!(
+            "fig, grid = plt.subplots({}, {}, squeeze=False)\n",
+            rows, columns
+        );
+        python_script += "axes = list(grid.flat)\n";
+        python_script += &format!("for ax in axes[{}:]:\n    ax.remove()\n", panels);
+        python_script += &format!("axes = axes[:{}]\n", panels);
+        if let Some(title) = config.title() {
+            python_script += &format!("fig.suptitle({})\n", python_string(title));
+        }
+        if !settings.is_empty() {
+            python_script += "for ax in axes:\n";
+            for setting in settings {
+                python_script += &format!("    {}\n", setting);
+            }
+        }
+    } else {
+        if three_d {
+            python_script += "fig, ax = plt.subplots(subplot_kw={\"projection\": \"3d\"})\n";
+        } else {
+            python_script += "fig, ax = plt.subplots()\n";
+        }
+        python_script += "axes = [ax]\n";
+        if let Some(title) = config.title() {
+            python_script += &format!("ax.set_title({})\n", python_string(title));
+        }
+        for setting in settings {
+            python_script += &format!("{}\n", setting);
+        }
+    }
+    python_script += "\n";
+
+    python_script
+}
+
+/// Ending of a matplotlib script: legends and either saving the figure into the output file,
+/// or showing it.
+pub(crate) fn ending_script(config: &Configuration) -> String {
+    let mut python_script = String::from("\n");
+
+    python_script += "for ax in axes:\n";
+    python_script += "    if ax.get_legend_handles_labels()[0]:\n";
+    python_script += "        ax.legend()\n";
+    match (config.output(), config.pause()) {
+        (Some(output), _) => {
+            python_script += &format!("fig.savefig({})\n", config.python_path(output))
+        }
+        (None, Some(pause)) if pause > 0. => {
+            python_script += "plt.show(block=False)\n";
+            python_script += &format!("plt.pause({})\n", pause);
+        }
+        (None, _) => python_script += "plt.show()\n",
+    }
+
+    python_script
+}
+
+/// Draws the first two columns of a data file as a series.
+pub(crate) fn series_script(
+    config: &Configuration,
+    path: &Path,
+    style: &Style,
+    dashtype: usize,
+    color: usize,
+    legend: Option<&str>,
+) -> String {
+    let legend = match legend {
+        Some(legend) => format!(", label={}", python_string(legend)),
+        None => String::new(),
+    };
+    format!(
+        "data = table({})\ndraw(ax, data[:, 0], data[:, 1], \"{}\", {}, color=\"C{}\"{})\n",
+        config.python_path(path),
+        style,
+        dashtype,
+        color,
+        legend
+    )
+}
+
+/// Keyword arguments for the colors of heatmaps: logarithmic scale and range of the z axis,
+/// and its label.
+pub(crate) fn color_arguments(config: &Configuration) -> String {
+    let mut arguments = norm_arguments(config);
+    if let Some(label) = config.labelz() {
+        arguments += &format!(", label={}", python_string(label));
+    }
+    arguments
+}
+
+/// Python string of a text, double-quoted.
+///
+/// Backslashes, double quotes and control characters (like newlines) are escaped,
+/// while other characters are written as they are, since scripts are encoded in UTF-8.
+pub(crate) fn python_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '\\' => quoted += "\\\\",
+            '"' => quoted += "\\\"",
+            '\n' => quoted += "\\n",
+            '\t' => quoted += "\\t",
+            '\r' => quoted += "\\r",
+            c if c.is_control() => quoted += &format!("\\x{:02x}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Python string of a path, with ``/`` as separator on every platform.
+pub(crate) fn path(path: &Path) -> String {
+    python_string(&crate::configuration::path::portable(path))
+}
+
+/// Keyword arguments for the scale of colors: logarithmic scale and range of the z axis.
+pub(crate) fn norm_arguments(config: &Configuration) -> String {
+    let (vmin, vmax) = match config.rangez() {
+        Some((min, max)) => (min.to_string(), max.to_string()),
+        None => (String::from("None"), String::from("None")),
+    };
+    match config.logz() {
+        Some(_) => format!(", norm=colors.LogNorm(vmin={}, vmax={})", vmin, vmax),
+        None if config.rangez().is_some() => format!(", vmin={}, vmax={}", vmin, vmax),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opening() {
+        let mut config = Configuration::default();
+        config
+            .set_title("Title")
+            .set_labelx("time")
+            .set_logy(2)
+            .set_rangex(0., 1.)
+            .set_ticsy::<_, &str>(None)
+            .set_ticsx(0.5);
+        let script = opening_script(&config, 1);
+        assert!(script.ends_with(
+            "fig, ax = plt.subplots()\n\
+             axes = [ax]\n\
+             ax.set_title(\"Title\")\n\
+             ax.set_xlabel(\"time\")\n\
+             ax.set_xlim(0, 1)\n\
+             ax.xaxis.set_major_locator(ticker.MultipleLocator(0.5))\n\
+             ax.set_yscale(\"log\", base=2)\n\
+             ax.set_yticks([])\n\n"
+        ));
+
+        let script = opening_script(&config, 3);
+        assert!(script.contains("fig, grid = plt.subplots(2, 2, squeeze=False)\n"));
+        assert!(script.contains("for ax in axes[3:]:\n    ax.remove()\n"));
+        assert!(script.contains("fig.suptitle(\"Title\")\nfor ax in axes:\n    ax.set_xlabel"));
+    }
+
+    #[test]
+    fn ending() {
+        let mut config = Configuration::default();
+        assert!(ending_script(&config).ends_with("plt.show()\n"));
+        config.set_pause(2);
+        assert!(ending_script(&config).ends_with("plt.show(block=False)\nplt.pause(2)\n"));
+        config.set_output("image.pdf");
+        assert!(ending_script(&config).ends_with("fig.savefig(\"image.pdf\")\n"));
+        let output = config.plot_path().with_file_name("image.pdf");
+        config.set_output(output).set_relative_paths(true);
+        assert!(ending_script(&config).ends_with("fig.savefig(here(\"image.pdf\"))\n"));
+    }
+
+    #[test]
+    fn python_strings() {
+        assert_eq!(python_string("plain"), "\"plain\"");
+        assert_eq!(
+            python_string("C:\\runs\\\"a\"\nb"),
+            "\"C:\\\\runs\\\\\\\"a\\\"\\nb\""
+        );
+        assert_eq!(python_string("bell\u{7}"), "\"bell\\x07\"");
+        assert_eq!(python_string("é\u{301}λ"), "\"é\u{301}λ\"");
+    }
+}
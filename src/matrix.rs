@@ -1,7 +1,15 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::Path;
+
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{
+    Binary, Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite,
+};
 use core::fmt::Display;
 use core::ops::Add;
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 pub mod comparison;
 
@@ -110,6 +118,64 @@ where
     }
 }
 
+impl<T, S, U> Heatmap<T, S, U>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+    S: Display + Clone + FromStr,
+    <S as FromStr>::Err: Display,
+    U: Display + Clone + FromStr,
+    <U as FromStr>::Err: Display,
+{
+    /// Reads a ``Heatmap<T, S, U>`` from a data file written by it,
+    /// restoring title, id and date from the header.
+    ///
+    /// The grid is read from the first two columns: each block (separated by a blank line)
+    /// corresponds to one value of ``xs`` and runs over all values of ``ys``.
+    ///
+    /// # Examples
+    ///
+    /// Plotting later in another binary.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// let heatmap = pre::Heatmap::<f64, f64, f64>::load("target/preexplorer/data/my_identifier.txt").unwrap();
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Heatmap<T, S, U>, PreexplorerError> {
+        let file = crate::load::DataFile::read(path)?;
+        let xs = file
+            .blocks
+            .iter()
+            .map(|block| crate::load::parse_entry(&block[0], 0))
+            .collect::<Result<Vec<T>, _>>()?;
+        let ys = match file.blocks.first() {
+            Some(block) => block
+                .iter()
+                .map(|row| crate::load::parse_entry(row, 1))
+                .collect::<Result<Vec<S>, _>>()?,
+            None => Vec::new(),
+        };
+        for block in &file.blocks {
+            if block.len() != ys.len() {
+                return Err(PreexplorerError::Parsing {
+                    line: block[0].0,
+                    message: format!(
+                        "expected {} rows in the block, found {}",
+                        ys.len(),
+                        block.len()
+                    ),
+                });
+            }
+        }
+        let values = file
+            .rows()
+            .map(|row| crate::load::parse_entry(row, 2))
+            .collect::<Result<Vec<U>, _>>()?;
+        let mut heatmap = Heatmap::new(xs, ys, values);
+        file.restore_header(&mut heatmap);
+        Ok(heatmap)
+    }
+}
+
 impl<T, S, U> Add for Heatmap<T, S, U>
 where
     T: Display + Clone,
@@ -145,25 +211,32 @@ where
     S: Display + Clone,
     U: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.xs.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
         for i in 0..self.xs.len() {
             for j in 0..self.ys.len() {
-                plotable_data.push_str(&format!(
-                    "{}\t{}\t{}\n",
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}",
                     self.xs[i],
                     self.ys[j],
                     self.values[i * self.ys.len() + j]
-                ));
+                )?;
             }
-            plotable_data.push_str("\n");
+            writeln!(writer)?;
         }
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["x", "y", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -174,16 +247,103 @@ where
     U: Display + Clone,
 {
     fn plot_script(&self) -> String {
+        self.plot_script_from(&self.config.script_path(self.data_path()))
+    }
+}
+
+impl<T, S, U> Matplotlib for Heatmap<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        python_script += &format!(
+            "heatmap(fig, ax, table({}){})\n",
+            self.config.python_path(self.data_path()),
+            crate::matplotlib::color_arguments(&self.config),
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S, U> VegaLite for Heatmap<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let view = format!(
+            "{},\n{}",
+            crate::vega::size(),
+            crate::vega::heatmap_view(&self.config, &data)
+        );
+
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
+impl<T, S, U> Heatmap<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    /// Plot script reading the data from ``source``, a gnuplot data file specification.
+    fn plot_script_from(&self, source: &str) -> String {
         let mut gnuplot_script = self.opening_plot_script();
 
         gnuplot_script += "set pm3d map\n";
-        gnuplot_script += &format!("splot {:?} using 1:2:3\n", self.data_path(),);
+        gnuplot_script += &format!("splot {} using 1:2:3\n", source);
         gnuplot_script += &self.ending_plot_script();
 
         gnuplot_script
     }
 }
 
+impl<T, S, U> Binary for Heatmap<T, S, U>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+    U: Display + Clone + ToPrimitive,
+{
+    fn write_binary<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for i in 0..self.xs.len() {
+            let x = self.xs[i].to_f64().unwrap_or(f64::NAN).to_le_bytes();
+            for j in 0..self.ys.len() {
+                writer.write_all(&x)?;
+                writer.write_all(&self.ys[j].to_f64().unwrap_or(f64::NAN).to_le_bytes())?;
+                let value = self.values[i * self.ys.len() + j]
+                    .to_f64()
+                    .unwrap_or(f64::NAN);
+                writer.write_all(&value.to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Each value of ``xs`` gives a scan line of the grid, as blank lines do in text.
+    fn binary_plot_script(&self) -> String {
+        self.plot_script_from(&format!(
+            "{} binary record=({},{}) format=\"%3float64\" endian=little",
+            self.config.script_path(self.binary_data_path()),
+            self.ys.len(),
+            self.xs.len(),
+        ))
+    }
+}
+
 impl<T> From<ndarray::Array2<T>> for Heatmap<usize, usize, T>
 where
     T: Display + Clone,
@@ -198,3 +358,100 @@ where
         Heatmap::new(xs, ys, values)
     }
 }
+
+impl<T, S, U> Heatmap<T, S, U>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+    U: Display + Clone + ToPrimitive,
+{
+    /// Values at each point, for rendering.
+    pub(crate) fn grid(&self) -> crate::figure::Grid {
+        let mut points = Vec::with_capacity(self.values.len());
+        let mut values = self.values.iter();
+        for x in self.xs.iter() {
+            for y in self.ys.iter() {
+                if let Some(value) = values.next() {
+                    points.push((
+                        x.to_f64().unwrap_or(f64::NAN),
+                        y.to_f64().unwrap_or(f64::NAN),
+                        value.to_f64().unwrap_or(f64::NAN),
+                    ));
+                }
+            }
+        }
+        let mut grid = crate::figure::Grid::new(points);
+        grid.title = self.title().cloned();
+        grid
+    }
+}
+
+impl<T, S, U> Figurable for Heatmap<T, S, U>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+    U: Display + Clone + ToPrimitive,
+{
+    fn figure(&self) -> crate::figure::Figure {
+        let layer = crate::figure::Layer::Grid(self.grid());
+        crate::figure::Figure::new(&self.config, vec![layer])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load() -> Result<(), PreexplorerError> {
+        let values = vec![0., 1., 2., 3., 4., 5.];
+        let mut heatmap = Heatmap::new(vec![0, 1], vec![0.5, 1.5, 2.5], values);
+        heatmap
+            .set_id("heatmap_load_test")
+            .set_root_dir(std::env::temp_dir().join("preexplorer_heatmap_load"))
+            .save()?;
+
+        let loaded = Heatmap::<i32, f64, f64>::load(heatmap.data_path())?;
+        assert_eq!(loaded.xs, heatmap.xs);
+        assert_eq!(loaded.ys, heatmap.ys);
+        assert_eq!(loaded.values, heatmap.values);
+        assert_eq!(loaded.id(), heatmap.id());
+        Ok(())
+    }
+
+    #[test]
+    fn binary() -> Result<(), PreexplorerError> {
+        use core::convert::TryInto;
+        let mut heatmap = Heatmap::new(vec![0, 1], vec![0.5, 1.5, 2.5], vec![0, 1, 2, 3, 4, 5]);
+        heatmap
+            .set_id("heatmap_binary_test")
+            .set_root_dir(std::env::temp_dir().join("preexplorer_heatmap_binary"))
+            .save_binary()?;
+
+        let bytes = std::fs::read(heatmap.binary_data_path())?;
+        assert_eq!(bytes.len(), 6 * 3 * 8);
+        let record: Vec<f64> = bytes[3 * 8..6 * 8]
+            .chunks(8)
+            .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(record, vec![0., 1.5, 1.]);
+        assert!(heatmap
+            .binary_plot_script()
+            .contains("heatmap_binary_test.bin\" binary record=(3,2) format=\"%3float64\" endian=little using 1:2:3"));
+        Ok(())
+    }
+
+    #[test]
+    fn binary_from_array() -> Result<(), PreexplorerError> {
+        let mut heatmap = Heatmap::from(ndarray::arr2(&[[0_u64, 1], [2, 3]]));
+        heatmap
+            .set_id("heatmap_binary_array_test")
+            .set_root_dir(std::env::temp_dir().join("preexplorer_heatmap_binary"))
+            .save_binary()?;
+
+        let bytes = std::fs::read(heatmap.binary_data_path())?;
+        assert_eq!(bytes.len(), 4 * 3 * 8);
+        assert_eq!(bytes[8..16], 1_f64.to_le_bytes());
+        Ok(())
+    }
+}
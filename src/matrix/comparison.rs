@@ -1,10 +1,13 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 /// Comparison counter part of [Heatmap] struct.
 ///
@@ -49,6 +52,35 @@ where
     }
 }
 
+impl<T, S, U> Heatmaps<T, S, U>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+    S: Display + Clone + FromStr,
+    <S as FromStr>::Err: Display,
+    U: Display + Clone + FromStr,
+    <U as FromStr>::Err: Display,
+{
+    /// Reads a ``Heatmaps<T, S, U>`` from the data files written by it,
+    /// where ``path`` is the data path of the comparison, e.g. ``target/preexplorer/data/my_identifier.txt``.
+    /// The data of each heatmap is read from the files ``my_identifier_0.txt``, ``my_identifier_1.txt``, etc.
+    /// See [Heatmap::load] for more.
+    ///
+    /// [Heatmap::load]: struct.Heatmap.html#method.load
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Heatmaps<T, S, U>, PreexplorerError> {
+        let path = path.as_ref();
+        let data_set = crate::load::comparison_paths(path)?
+            .iter()
+            .map(crate::Heatmap::load)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut comparison = Heatmaps::new(data_set);
+        if let Some(id) = path.file_stem() {
+            comparison.set_id(id.to_string_lossy());
+        }
+        Ok(comparison)
+    }
+}
+
 impl<T, S, U> From<crate::Heatmap<T, S, U>> for Heatmaps<T, S, U>
 where
     T: Display + Clone,
@@ -138,8 +170,10 @@ where
         let columns = (self.data_set.len() as f64 / rows).ceil();
         let overall_title: &str = self.title().map(|s| s.as_str()).unwrap_or("");
         gnuplot_script += &format!(
-            "set multiplot layout {},{} rowsfirst downwards title \"{}\"\n",
-            rows, columns, overall_title
+            "set multiplot layout {},{} rowsfirst downwards title {}\n",
+            rows,
+            columns,
+            self.config.text_string(overall_title)
         );
 
         for (counter, heatmap) in self.data_set.iter().enumerate() {
@@ -156,37 +190,169 @@ where
                 None => counter.to_string(),
             };
 
-            gnuplot_script += &format!("set title \"{}\"\n", legend,);
+            gnuplot_script += &format!("set title {}\n", self.config.text_string(&legend));
             gnuplot_script += "set pm3d map\n";
-            gnuplot_script += &format!("splot {:?} using 1:2:3\n", inner_path,);
+            gnuplot_script += &format!(
+                "splot {} using 1:2:3\n",
+                self.config.script_path(&inner_path),
+            );
         }
-        gnuplot_script += "\n";
+        gnuplot_script += "unset multiplot\n";
         gnuplot_script += &self.ending_plot_script();
 
         gnuplot_script
     }
 }
 
+impl<T, S, U> Matplotlib for Heatmaps<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self
+            .config
+            .opening_matplotlib_script_multiplot(self.data_set.len());
+
+        for (counter, heatmap) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match heatmap.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            python_script += &format!(
+                "ax = axes[{}]\nax.set_title({})\nheatmap(fig, ax, table({}){})\n",
+                counter,
+                crate::matplotlib::python_string(&legend),
+                self.config.python_path(&inner_path),
+                crate::matplotlib::color_arguments(&self.config),
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S, U> VegaLite for Heatmaps<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut views = Vec::new();
+
+        for (counter, heatmap) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                heatmap,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match heatmap.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            views.push(format!(
+                "{{\"title\": {}, {}, {}}}",
+                crate::configuration::save::format::json_string(&legend),
+                crate::vega::size(),
+                crate::vega::heatmap_view(&self.config, &data),
+            ));
+        }
+
+        let rows = (self.data_set.len() as f64).sqrt().ceil();
+        let columns = (self.data_set.len() as f64 / rows).ceil();
+        let view = format!(
+            "\"columns\": {},\n\"concat\": [\n{}\n],\n\"resolve\": {{\"scale\": {{\"color\": \"independent\"}}}}",
+            columns,
+            views.join(",\n"),
+        );
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
 impl<T, S, U> Saveable for Heatmaps<T, S, U>
 where
     T: Display + Clone,
     S: Display + Clone,
     U: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for heatmap in self.data_set.iter() {
-            raw_data += &heatmap.plotable_data();
-            raw_data += "\n";
+            heatmap.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<W: Display>(&self, id: W) -> Result<&Self, PreexplorerError> {
         for (counter, heatmap) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            heatmap.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(heatmap, &inner_path)?;
         }
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
+}
+
+impl<T, S, U> Figurable for Heatmaps<T, S, U>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+    U: Display + Clone + ToPrimitive,
+{
+    /// Each heatmap in its own panel.
+    fn figure(&self) -> crate::figure::Figure {
+        let layers = self.data_set.iter().enumerate().map(|(counter, heatmap)| {
+            let mut grid = heatmap.grid();
+            grid.title.get_or_insert_with(|| counter.to_string());
+            crate::figure::Layer::Grid(grid)
+        });
+        crate::figure::Figure::new(&self.config, layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_script() {
+        let heatmap = crate::Heatmap::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        let mut heatmaps = heatmap.clone() + heatmap;
+        heatmaps
+            .set_id("heatmaps_test")
+            .set_output("heatmaps_test.png");
+        let script = heatmaps.plot_script();
+
+        assert!(script.contains("set multiplot layout 2,1 rowsfirst downwards"));
+        assert!(script.ends_with("using 1:2:3\nunset multiplot\nunset output\n"));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let heatmap = crate::Heatmap::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        let mut heatmaps = heatmap.clone() + heatmap.clone() + heatmap;
+        heatmaps
+            .set_id("heatmaps_matplotlib_test")
+            .set_title("Overall")
+            .set_rangez(0, 4);
+        let script = heatmaps.matplotlib_script();
+
+        assert!(script.contains("fig, grid = plt.subplots(2, 2, squeeze=False)\n"));
+        assert!(script.contains("fig.suptitle(\"Overall\")\n"));
+        assert!(script.contains("ax = axes[2]\nax.set_title(\"2\")\n"));
+        assert!(script.contains("heatmaps_matplotlib_test_2.txt\"), vmin=0, vmax=4)\n"));
+    }
 }
@@ -1,7 +1,15 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::Path;
+
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{
+    Binary, Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite,
+};
 use core::fmt::Display;
 use core::ops::Add;
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 pub mod bin;
 pub mod comparison;
@@ -87,6 +95,64 @@ where
     }
 }
 
+impl<T, S> Process<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    /// Starts recording the process under ``id``, appending new points to its data file
+    /// as they are produced. See [Recorder] for more.
+    ///
+    /// # Examples
+    ///
+    /// Recording a trajectory.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// let mut recorder = pre::Process::new(vec![0.], vec![1.]).record("my_identifier").unwrap();
+    /// recorder.push(1., 2.).unwrap();
+    /// recorder.finish().unwrap();
+    /// ```
+    ///
+    /// [Recorder]: struct.Recorder.html
+    pub fn record<U: Display>(self, id: U) -> Result<crate::Recorder<Self>, PreexplorerError> {
+        let rows = self.domain.len().min(self.image.len());
+        crate::Recorder::start(self, id, rows)
+    }
+}
+
+impl<T, S> Process<T, S>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+    S: Display + Clone + FromStr,
+    <S as FromStr>::Err: Display,
+{
+    /// Reads a ``Process<T, S>`` from a data file written by it,
+    /// restoring title, id and date from the header.
+    ///
+    /// # Examples
+    ///
+    /// Plotting later in another binary.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// let pro = pre::Process::<f64, f64>::load("target/preexplorer/data/my_identifier.txt").unwrap();
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Process<T, S>, PreexplorerError> {
+        let file = crate::load::DataFile::read(path)?;
+        let domain = file
+            .rows()
+            .map(|row| crate::load::parse_entry(row, 0))
+            .collect::<Result<Vec<T>, _>>()?;
+        let image = file
+            .rows()
+            .map(|row| crate::load::parse_entry(row, 1))
+            .collect::<Result<Vec<S>, _>>()?;
+        let mut pro = Process::new(domain, image);
+        file.restore_header(&mut pro);
+        Ok(pro)
+    }
+}
+
 impl<T, S> Add for Process<T, S>
 where
     T: Display + Clone,
@@ -119,17 +185,23 @@ where
     T: Display + Clone,
     S: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.domain.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-        for (time, value) in self.domain.clone().into_iter().zip(self.image.clone()) {
-            plotable_data.push_str(&format!("{}\t{}\n", time, value));
+        for (time, value) in self.domain.iter().zip(self.image.iter()) {
+            writeln!(writer, "{}\t{}", time, value)?;
         }
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["domain", "image"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -139,13 +211,72 @@ where
     S: Display + Clone,
 {
     fn plot_script(&self) -> String {
+        self.plot_script_from(&self.config.script_path(self.data_path()))
+    }
+}
+
+impl<T, S> Matplotlib for Process<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        let dashtype = self.dashtype().unwrap_or(1);
+        python_script += &crate::matplotlib::series_script(
+            &self.config,
+            self.data_path(),
+            self.style(),
+            dashtype,
+            0,
+            None,
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S> VegaLite for Process<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layer = crate::vega::series_layer(
+            &self.config,
+            &data,
+            ("domain", "image"),
+            self.style(),
+            self.dashtype().unwrap_or(1),
+            None,
+        );
+
+        crate::vega::layered_spec(&self.config, &[layer])
+    }
+}
+
+impl<T, S> Process<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    /// Plot script reading the data from ``source``, a gnuplot data file specification.
+    fn plot_script_from(&self, source: &str) -> String {
         let mut gnuplot_script = self.opening_plot_script();
 
         let dashtype = self.dashtype().unwrap_or(1);
 
         gnuplot_script += &format!(
-            "plot {:?} using 1:2 with {} dashtype {}\n",
-            self.data_path(),
+            "plot {} using 1:2 with {} dashtype {}\n",
+            source,
             self.style(),
             dashtype,
         );
@@ -154,3 +285,58 @@ where
         gnuplot_script
     }
 }
+
+impl<T, S> Binary for Process<T, S>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+{
+    fn write_binary<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for (time, value) in self.domain.iter().zip(self.image.iter()) {
+            writer.write_all(&time.to_f64().unwrap_or(f64::NAN).to_le_bytes())?;
+            writer.write_all(&value.to_f64().unwrap_or(f64::NAN).to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn binary_plot_script(&self) -> String {
+        self.plot_script_from(&format!(
+            "{} binary format=\"%2float64\" endian=little",
+            self.config.script_path(self.binary_data_path())
+        ))
+    }
+}
+
+impl<T, S> Process<T, S>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+{
+    /// Points to draw, in the given color.
+    pub(crate) fn series(&self, color: usize) -> crate::figure::Series {
+        let points = self
+            .domain
+            .iter()
+            .zip(self.image.iter())
+            .map(|(time, value)| {
+                (
+                    time.to_f64().unwrap_or(f64::NAN),
+                    value.to_f64().unwrap_or(f64::NAN),
+                )
+            });
+        let mut series = crate::figure::Series::new(points, self.style(), color);
+        series.dashtype = self.dashtype();
+        series
+    }
+}
+
+impl<T, S> Figurable for Process<T, S>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+{
+    fn figure(&self) -> crate::figure::Figure {
+        let layer = crate::figure::Layer::Series(self.series(0));
+        crate::figure::Figure::new(&self.config, vec![layer])
+    }
+}
@@ -1,9 +1,10 @@
 // Structs
 use crate::errors::PreexplorerError;
 use crate::ProcessBin;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -124,23 +125,28 @@ where
     T: Display + Clone,
     S: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for process_bin in self.data_set.iter() {
-            raw_data += &process_bin.plotable_data();
-            raw_data += "\n";
+            process_bin.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<U: Display>(&self, id: U) -> Result<&Self, PreexplorerError> {
         for (counter, process_bin) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            process_bin.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(process_bin, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl<T, S> Plotable for ProcessBins<T, S>
@@ -191,18 +197,19 @@ where
             gnuplot_script += &format!("\
 # Plotting each histogram in this process
 do for [i=0:{}] {{
-    set table {:?}.'_partial_plot'.i
+    set table {}.'_partial_plot'.i{}
     WEIGTH = 1. / (DATA_POINTS_{}[i+1] * BINWIDTH_{})
-    plot {:?} index i using 2:WEIGTH bins binwidth=BINWIDTH_{} with boxes # reference: http://www.bersch.net/gnuplot-doc/plot.html#commands-plot-datafile-bins 
+    plot {} index i using 2:WEIGTH bins binwidth=BINWIDTH_{} with boxes # reference: http://www.bersch.net/gnuplot-doc/plot.html#commands-plot-datafile-bins 
     unset table
 }}
 
 ",
                 process_bin.domain.len() - 1,
-                self.data_path().with_file_name(inner_id),
+                self.config.script_path(self.data_path().with_file_name(inner_id)),
+                self.data_format().table_separator(),
                 counter,
                 counter,
-                inner_path,
+                self.config.script_path(&inner_path),
                 counter,
             );
         }
@@ -232,26 +239,26 @@ do for [i=0:{}] {{
                 gnuplot_script += "re";
             }
             gnuplot_script += &format!("\
-plot '{}'.'_partial_plot'.'0' using (TIMES({}, 1)):1:(TIMES({}, 1)):(TIMES({}, 1)+$2):3:4 with boxxyerrorbars linecolor {} title \"{}\" # using x:y:xlow:xhigh:ylow:yhigh
+plot {}.'_partial_plot'.'0' using (TIMES({}, 1)):1:(TIMES({}, 1)):(TIMES({}, 1)+$2):3:4 with boxxyerrorbars linecolor {} title {} # using x:y:xlow:xhigh:ylow:yhigh
 ",
-                inner_path.display(),
+                self.config.script_path(&inner_path),
                 counter,
                 counter,
                 counter,
                 counter,
-                legend,
+                self.config.text_string(&legend),
             );
         }
 
         // Plot without titles
         let mut path = self.data_path().to_path_buf();
-        path.set_file_name(&id);
+        path.set_file_name(id);
         gnuplot_script += &format!("\
 # Plotting the rest of the histograms in each Process
-replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i using (TIMES(j, i+1)):1:(TIMES(j, i+1)):(TIMES(j, i+1)+$2):3:4 with boxxyerrorbars linecolor j notitle # using x:y:xlow:xhigh:ylow:yhigh
+replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] {}.'_'.j.'_partial_plot'.i using (TIMES(j, i+1)):1:(TIMES(j, i+1)):(TIMES(j, i+1)+$2):3:4 with boxxyerrorbars linecolor j notitle # using x:y:xlow:xhigh:ylow:yhigh
 ",
             self.data_set.len() - 1,
-            path.display(),
+            self.config.script_path(&path),
         );
         gnuplot_script += "\n";
         gnuplot_script += &self.ending_plot_script();
@@ -259,3 +266,66 @@ replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i u
         gnuplot_script
     }
 }
+
+impl<T, S> Matplotlib for ProcessBins<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        for (counter, process_bin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match process_bin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            python_script += &format!(
+                "for i, block in enumerate(load({})):\n    bins(ax, block[0, 0], block[:, 1], {}, color=\"C{}\", label={} if i == 0 else None)\n",
+                self.config.python_path(&inner_path),
+                process_bin.binwidth,
+                counter,
+                crate::matplotlib::python_string(&legend)
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S> VegaLite for ProcessBins<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut layers = Vec::new();
+
+        for (counter, process_bin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                process_bin,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match process_bin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            layers.push(crate::vega::bins_layer(
+                &self.config,
+                &data,
+                "domain",
+                process_bin.binwidth,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
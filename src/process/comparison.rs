@@ -1,10 +1,13 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 /// Comparison counter part of [Process] struct.
 ///
@@ -46,6 +49,33 @@ where
     }
 }
 
+impl<T, S> Processes<T, S>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+    S: Display + Clone + FromStr,
+    <S as FromStr>::Err: Display,
+{
+    /// Reads a ``Processes<T, S>`` from the data files written by it,
+    /// where ``path`` is the data path of the comparison, e.g. ``target/preexplorer/data/my_identifier.txt``.
+    /// The data of each process is read from the files ``my_identifier_0.txt``, ``my_identifier_1.txt``, etc.
+    /// See [Process::load] for more.
+    ///
+    /// [Process::load]: struct.Process.html#method.load
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Processes<T, S>, PreexplorerError> {
+        let path = path.as_ref();
+        let data_set = crate::load::comparison_paths(path)?
+            .iter()
+            .map(crate::Process::load)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut comparison = Processes::new(data_set);
+        if let Some(id) = path.file_stem() {
+            comparison.set_id(id.to_string_lossy());
+        }
+        Ok(comparison)
+    }
+}
+
 impl<T, S> From<crate::Process<T, S>> for Processes<T, S>
 where
     T: Display + Clone,
@@ -154,8 +184,11 @@ where
             };
 
             gnuplot_script += &format!(
-                "{:?} using 1:2 with {} title \"{}\" dashtype {}, ",
-                inner_path, process_style, legend, dashtype,
+                "{} using 1:2 with {} title {} dashtype {}, ",
+                self.config.script_path(&inner_path),
+                process_style,
+                self.config.text_string(&legend),
+                dashtype,
             );
             if counter < self.data_set.len() - 1 {
                 gnuplot_script += "\\\n";
@@ -168,25 +201,151 @@ where
     }
 }
 
+impl<T, S> Matplotlib for Processes<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, process) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match process.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let process_style = match style {
+                crate::configuration::plot::style::Style::Default => process.style(),
+                _ => style,
+            };
+            let dashtype = match process.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            python_script += &crate::matplotlib::series_script(
+                &self.config,
+                &inner_path,
+                process_style,
+                dashtype,
+                counter,
+                Some(&legend),
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S> VegaLite for Processes<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, process) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                process,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match process.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let process_style = match style {
+                crate::configuration::plot::style::Style::Default => process.style(),
+                _ => style,
+            };
+            let dashtype = match process.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.push(crate::vega::series_layer(
+                &self.config,
+                &data,
+                ("domain", "image"),
+                process_style,
+                dashtype,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
+
 impl<T, S> Saveable for Processes<T, S>
 where
     T: Display + Clone,
     S: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for process in self.data_set.iter() {
-            raw_data += &process.plotable_data();
-            raw_data += "\n";
+            process.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<U: Display>(&self, id: U) -> Result<&Self, PreexplorerError> {
         for (counter, process) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            process.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(process, &inner_path)?;
         }
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
+}
+
+impl<T, S> Processes<T, S>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+{
+    /// Points to draw, one series per process.
+    pub(crate) fn series(&self) -> Vec<crate::figure::Series> {
+        self.data_set
+            .iter()
+            .enumerate()
+            .map(|(counter, process)| {
+                let series = process.series(counter);
+                crate::figure::comparison_series(series, self.style(), process.title(), counter)
+            })
+            .collect()
+    }
+}
+
+impl<T, S> Figurable for Processes<T, S>
+where
+    T: Display + Clone + ToPrimitive,
+    S: Display + Clone + ToPrimitive,
+{
+    fn figure(&self) -> crate::figure::Figure {
+        let layers = self.series().into_iter().map(crate::figure::Layer::Series);
+        crate::figure::Figure::new(&self.config, layers)
+    }
 }
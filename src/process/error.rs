@@ -1,5 +1,5 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
 
@@ -110,17 +110,23 @@ impl<T> Saveable for ProcessError<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.domain.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut raw_data = String::new();
-        for (time, (value, error)) in self.domain.clone().into_iter().zip(self.image.clone()) {
-            raw_data.push_str(&format!("{}\t{}\t{}\n", time, value, error));
+        for (time, (value, error)) in self.domain.iter().zip(self.image.iter()) {
+            writeln!(writer, "{}\t{}\t{}", time, value, error)?;
         }
-        raw_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["domain", "image", "error"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -134,8 +140,8 @@ where
         let dashtype = self.dashtype().unwrap_or(1);
 
         gnuplot_script += &format!(
-            "plot {:?} using 1:2 with {} dashtype {}, \"\" using 1:($2+$3):($2-$3) with filledcurves fs transparent solid 0.5 linecolor rgb \"dark-grey\"\n",
-            self.data_path(),
+            "plot {} using 1:2 with {} dashtype {}, \"\" using 1:($2+$3):($2-$3) with filledcurves fs transparent solid 0.5 linecolor rgb \"dark-grey\"\n",
+            self.config.script_path(self.data_path()),
             self.style(),
             dashtype,
         );
@@ -144,3 +150,56 @@ where
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for ProcessError<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        let dashtype = self.dashtype().unwrap_or(1);
+        python_script += &crate::matplotlib::series_script(
+            &self.config,
+            self.data_path(),
+            self.style(),
+            dashtype,
+            0,
+            None,
+        );
+        python_script += "ax.fill_between(data[:, 0], data[:, 1] - data[:, 2], data[:, 1] + data[:, 2], color=\"darkgrey\", alpha=0.5)\n";
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for ProcessError<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let errors = format!(
+            "{{\"data\": {}, \"mark\": {{\"type\": \"errorband\", \"color\": \"darkgrey\"}}, \"encoding\": {{{}, {}, \"yError\": {{\"field\": \"error\"}}}}}}",
+            data,
+            crate::vega::x(&self.config, "domain"),
+            crate::vega::y(&self.config, "image"),
+        );
+        let layer = crate::vega::series_layer(
+            &self.config,
+            &data,
+            ("domain", "image"),
+            self.style(),
+            self.dashtype().unwrap_or(1),
+            None,
+        );
+
+        crate::vega::layered_spec(&self.config, &[errors, layer])
+    }
+}
@@ -1,8 +1,9 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -145,8 +146,8 @@ where
             };
 
             gnuplot_script += &format!(
-                "{:?} using 1:2 with {} title \"{}\" dashtype {}, \"\" using 1:($2+$3):($2-$3) with filledcurves fs transparent solid 0.5 linecolor rgb \"dark-grey\" notitle, ",
-                inner_path, process_style, legend, dashtype,
+                "{} using 1:2 with {} title {} dashtype {}, \"\" using 1:($2+$3):($2-$3) with filledcurves fs transparent solid 0.5 linecolor rgb \"dark-grey\" notitle, ",
+                self.config.script_path(&inner_path), process_style, self.config.text_string(&legend), dashtype,
             );
             if counter < self.data_set.len() - 1 {
                 gnuplot_script += "\\\n";
@@ -159,24 +160,126 @@ where
     }
 }
 
+impl<T> Matplotlib for ProcessErrors<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, process) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match process.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let process_style = match style {
+                crate::configuration::plot::style::Style::Default => process.style(),
+                _ => style,
+            };
+            let dashtype = match process.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            python_script += &crate::matplotlib::series_script(
+                &self.config,
+                &inner_path,
+                process_style,
+                dashtype,
+                counter,
+                Some(&legend),
+            );
+            python_script += "ax.fill_between(data[:, 0], data[:, 1] - data[:, 2], data[:, 1] + data[:, 2], color=\"darkgrey\", alpha=0.5)\n";
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for ProcessErrors<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, process) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                process,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match process.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let process_style = match style {
+                crate::configuration::plot::style::Style::Default => process.style(),
+                _ => style,
+            };
+            let dashtype = match process.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.push(format!(
+                "{{\"data\": {}, \"mark\": {{\"type\": \"errorband\", \"color\": \"darkgrey\"}}, \"encoding\": {{{}, {}, \"yError\": {{\"field\": \"error\"}}}}}}",
+                data,
+                crate::vega::x(&self.config, "domain"),
+                crate::vega::y(&self.config, "image"),
+            ));
+            layers.push(crate::vega::series_layer(
+                &self.config,
+                &data,
+                ("domain", "image"),
+                process_style,
+                dashtype,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
+
 impl<T> Saveable for ProcessErrors<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for process in self.data_set.iter() {
-            raw_data += &process.plotable_data();
-            raw_data += "\n";
+            process.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<U: Display>(&self, id: U) -> Result<&Self, PreexplorerError> {
         for (counter, process) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            process.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(process, &inner_path)?;
         }
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
@@ -1,5 +1,5 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
 
@@ -108,22 +108,27 @@ where
     T: Display + Clone,
     S: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.domain.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-
-        for (time, values) in self.domain.clone().into_iter().zip(self.image.clone()) {
+        for (time, values) in self.domain.iter().zip(self.image.iter()) {
             for value in values {
-                plotable_data.push_str(&format!("{}\t{}\n", time, value));
+                writeln!(writer, "{}\t{}", time, value)?;
             }
             // Separate datasets
-            plotable_data.push_str("\n\n");
+            writer.write_all(b"\n\n")?;
         }
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["domain", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -146,15 +151,15 @@ RENORMALIZE = 2
 do for [i=0:{}] {{
     # Computing some values
     set table $_
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     RENORMALIZE = (RENORMALIZE < 2 * GPVAL_Y_MAX) ? 2 * GPVAL_Y_MAX : RENORMALIZE
     # Plotting a greater domain
-    set table '{}'.'_partial_plot'.i
+    set table {}.'_partial_plot'.i{}
     x_min = (GPVAL_X_MIN < GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MIN : GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH
     x_max = (GPVAL_X_MAX > GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MAX : GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH
     set xrange [x_min:x_max]
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     # Clean the plotting
     unset xrange
@@ -164,18 +169,19 @@ do for [i=0:{}] {{
 # Plotting the violins
 set style fill transparent solid 0.5
 # Right side
-plot for [i=0:{}] '{}'.'_partial_plot'.i using (TIMES[i+1] + $2/RENORMALIZE):1 with filledcurve x=TIMES[i+1] linecolor i
+plot for [i=0:{}] {}.'_partial_plot'.i using (TIMES[i+1] + $2/RENORMALIZE):1 with filledcurve x=TIMES[i+1] linecolor i
 # Left side
-replot for [i=0:{}] '{}'.'_partial_plot'.i using (TIMES[i+1] - $2/RENORMALIZE):1 with filledcurve x=TIMES[i+1] linecolor i
+replot for [i=0:{}] {}.'_partial_plot'.i using (TIMES[i+1] - $2/RENORMALIZE):1 with filledcurve x=TIMES[i+1] linecolor i
 ",
             self.image.len() - 1,
-            self.data_path(),
-            self.data_path().display(),
-            self.data_path(),
+            self.config.script_path(self.data_path()),
+            self.config.script_path(self.data_path()),
+            self.data_format().table_separator(),
+            self.config.script_path(self.data_path()),
             self.image.len() - 1,
-            self.data_path().display(),
+            self.config.script_path(self.data_path()),
             self.image.len() - 1,
-            self.data_path().display(),
+            self.config.script_path(self.data_path()),
         );
         gnuplot_script += &self.ending_plot_script();
 
@@ -183,6 +189,42 @@ replot for [i=0:{}] '{}'.'_partial_plot'.i using (TIMES[i+1] - $2/RENORMALIZE):1
     }
 }
 
+impl<T, S> Matplotlib for ProcessViolin<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        python_script += &format!(
+            "violins(ax, load({}))\n",
+            self.config.python_path(self.data_path())
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S> VegaLite for ProcessViolin<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layer = crate::vega::violins_layer(&self.config, &data, "domain", None);
+
+        crate::vega::layered_spec(&self.config, &[layer])
+    }
+}
+
 ///////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
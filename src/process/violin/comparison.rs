@@ -1,9 +1,10 @@
 // Structs
 use crate::errors::PreexplorerError;
 use crate::ProcessViolin;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -119,23 +120,28 @@ where
     T: Display + Clone,
     S: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for process_bin in self.data_set.iter() {
-            raw_data += &process_bin.plotable_data();
-            raw_data += "\n";
+            process_bin.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<U: Display>(&self, id: U) -> Result<&Self, PreexplorerError> {
         for (counter, process_bin) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            process_bin.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(process_bin, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl<T, S> Plotable for ProcessViolins<T, S>
@@ -180,14 +186,14 @@ where
 do for [i=0:PROCESS_LENGTHS[{}] - 1] {{
     # Computing some values
     set table $_
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     # Plotting a greater domain
-    set table '{}'.'_partial_plot'.i
+    set table {}.'_partial_plot'.i{}
     x_min = (GPVAL_X_MIN < GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MIN : GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH
     x_max = (GPVAL_X_MAX > GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MAX : GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH
     set xrange [x_min:x_max]
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     # Clean the plotting
     unset xrange
@@ -195,9 +201,10 @@ do for [i=0:PROCESS_LENGTHS[{}] - 1] {{
 }}
 ",
                 counter + 1,
-                inner_path_with_extension,
-                inner_path.display(),
-                inner_path_with_extension,
+                self.config.script_path(&inner_path_with_extension),
+                self.config.script_path(&inner_path),
+                self.data_format().table_separator(),
+                self.config.script_path(&inner_path_with_extension),
             );
 
             // Forget extension
@@ -243,20 +250,20 @@ set style fill transparent solid 0.5
             }
             // Left violin, with title
             gnuplot_script += &format!("\
-plot '{}'.'_partial_plot'.'0' using (TIMES({}, 1) + $2/RENORMALIZE[{}]):1 with filledcurve x=TIMES({}, 1) linecolor {} title \"{}\"
+plot {}.'_partial_plot'.'0' using (TIMES({}, 1) + $2/RENORMALIZE[{}]):1 with filledcurve x=TIMES({}, 1) linecolor {} title {}
 ",
-                inner_path.display(),
+                self.config.script_path(&inner_path),
                 counter,
                 counter + 1,
                 counter,
                 counter,
-                legend,
+                self.config.text_string(&legend),
             );
             // Right violin, without title
             gnuplot_script += &format!("\
-replot '{}'.'_partial_plot'.'0' using (TIMES({}, 1) - $2/RENORMALIZE[{}]):1 with filledcurve x=TIMES({}, 1) linecolor {} notitle 
+replot {}.'_partial_plot'.'0' using (TIMES({}, 1) - $2/RENORMALIZE[{}]):1 with filledcurve x=TIMES({}, 1) linecolor {} notitle 
 ",
-                inner_path.display(),
+                self.config.script_path(&inner_path),
                 counter,
                 counter + 1,
                 counter,
@@ -266,16 +273,16 @@ replot '{}'.'_partial_plot'.'0' using (TIMES({}, 1) - $2/RENORMALIZE[{}]):1 with
 
         // Plot without titles
         let mut path = self.data_path().to_path_buf();
-        path.set_file_name(&id);
+        path.set_file_name(id);
         gnuplot_script += &format!("\
 \n# Plotting the rest of the histograms in each Process
-replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i using (TIMES(j, i+1) + $2/RENORMALIZE[j+1]):1 with filledcurve x=TIMES(j, i+1) linecolor j notitle
-replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i using (TIMES(j, i+1) - $2/RENORMALIZE[j+1]):1 with filledcurve x=TIMES(j, i+1) linecolor j notitle
+replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] {}.'_'.j.'_partial_plot'.i using (TIMES(j, i+1) + $2/RENORMALIZE[j+1]):1 with filledcurve x=TIMES(j, i+1) linecolor j notitle
+replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] {}.'_'.j.'_partial_plot'.i using (TIMES(j, i+1) - $2/RENORMALIZE[j+1]):1 with filledcurve x=TIMES(j, i+1) linecolor j notitle
 ",
             self.data_set.len() - 1,
-            path.display(),
+            self.config.script_path(&path),
             self.data_set.len() - 1,
-            path.display(),
+            self.config.script_path(&path),
         );
 
         // Finish the script
@@ -285,3 +292,64 @@ replot for [j=0:{}] for [i=1:PROCESS_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i u
         gnuplot_script
     }
 }
+
+impl<T, S> Matplotlib for ProcessViolins<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        for (counter, process_violin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match process_violin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            python_script += &format!(
+                "violins(ax, load({}), color=\"C{}\", label={})\n",
+                self.config.python_path(&inner_path),
+                counter,
+                crate::matplotlib::python_string(&legend)
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S> VegaLite for ProcessViolins<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut layers = Vec::new();
+
+        for (counter, process_violin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                process_violin,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match process_violin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            layers.push(crate::vega::violins_layer(
+                &self.config,
+                &data,
+                "domain",
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
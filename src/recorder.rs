@@ -0,0 +1,244 @@
+// Structs
+use crate::configuration::save::format::DataWriter;
+use crate::errors::PreexplorerError;
+use std::path::Path;
+
+// Traits
+use crate::traits::Plotable;
+use core::fmt::Display;
+use std::io::Write;
+
+/// Refresh interval of live plots, in seconds, if no positive pause is set.
+const REFRESH_INTERVAL: f64 = 1.;
+
+/// Handle that appends data to the data file of a struct while it is produced.
+///
+/// The data the struct already has is written at the start, together with a plot script
+/// that re-reads itself (with gnuplot ``reread``), so that plotting it shows the progress live.
+/// The refresh interval is the pause of the struct, one second if not positive.
+///
+/// It is created with the ``record`` method of [Process] and [Sequence].
+///
+/// # Remarks
+///
+/// Every new row is flushed to the file, so that gnuplot sees it.
+/// If an output file is set, the plot script is written without ``reread``.
+/// The data files are completed (for example, closing the array of JSON) by ``finish``,
+/// or when the recorder is dropped.
+/// Inside a [Run], the data files and the plot script are listed in its manifest.
+///
+/// # Examples
+///
+/// Watching a long simulation.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let mut pro = pre::Process::new(Vec::<f64>::new(), Vec::<f64>::new());
+/// pro.set_title("Random walk");
+/// let mut recorder = pro.record("my_identifier").unwrap();
+/// recorder.plot().unwrap();
+/// let mut x = 0.;
+/// for step in 0..1_000_000 {
+///     x += if rand::random() { 1. } else { -1. };
+///     recorder.push(step as f64, x).unwrap();
+/// }
+/// recorder.finish().unwrap();
+/// ```
+///
+/// [Process]: struct.Process.html
+/// [Run]: struct.Run.html
+/// [Sequence]: struct.Sequence.html
+pub struct Recorder<P> {
+    item: P,
+    writer: DataWriter,
+    rows: usize,
+    finished: bool,
+}
+
+impl<P> Recorder<P>
+where
+    P: Plotable,
+{
+    /// Writes the plot script and the current data of ``item``, with ``rows`` rows.
+    pub(crate) fn start<S: Display>(
+        mut item: P,
+        id: S,
+        rows: usize,
+    ) -> Result<Recorder<P>, PreexplorerError> {
+        item.set_id(id);
+        let live = item.output().is_none();
+        if live && !matches!(item.pause(), Some(pause) if pause > 0.) {
+            item.set_pause(REFRESH_INTERVAL);
+        }
+        let mut gnuplot_script = item.plot_script();
+        if !gnuplot_script.ends_with('\n') {
+            gnuplot_script.push('\n');
+        }
+        if live {
+            gnuplot_script += "reread\n";
+        }
+        item.write_plot_script(gnuplot_script)?;
+
+        let mut writer = DataWriter::create(item.data_format(), &item, item.data_path())?;
+        if rows > 0 {
+            item.write_data(&mut writer)?;
+        }
+        writer.flush()?;
+        crate::run::record(&item, item.checked_id(), &item.data_paths(), None);
+
+        Ok(Recorder {
+            item,
+            writer,
+            rows,
+            finished: false,
+        })
+    }
+
+    /// Appends a row of gnuplot text, without the new line, to the data file.
+    pub(crate) fn push_row<S: Display>(&mut self, row: S) -> Result<&mut Self, PreexplorerError> {
+        writeln!(self.writer, "{}", row)?;
+        self.writer.flush()?;
+        self.rows += 1;
+        Ok(self)
+    }
+
+    /// Number of rows recorded, including those of the struct when starting.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Path of the data file.
+    pub fn data_path(&self) -> &Path {
+        self.item.data_path()
+    }
+
+    /// Path of the plot script.
+    pub fn plot_path(&self) -> &Path {
+        self.item.plot_path()
+    }
+
+    /// Runs gnuplot on the plot script, without waiting for it.
+    pub fn plot(&self) -> Result<&Self, PreexplorerError> {
+        crate::functions::run_gnuplot(self.plot_path(), false, self.item.relative_paths())?;
+        Ok(self)
+    }
+
+    /// Flushes and closes the data file.
+    ///
+    /// The whole data can be read back with the ``load`` method of the struct.
+    pub fn finish(mut self) -> Result<(), PreexplorerError> {
+        self.finished = true;
+        self.writer.finish()?;
+        Ok(())
+    }
+}
+
+impl<P> Drop for Recorder<P> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        if let Err(e) = self.writer.finish() {
+            eprintln!("Warning: could not finish the recorded data: {}", e);
+        }
+    }
+}
+
+impl<T, S> Recorder<crate::Process<T, S>>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    /// Appends a new point of the process.
+    pub fn push(&mut self, time: T, value: S) -> Result<&mut Self, PreexplorerError> {
+        self.push_row(format!("{}\t{}", time, value))
+    }
+}
+
+impl<T> Recorder<crate::Sequence<T>>
+where
+    T: Display + Clone,
+{
+    /// Appends a new value of the sequence.
+    pub fn push(&mut self, value: T) -> Result<&mut Self, PreexplorerError> {
+        let index = self.rows;
+        self.push_row(format!("{}\t{}", index, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn record_process() -> Result<(), PreexplorerError> {
+        let root = std::env::temp_dir().join("preexplorer_recorder");
+        let mut pro = pre::Process::new(vec![0., 1.], vec![0., 2.]);
+        pro.set_root_dir(&root);
+        let mut recorder = pro.record("recorder_test")?;
+
+        let script = std::fs::read_to_string(recorder.plot_path())?;
+        assert!(script.ends_with("pause 1\nreread\n"));
+
+        recorder.push(2., 4.)?.push(3., 6.)?;
+        assert_eq!(recorder.rows(), 4);
+        let path = recorder.data_path().to_path_buf();
+        recorder.finish()?;
+
+        let loaded = pre::Process::<f64, f64>::load(path)?;
+        assert_eq!(loaded.plotable_data(), "0\t0\n1\t2\n2\t4\n3\t6\n");
+        Ok(())
+    }
+
+    #[test]
+    fn record_sequence() -> Result<(), PreexplorerError> {
+        let root = std::env::temp_dir().join("preexplorer_recorder");
+        let mut seq = pre::Sequence::new(vec![5]);
+        seq.set_root_dir(&root).set_data_format("csv").unwrap();
+        let mut recorder = seq.record("recorder_csv_test")?;
+        recorder.push(6)?.push(7)?;
+        let path = recorder.data_path().to_path_buf();
+        recorder.finish()?;
+
+        let csv = std::fs::read_to_string(path)?;
+        assert_eq!(csv, "index,value,block\n0,5,0\n1,6,0\n2,7,0\n");
+        Ok(())
+    }
+
+    #[test]
+    fn record_in_run() -> Result<(), PreexplorerError> {
+        let _lock = crate::functions::ROOT_DIR_TEST_LOCK.lock().unwrap();
+        let root = std::env::temp_dir().join("preexplorer_recorder_run");
+        crate::set_root_dir(&root);
+
+        let run = crate::Run::start()?;
+        let mut recorder = pre::Sequence::new(vec![5]).record("recorder_run_test")?;
+        recorder.push(6)?;
+        recorder.finish()?;
+        let manifest_path = run.finish()?;
+        crate::set_root_dir::<_, std::path::PathBuf>(None);
+
+        let manifest = std::fs::read_to_string(&manifest_path)?;
+        assert!(manifest.contains("\"id\": \"recorder_run_test\""));
+        assert!(manifest.contains("\"data_files\": [\"data/recorder_run_test.txt\"]"));
+        assert!(manifest.contains("\"script_file\": \"plots/recorder_run_test.gnu\""));
+
+        std::fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn drop_without_finish() -> Result<(), PreexplorerError> {
+        let root = std::env::temp_dir().join("preexplorer_recorder");
+        let mut seq = pre::Sequence::new(vec![5]);
+        seq.set_root_dir(&root).set_data_format("json").unwrap();
+        let mut recorder = seq.record("recorder_json_test")?;
+        recorder.push(6)?;
+        let path = recorder.data_path().with_extension("json");
+        drop(recorder);
+
+        let json = std::fs::read_to_string(path)?;
+        assert!(json.ends_with("{\"index\":1,\"value\":6,\"block\":0}\n]\n"));
+        Ok(())
+    }
+}
@@ -0,0 +1,395 @@
+// Structs
+use crate::configuration::plot::style::Style;
+use crate::errors::PreexplorerError;
+use crate::figure::{bounds, cell_size, step_points, y_bounds, Figure, Grid, Series};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::path::{Path, PathBuf};
+
+// Traits
+use crate::traits::{Backend, Figurable};
+use core::fmt::Display;
+
+/// Size, in pixels, of rendered images.
+const SIZE: (u32, u32) = (1024, 768);
+
+/// Drawing images directly from Rust, with [plotters], without calling gnuplot.
+///
+/// The format is chosen by the extension of the path: ``svg`` for SVG and
+/// bitmaps (``png``, ``bmp``, ``jpg``...) otherwise.
+///
+/// Title, labels, ranges, logarithmic scales and style are taken from the configuration.
+/// Gnuplot scripts are still the default way of plotting, see [Plotable].
+///
+/// # Remarks
+///
+/// Only available with the **`plotters`** feature. It is implemented for all [Figurable] structs,
+/// drawing their figure with the [Image] backend.
+///
+/// # Examples
+///
+/// Rendering a sequence to png.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// (0..100).map(|i| i * i)
+///     .preexplore()
+///     .set_title("My computations")
+///     .render("my_plot.png")
+///     .unwrap();
+/// ```
+///
+/// [plotters]: https://crates.io/crates/plotters
+/// [Plotable]: trait.Plotable.html
+/// [Figurable]: trait.Figurable.html
+/// [Image]: struct.Image.html
+pub trait Render: Figurable {
+    /// Draws the struct into an image file, creating its directory if needed.
+    fn render<P: AsRef<Path>>(&self, path: P) -> Result<&Self, PreexplorerError> {
+        self.plot_with(&Image::new(path))?;
+        Ok(self)
+    }
+
+    /// Draws the struct into a png image in the plots directory, named after ``id``,
+    /// and returns its path.
+    fn render_png<S: Display>(&mut self, id: S) -> Result<PathBuf, PreexplorerError> {
+        self.set_id(id);
+        let path = self.plot_path().with_extension("png");
+        self.plot_with(&Image::new(path))
+    }
+}
+
+impl<F: Figurable> Render for F {}
+
+/// [Backend] drawing figures into image files, with [plotters].
+///
+/// The format is chosen by the extension of the path: ``svg`` for SVG and
+/// bitmaps (``png``, ``bmp``, ``jpg``...) otherwise.
+/// Drawing returns the path of the image.
+///
+/// # Examples
+///
+/// Drawing a process into an svg image of 400 by 300 pixels.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let image = pre::Image::new("my_plot.svg").with_size(400, 300);
+/// pre::Process::new(vec![0., 1., 2.], vec![0., 1., 4.])
+///     .plot_with(&image)
+///     .unwrap();
+/// ```
+///
+/// [Backend]: trait.Backend.html
+/// [plotters]: https://crates.io/crates/plotters
+#[derive(Debug, PartialEq, Clone)]
+pub struct Image {
+    path: PathBuf,
+    size: (u32, u32),
+}
+
+impl Image {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Image {
+            path: path.as_ref().to_path_buf(),
+            size: SIZE,
+        }
+    }
+
+    /// Sets the size of the image, in pixels.
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.size = (width, height);
+        self
+    }
+}
+
+impl Backend for Image {
+    type Output = PathBuf;
+
+    fn draw(&self, figure: &Figure) -> Result<PathBuf, PreexplorerError> {
+        let path = self.path.as_path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("svg") => {
+                let root = SVGBackend::new(path, self.size).into_drawing_area();
+                draw_figure(figure, &root)?;
+                root.present().map_err(rendering)?;
+            }
+            _ => {
+                let root = BitMapBackend::new(path, self.size).into_drawing_area();
+                draw_figure(figure, &root)?;
+                root.present().map_err(rendering)?;
+            }
+        }
+        Ok(self.path.clone())
+    }
+}
+
+fn rendering<E: std::error::Error>(error: E) -> PreexplorerError {
+    PreexplorerError::Rendering(error.to_string())
+}
+
+/// Color of a value in [0, 1], from blue to red.
+fn heat_color(t: f64) -> HSLColor {
+    HSLColor((1. - t.clamp(0., 1.)) * 2. / 3., 0.9, 0.5)
+}
+
+/// Draws series in a single chart, and each grid in its own panel.
+fn draw_figure<DB: DrawingBackend>(
+    figure: &Figure,
+    root: &DrawingArea<DB, Shift>,
+) -> Result<(), PreexplorerError> {
+    root.fill(&WHITE).map_err(rendering)?;
+    let series = figure.series();
+    let grids = figure.grids();
+    if grids.is_empty() {
+        return draw_series(figure, &series, figure.title.as_deref(), root);
+    }
+
+    let area = match &figure.title {
+        Some(title) => root.titled(title, ("sans-serif", 24)).map_err(rendering)?,
+        None => root.clone(),
+    };
+    let number = grids.len() + !series.is_empty() as usize;
+    let rows = (number as f64).sqrt().ceil() as usize;
+    let columns = (number as f64 / rows as f64).ceil() as usize;
+    let mut panels = area.split_evenly((rows, columns)).into_iter();
+    if !series.is_empty() {
+        draw_series(figure, &series, None, &panels.next().unwrap())?;
+    }
+    for (grid, panel) in grids.into_iter().zip(panels) {
+        draw_grid(figure, grid, &panel)?;
+    }
+    Ok(())
+}
+
+/// Draws series in a chart.
+fn draw_series<DB: DrawingBackend>(
+    figure: &Figure,
+    series: &[&Series],
+    title: Option<&str>,
+    root: &DrawingArea<DB, Shift>,
+) -> Result<(), PreexplorerError> {
+    let (logx, logy) = (figure.x.log_base(), figure.y.log_base());
+    let (x0, x1) = figure.x.range.unwrap_or_else(|| {
+        let values = series.iter().flat_map(|s| s.points.iter().map(|p| p.0));
+        bounds(values, logx.is_some())
+    });
+    let (y0, y1) = figure
+        .y
+        .range
+        .unwrap_or_else(|| y_bounds(series, logy.is_some()));
+    let base = 0_f64.max(y0).min(y1);
+
+    let mut builder = ChartBuilder::on(root);
+    builder
+        .margin(20)
+        .margin_right(40)
+        .x_label_area_size(40)
+        .y_label_area_size(60);
+    if let Some(title) = title {
+        builder.caption(title, ("sans-serif", 24));
+    }
+
+    macro_rules! chart {
+        ($x_range:expr, $y_range:expr) => {{
+            let mut chart = builder
+                .build_cartesian_2d($x_range, $y_range)
+                .map_err(rendering)?;
+            let mut mesh = chart.configure_mesh();
+            if let Some(label) = &figure.x.label {
+                mesh.x_desc(label.as_str());
+            }
+            if let Some(label) = &figure.y.label {
+                mesh.y_desc(label.as_str());
+            }
+            mesh.draw().map_err(rendering)?;
+
+            for series in series.iter() {
+                let color = Palette99::pick(series.color).to_rgba();
+                let points = series.points.iter().copied();
+                let annotation = match series.style {
+                    Style::Points => {
+                        chart.draw_series(points.map(|p| Circle::new(p, 3, color.filled())))
+                    }
+                    Style::Dots => {
+                        chart.draw_series(points.map(|p| Circle::new(p, 1, color.filled())))
+                    }
+                    Style::Impulses => chart.draw_series(
+                        points.map(|(x, y)| PathElement::new(vec![(x, base), (x, y)], color)),
+                    ),
+                    Style::Boxes => {
+                        let half = cell_size(series.points.iter().map(|p| p.0)) / 2.;
+                        chart.draw_series(points.map(|(x, y)| {
+                            Rectangle::new(
+                                [(x - half, base), (x + half, y)],
+                                color.mix(0.5).filled(),
+                            )
+                        }))
+                    }
+                    Style::Linespoints => {
+                        chart
+                            .draw_series(points.clone().map(|p| Circle::new(p, 3, color.filled())))
+                            .map_err(rendering)?;
+                        chart.draw_series(LineSeries::new(points, color))
+                    }
+                    Style::Steps | Style::Fsteps | Style::Histeps => {
+                        let points = step_points(&series.points, &series.style);
+                        match series.dashtype {
+                            Some(dash) if dash > 1 => chart.draw_series(DashedLineSeries::new(
+                                points,
+                                8,
+                                4 * dash as u32,
+                                color.into(),
+                            )),
+                            _ => chart.draw_series(LineSeries::new(points, color)),
+                        }
+                    }
+                    Style::Default | Style::Lines => match series.dashtype {
+                        Some(dash) if dash > 1 => chart.draw_series(DashedLineSeries::new(
+                            points,
+                            8,
+                            4 * dash as u32,
+                            color.into(),
+                        )),
+                        _ => chart.draw_series(LineSeries::new(points, color)),
+                    },
+                }
+                .map_err(rendering)?;
+                if let Some(title) = &series.title {
+                    annotation
+                        .label(title.as_str())
+                        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+                }
+            }
+
+            if series.iter().any(|s| s.title.is_some()) {
+                chart
+                    .configure_series_labels()
+                    .background_style(WHITE.mix(0.8))
+                    .border_style(BLACK)
+                    .draw()
+                    .map_err(rendering)?;
+            }
+        }};
+    }
+
+    match (logx, logy) {
+        (None, None) => chart!(x0..x1, y0..y1),
+        (Some(bx), None) => chart!((x0..x1).log_scale().base(bx), y0..y1),
+        (None, Some(by)) => chart!(x0..x1, (y0..y1).log_scale().base(by)),
+        (Some(bx), Some(by)) => {
+            chart!((x0..x1).log_scale().base(bx), (y0..y1).log_scale().base(by))
+        }
+    }
+    Ok(())
+}
+
+/// Draws a grid as a heatmap.
+fn draw_grid<DB: DrawingBackend>(
+    figure: &Figure,
+    grid: &Grid,
+    root: &DrawingArea<DB, Shift>,
+) -> Result<(), PreexplorerError> {
+    let scale = |v: f64| match figure.z.log_base() {
+        Some(base) => v.log(base),
+        None => v,
+    };
+    let half_x = cell_size(grid.points.iter().map(|p| p.0)) / 2.;
+    let half_y = cell_size(grid.points.iter().map(|p| p.1)) / 2.;
+    let (x0, x1) = figure.x.range.unwrap_or_else(|| {
+        let (min, max) = bounds(grid.points.iter().map(|p| p.0), false);
+        (min - half_x, max + half_x)
+    });
+    let (y0, y1) = figure.y.range.unwrap_or_else(|| {
+        let (min, max) = bounds(grid.points.iter().map(|p| p.1), false);
+        (min - half_y, max + half_y)
+    });
+    let (z0, z1) = match figure.z.range {
+        Some((z0, z1)) => (scale(z0), scale(z1)),
+        None => bounds(grid.points.iter().map(|p| scale(p.2)), false),
+    };
+
+    let mut builder = ChartBuilder::on(root);
+    builder
+        .margin(20)
+        .margin_right(40)
+        .x_label_area_size(40)
+        .y_label_area_size(60);
+    if let Some(title) = &grid.title {
+        builder.caption(title, ("sans-serif", 18));
+    }
+    let mut chart = builder
+        .build_cartesian_2d(x0..x1, y0..y1)
+        .map_err(rendering)?;
+    let mut mesh = chart.configure_mesh();
+    mesh.disable_mesh();
+    if let Some(label) = &figure.x.label {
+        mesh.x_desc(label.as_str());
+    }
+    if let Some(label) = &figure.y.label {
+        mesh.y_desc(label.as_str());
+    }
+    mesh.draw().map_err(rendering)?;
+
+    chart
+        .draw_series(grid.points.iter().map(|&(x, y, z)| {
+            let color = heat_color((scale(z) - z0) / (z1 - z0));
+            Rectangle::new(
+                [(x - half_x, y - half_y), (x + half_x, y + half_y)],
+                color.filled(),
+            )
+        }))
+        .map_err(rendering)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_files() -> Result<(), PreexplorerError> {
+        use crate::prelude::*;
+        let dir = std::env::temp_dir().join("preexplorer_render");
+
+        let mut seq = pre::Sequence::new(vec![1., 4., 9.]);
+        seq.set_title("Squares").set_style("points").unwrap();
+        let mut pro = pre::Process::new(vec![1., 10., 100.], vec![1., 2., 3.]);
+        pro.set_title("Logarithm").set_logx(10);
+        let mut seqs = pre::Sequences::new(vec![seq.clone(), seq.clone()]);
+        seqs.set_labelx("index").set_rangey(0., 10.);
+        seqs.render(dir.join("sequences.svg"))?;
+        let svg = std::fs::read_to_string(dir.join("sequences.svg"))?;
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Squares"));
+        pro.render(dir.join("process.png"))?;
+        pro.set_logx(-1.)
+            .render(dir.join("process_default_base.png"))?;
+
+        let mut den = pre::Density::new(vec![0., 0.5, 1., 1., 2.]);
+        den.set_root_dir(&dir);
+        let path = den.render_png("density_render_test")?;
+        assert_eq!(path.extension().unwrap(), "png");
+
+        let heatmap = pre::Heatmap::new(0..3, 0..2, (0..6).map(|v| v as f64));
+        heatmap.render(dir.join("heatmap.bmp"))?;
+        // Integers without a lossless conversion to f64
+        pre::Sequence::new(vec![1usize, 4, 9]).render(dir.join("sequence_usize.png"))?;
+        pre::Heatmap::new(0..3u64, 0..2u64, 0..6i64).render(dir.join("heatmap_u64.png"))?;
+        (heatmap.clone() + heatmap).render(dir.join("heatmaps.svg"))?;
+
+        let files = [
+            "process.png",
+            "process_default_base.png",
+            "heatmap.bmp",
+            "heatmaps.svg",
+            "sequence_usize.png",
+            "heatmap_u64.png",
+        ];
+        for file in files.iter() {
+            assert!(std::fs::metadata(dir.join(file))?.len() > 0);
+        }
+        assert!(std::fs::metadata(path)?.len() > 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,415 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
+
+// Traits
+use crate::traits::{Plotable, Saveable};
+use core::fmt::Display;
+
+/// Gnuplot terminal drawing the plots of reports.
+const SVG_TERMINAL: &str = "svg enhanced background rgb 'white'";
+
+/// Style of the report, kept short so that the file stays readable.
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: auto; padding: 1em; }
+figure { margin: 1em 0; }
+figure svg { max-width: 100%; height: auto; }
+nav li.plot { margin-left: 1.5em; }
+pre { background: #f4f4f4; padding: 0.5em; overflow-x: auto; }";
+
+#[derive(Debug, PartialEq, Clone)]
+enum Block {
+    Section(String),
+    Note(String),
+    Plot(Entry),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Entry {
+    id: String,
+    plot_type: String,
+    configuration: String,
+    data_files: Vec<PathBuf>,
+    script_file: Option<PathBuf>,
+    svg: String,
+}
+
+/// Single HTML file collecting many plots, with section headings and notes.
+///
+/// Each plot is drawn to SVG when added, either with gnuplot (see [plot]) or
+/// natively (see [render]), and included in the file together with its configuration
+/// and links to its data files and plot script. The file starts with an index
+/// of sections and plots, so it can be attached to a pull request or a lab notebook.
+///
+/// # Remarks
+///
+/// Links are relative to the directory of the report. By default, reports are written
+/// in the root directory (see [root_dir]), next to the ``data`` and ``plots`` folders.
+///
+/// # Examples
+///
+/// Summarizing a simulation.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let mut report = pre::Report::new("Simulation");
+/// report
+///     .section("Convergence")
+///     .note("The error decays like 1/n.")
+///     .plot(&mut (1..100).map(|n| 1. / n as f64).preexplore(), "error")
+///     .unwrap();
+/// let path = report.write("simulation").unwrap();
+/// println!("Open {:?} in a browser", path);
+/// ```
+///
+/// [plot]: struct.Report.html#method.plot
+/// [render]: struct.Report.html#method.render
+/// [root_dir]: fn.root_dir.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct Report {
+    title: String,
+    blocks: Vec<Block>,
+}
+
+impl Report {
+    /// Empty report, with ``title`` as heading of the HTML file.
+    pub fn new<S: Display>(title: S) -> Self {
+        Report {
+            title: title.to_string(),
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Starts a new section, listed in the index.
+    pub fn section<S: Display>(&mut self, heading: S) -> &mut Self {
+        self.blocks.push(Block::Section(heading.to_string()));
+        self
+    }
+
+    /// Adds free text. Blank lines separate paragraphs.
+    pub fn note<S: Display>(&mut self, text: S) -> &mut Self {
+        self.blocks.push(Block::Note(text.to_string()));
+        self
+    }
+
+    /// Adds a plot drawn by gnuplot, with its svg terminal.
+    ///
+    /// Data and plot script are saved as with ``plot_to_file``.
+    /// The terminal and output of the struct are left as they were.
+    ///
+    /// # Errors
+    ///
+    /// If gnuplot is not installed or fails, see ``plot_to_file``.
+    pub fn plot<P, S>(&mut self, item: &mut P, id: S) -> Result<&mut Self, PreexplorerError>
+    where
+        P: Plotable,
+        S: Display,
+    {
+        let id = id.to_string();
+        let terminal = item.terminal().cloned();
+        let output = item.output().map(Path::to_path_buf);
+        item.set_terminal(SVG_TERMINAL)
+            .set_output::<_, PathBuf>(None);
+        let image = item.plot_to_file(&id);
+        item.set_terminal::<_, String>(terminal)
+            .set_output::<_, PathBuf>(output);
+
+        let svg = std::fs::read_to_string(image?).map_err(PreexplorerError::Reading)?;
+        let script_file = Some(item.plot_path().to_path_buf());
+        self.push_plot(&*item, id, svg, script_file)
+    }
+
+    /// Adds a plot drawn natively, with [Render], without calling gnuplot.
+    ///
+    /// The data is saved, but no plot script is written.
+    ///
+    /// # Remarks
+    ///
+    /// Only available with the **`plotters`** feature.
+    ///
+    /// [Render]: trait.Render.html
+    #[cfg(feature = "plotters")]
+    pub fn render<F, S>(&mut self, item: &mut F, id: S) -> Result<&mut Self, PreexplorerError>
+    where
+        F: crate::Render + Saveable,
+        S: Display,
+    {
+        let id = id.to_string();
+        item.set_id(&id);
+        item.save()?;
+        let path = item.plot_path().with_extension("svg");
+        item.render(&path)?;
+
+        let svg = std::fs::read_to_string(path).map_err(PreexplorerError::Reading)?;
+        self.push_plot(&*item, id, svg, None)
+    }
+
+    fn push_plot<C>(
+        &mut self,
+        item: &C,
+        id: String,
+        svg: String,
+        script_file: Option<PathBuf>,
+    ) -> Result<&mut Self, PreexplorerError>
+    where
+        C: Saveable + ?Sized,
+    {
+        // Data files of the struct, or of each struct in comparisons
+        let data_files = item.data_paths();
+
+        // Only the svg element, without xml declarations
+        let svg = match svg.find("<svg") {
+            Some(start) => svg[start..].trim_end(),
+            None => &svg,
+        };
+        // Ids of the elements, unique among all plots
+        let svg = prefix_ids(svg, &format!("plot{}-", self.blocks.len()));
+
+        self.blocks.push(Block::Plot(Entry {
+            plot_type: crate::run::plot_type::<C>(),
+            configuration: crate::run::configuration_json(item.configuration()),
+            id,
+            data_files,
+            script_file,
+            svg,
+        }));
+        Ok(self)
+    }
+
+    /// HTML document of the report, with links relative to ``dir``.
+    pub fn html<P: AsRef<Path>>(&self, dir: P) -> String {
+        let dir = dir.as_ref();
+        let mut html = String::new();
+
+        html += "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n";
+        html += &format!("<title>{}</title>\n", escape(&self.title));
+        html += &format!("<style>\n{}\n</style>\n</head>\n<body>\n", STYLE);
+        html += &format!("<h1>{}</h1>\n", escape(&self.title));
+
+        // Index
+        html += "<nav>\n<ul>\n";
+        for (counter, block) in self.blocks.iter().enumerate() {
+            match block {
+                Block::Section(heading) => {
+                    html += &format!(
+                        "<li><a href=\"#section-{}\">{}</a></li>\n",
+                        counter,
+                        escape(heading)
+                    );
+                }
+                Block::Plot(entry) => {
+                    html += &format!(
+                        "<li class=\"plot\"><a href=\"#plot-{}\">{}</a></li>\n",
+                        escape(&entry.id),
+                        escape(&entry.id)
+                    );
+                }
+                Block::Note(_) => (),
+            }
+        }
+        html += "</ul>\n</nav>\n";
+
+        // Content
+        for (counter, block) in self.blocks.iter().enumerate() {
+            match block {
+                Block::Section(heading) => {
+                    html += &format!("<h2 id=\"section-{}\">{}</h2>\n", counter, escape(heading));
+                }
+                Block::Note(text) => {
+                    for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
+                        html += &format!("<p>{}</p>\n", escape(paragraph.trim()));
+                    }
+                }
+                Block::Plot(entry) => html += &entry.html(dir),
+            }
+        }
+        html += "</body>\n</html>\n";
+
+        html
+    }
+
+    /// Writes the report in the root directory, named after ``id``, and returns its path.
+    pub fn write<S: Display>(&self, id: S) -> Result<PathBuf, PreexplorerError> {
+        let dir = crate::root_dir();
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.html", id));
+        std::fs::write(&path, self.html(&dir))?;
+        Ok(path)
+    }
+}
+
+impl Entry {
+    fn html(&self, dir: &Path) -> String {
+        let mut html = String::new();
+
+        html += &format!("<figure id=\"plot-{}\">\n", escape(&self.id));
+        html += &self.svg;
+        html += &format!(
+            "\n<figcaption>{} ({})</figcaption>\n</figure>\n",
+            escape(&self.id),
+            escape(&self.plot_type)
+        );
+
+        let mut links = Vec::new();
+        for path in self.data_files.iter() {
+            links.push(format!("data {}", link(path, dir)));
+        }
+        if let Some(path) = &self.script_file {
+            links.push(format!("script {}", link(path, dir)));
+        }
+        if !links.is_empty() {
+            html += &format!("<p>{}</p>\n", links.join(", "));
+        }
+        html += &format!(
+            "<details>\n<summary>Configuration</summary>\n<pre>{}</pre>\n</details>\n",
+            escape(&self.configuration)
+        );
+
+        html
+    }
+}
+
+/// Link to a file, relative to ``dir`` if the file is inside it.
+fn link(path: &Path, dir: &Path) -> String {
+    let relative = match path.strip_prefix(dir) {
+        Ok(relative) => relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Err(_) => path.to_string_lossy().to_string(),
+    };
+    format!("<a href=\"{0}\">{0}</a>", escape(&relative))
+}
+
+/// Svg element with ``prefix`` added to the ids of its elements, and to the references to them.
+fn prefix_ids(svg: &str, prefix: &str) -> String {
+    let mut ids = Vec::new();
+    for (start, _) in svg.match_indices(" id=") {
+        let value = &svg[start + 4..];
+        if let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') {
+            if let Some(end) = value[1..].find(quote) {
+                ids.push(&value[1..end + 1]);
+            }
+        }
+    }
+    ids.sort_unstable();
+    ids.dedup();
+
+    let references = |id: &str| {
+        [
+            format!(" id=\"{}\"", id),
+            format!(" id='{}'", id),
+            format!("href=\"#{}\"", id),
+            format!("href='#{}'", id),
+            format!("url(#{})", id),
+        ]
+    };
+    let mut prefixed_svg = svg.to_string();
+    for id in ids {
+        let prefixed_references = references(&format!("{}{}", prefix, id));
+        for (reference, prefixed) in references(id).iter().zip(prefixed_references.iter()) {
+            prefixed_svg = prefixed_svg.replace(reference, prefixed);
+        }
+    }
+    prefixed_svg
+}
+
+/// Text with the special characters of HTML escaped.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&#39;",
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn sections_and_notes() {
+        let _lock = crate::functions::ROOT_DIR_TEST_LOCK.lock().unwrap();
+        let mut report = Report::new("Results <draft>");
+        report
+            .section("Convergence")
+            .note("First paragraph.\n\nSecond & last.");
+        let html = report.html(crate::root_dir());
+
+        assert!(html.contains("<title>Results &lt;draft&gt;</title>\n"));
+        assert!(html.contains("<li><a href=\"#section-0\">Convergence</a></li>\n"));
+        assert!(html.contains("<h2 id=\"section-0\">Convergence</h2>\n"));
+        assert!(html.contains("<p>First paragraph.</p>\n<p>Second &amp; last.</p>\n"));
+        assert!(html.ends_with("</body>\n</html>\n"));
+    }
+
+    #[test]
+    fn plot_entry() -> Result<(), PreexplorerError> {
+        let _lock = crate::functions::ROOT_DIR_TEST_LOCK.lock().unwrap();
+        let mut seqs = (0..10).preexplore() + (0..5).preexplore();
+        seqs.set_title("Comparison").set_id("report_test").save()?;
+        let mut report = Report::new("Report");
+        let svg = String::from("<?xml version=\"1.0\"?>\n<svg></svg>\n");
+        report.push_plot(&seqs, String::from("report_test"), svg, None)?;
+        let html = report.html(crate::root_dir());
+
+        assert!(html.contains("<li class=\"plot\"><a href=\"#plot-report_test\">"));
+        assert!(html.contains("<figure id=\"plot-report_test\">\n<svg></svg>\n<figcaption>report_test (Sequences)</figcaption>"));
+        assert!(html.contains("data <a href=\"data/report_test_0.txt\">data/report_test_0.txt</a>, data <a href=\"data/report_test_1.txt\">"));
+        assert!(html.contains("&quot;title&quot;: &quot;Comparison&quot;"));
+        Ok(())
+    }
+
+    #[test]
+    fn plot_comparison() -> Result<(), PreexplorerError> {
+        let _lock = crate::functions::ROOT_DIR_TEST_LOCK.lock().unwrap();
+        let heatmap = pre::Heatmap::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        let mut heatmaps = heatmap.clone() + heatmap;
+        let mut report = Report::new("Report");
+        // Without gnuplot installed, only the script is checked
+        let plotted = report.plot(&mut heatmaps, "report_comparison_test").is_ok();
+        let script = std::fs::read_to_string(heatmaps.plot_path())?;
+
+        assert!(script.contains("set terminal svg enhanced background rgb 'white'\n"));
+        assert!(script.contains("set multiplot layout 2,1"));
+        assert!(script.ends_with("unset multiplot\nunset output\n"));
+        assert!(heatmaps.terminal().is_none());
+        assert!(heatmaps.output().is_none());
+        if plotted {
+            let html = report.html(crate::root_dir());
+            assert!(html.contains("<figure id=\"plot-report_comparison_test\">\n<svg"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn svg_ids() {
+        let svg = "<svg><defs><path id='gpPt0'/></defs><g id=\"gnuplot_canvas\" filter=\"url(#textbox)\"><use xlink:href='#gpPt0'/></g></svg>";
+        assert_eq!(
+            prefix_ids(svg, "plot3-"),
+            "<svg><defs><path id='plot3-gpPt0'/></defs><g id=\"plot3-gnuplot_canvas\" filter=\"url(#textbox)\"><use xlink:href='#plot3-gpPt0'/></g></svg>"
+        );
+    }
+
+    #[cfg(feature = "plotters")]
+    #[test]
+    fn render() -> Result<(), PreexplorerError> {
+        let _lock = crate::functions::ROOT_DIR_TEST_LOCK.lock().unwrap();
+        let mut seq = pre::Sequence::new(vec![0., 1., 4.]);
+        let mut report = Report::new("Report");
+        report.render(&mut seq, "report_render_test")?;
+        let path = report.write("report_render_test")?;
+        let html = std::fs::read_to_string(path)?;
+
+        assert!(html.contains("<figure id=\"plot-report_render_test\">\n<svg"));
+        assert!(html.contains("<a href=\"data/report_render_test.txt\">"));
+        Ok(())
+    }
+}
@@ -0,0 +1,372 @@
+// Structs
+use crate::configuration::save::format::json_string;
+use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// Traits
+use crate::traits::Configurable;
+use core::fmt::Write;
+
+/// Name of the folder, inside the root directory, where runs are saved.
+const RUNS_FOLDER: &str = "runs";
+/// Name of the manifest file, inside the directory of a run.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// State of the active run, if any.
+static ACTIVE_RUN: Mutex<Option<RunState>> = Mutex::new(None);
+
+#[derive(Debug)]
+struct RunState {
+    dir: PathBuf,
+    date: chrono::DateTime<chrono::Local>,
+    previous_root: Option<PathBuf>,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    id: String,
+    plot_type: String,
+    data_files: Vec<PathBuf>,
+    script_file: Option<PathBuf>,
+    configuration: String,
+}
+
+/// Scope in which all artifacts are saved in a new directory, together with a manifest.
+///
+/// Starting a run creates the directory ``runs/<date>`` inside the root directory
+/// (see [root_dir]) and makes it the root directory of the program until the run
+/// is finished or dropped. Therefore, re-running a simulation does not overwrite
+/// the results of previous runs.
+///
+/// While the run is active, every saved data file and plot script is listed,
+/// together with the id, the plot type and the configuration of the struct.
+/// The list is kept in memory and written in the file ``manifest.json`` of the run
+/// when the run is finished or dropped.
+///
+/// # Remarks
+///
+/// Only structs created while the run is active are saved in its directory.
+/// Their default date is the date of the run.
+///
+/// # Examples
+///
+/// Keeping the results of every run.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let run = pre::Run::start().unwrap();
+/// (0..10).preexplore().plot("my_identifier").unwrap();
+/// pre::Density::new(0..10).plot_later("my_density").unwrap();
+/// let manifest = run.finish().unwrap();
+/// println!("Everything is listed in {:?}", manifest);
+/// ```
+///
+/// [root_dir]: fn.root_dir.html
+#[derive(Debug)]
+pub struct Run {
+    dir: PathBuf,
+    active: bool,
+}
+
+impl Run {
+    /// Starts a run at the current time.
+    ///
+    /// # Errors
+    ///
+    /// If there is already an active run or the directory of the run can not be created.
+    pub fn start() -> Result<Run, PreexplorerError> {
+        Run::start_at(chrono::Local::now())
+    }
+
+    /// Starts a run with a given date, which names the directory of the run.
+    ///
+    /// # Errors
+    ///
+    /// If there is already an active run or the directory of the run can not be created.
+    pub fn start_at(date: chrono::DateTime<chrono::Local>) -> Result<Run, PreexplorerError> {
+        let mut active_run = ACTIVE_RUN.lock().unwrap();
+        if let Some(state) = active_run.as_ref() {
+            return Err(PreexplorerError::Run(format!(
+                "There is already an active run in {:?}",
+                state.dir
+            )));
+        }
+
+        let dir = crate::root_dir()
+            .join(RUNS_FOLDER)
+            .join(date.format("%Y-%m-%d_%H-%M-%S%.3f").to_string());
+        std::fs::create_dir_all(&dir)?;
+
+        let previous_root = crate::functions::replace_root_dir(Some(dir.clone()));
+        let state = RunState {
+            dir: dir.clone(),
+            date,
+            previous_root,
+            entries: Vec::new(),
+        };
+        *active_run = Some(state);
+
+        Ok(Run { dir, active: true })
+    }
+
+    /// Directory of the run.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the manifest of the run, which is written when the run is finished or dropped.
+    pub fn manifest_path(&self) -> PathBuf {
+        self.dir.join(MANIFEST_FILE)
+    }
+
+    /// Finishes the run, restoring the previous root directory and writing the manifest,
+    /// and returns the path of the manifest.
+    pub fn finish(mut self) -> Result<PathBuf, PreexplorerError> {
+        self.deactivate()?;
+        Ok(self.manifest_path())
+    }
+
+    fn deactivate(&mut self) -> Result<(), PreexplorerError> {
+        if !self.active {
+            return Ok(());
+        }
+        self.active = false;
+        if let Some(state) = ACTIVE_RUN.lock().unwrap().take() {
+            crate::functions::replace_root_dir(state.previous_root.clone());
+            state.write_manifest()?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        if let Err(e) = self.deactivate() {
+            eprintln!("Warning: could not finish the run in {:?}: {}", self.dir, e);
+        }
+    }
+}
+
+impl RunState {
+    fn write_manifest(&self) -> Result<(), PreexplorerError> {
+        let mut manifest = String::new();
+        manifest += "{\n";
+        manifest += &format!("  \"date\": {},\n", json_string(&self.date.to_rfc3339()));
+        manifest += "  \"entries\": [";
+        for (counter, entry) in self.entries.iter().enumerate() {
+            if counter > 0 {
+                manifest += ",";
+            }
+            let data_files: Vec<String> = entry
+                .data_files
+                .iter()
+                .map(|path| json_string(&self.relative(path)))
+                .collect();
+            let script_file = match &entry.script_file {
+                Some(path) => json_string(&self.relative(path)),
+                None => String::from("null"),
+            };
+            manifest += "\n    {\n";
+            manifest += &format!("      \"id\": {},\n", json_string(&entry.id));
+            manifest += &format!("      \"plot_type\": {},\n", json_string(&entry.plot_type));
+            manifest += &format!("      \"data_files\": [{}],\n", data_files.join(", "));
+            manifest += &format!("      \"script_file\": {},\n", script_file);
+            manifest += &format!("      \"configuration\": {}\n", entry.configuration);
+            manifest += "    }";
+        }
+        if !self.entries.is_empty() {
+            manifest += "\n  ";
+        }
+        manifest += "]\n}\n";
+
+        std::fs::write(self.dir.join(MANIFEST_FILE), manifest)?;
+        Ok(())
+    }
+
+    /// Path relative to the directory of the run, with ``/`` as separator.
+    fn relative(&self, path: &Path) -> String {
+        let path = path.strip_prefix(&self.dir).unwrap_or(path);
+        path.components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn entry_mut(&mut self, id: &str) -> &mut Entry {
+        match self.entries.iter().position(|entry| entry.id == id) {
+            Some(index) => &mut self.entries[index],
+            None => {
+                self.entries.push(Entry {
+                    id: id.to_string(),
+                    plot_type: String::new(),
+                    data_files: Vec::new(),
+                    script_file: None,
+                    configuration: String::new(),
+                });
+                self.entries.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+/// Date of the active run, if any.
+pub(crate) fn date() -> Option<chrono::DateTime<chrono::Local>> {
+    ACTIVE_RUN.lock().unwrap().as_ref().map(|state| state.date)
+}
+
+/// Records a struct, with the data files it wrote and possibly its plot script,
+/// in the active run, if any.
+pub(crate) fn record<C>(item: &C, id: &str, data_files: &[PathBuf], script_file: Option<&Path>)
+where
+    C: Configurable + ?Sized,
+{
+    let mut active_run = ACTIVE_RUN.lock().unwrap();
+    if let Some(state) = active_run.as_mut() {
+        let entry = state.entry_mut(id);
+        entry.plot_type = plot_type::<C>();
+        entry.configuration = configuration_json(item.configuration());
+        for path in data_files {
+            if !entry.data_files.contains(path) {
+                entry.data_files.push(path.clone());
+            }
+        }
+        if let Some(script_file) = script_file {
+            entry.script_file = Some(script_file.to_path_buf());
+        }
+    }
+}
+
+/// Name of the type, without modules or generics. For example, ``Sequence``.
+pub(crate) fn plot_type<C: ?Sized>() -> String {
+    let name = std::any::type_name::<C>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+/// Configuration as a JSON object.
+pub(crate) fn configuration_json(config: &crate::Configuration) -> String {
+    fn string(value: Option<&String>) -> String {
+        value
+            .map(|s| json_string(s))
+            .unwrap_or_else(|| String::from("null"))
+    }
+    fn number(value: Option<f64>) -> String {
+        match value {
+            Some(x) if x.is_finite() => x.to_string(),
+            _ => String::from("null"),
+        }
+    }
+    fn range(value: Option<(f64, f64)>) -> String {
+        match value {
+            Some((a, b)) => format!("[{}, {}]", number(Some(a)), number(Some(b))),
+            None => String::from("null"),
+        }
+    }
+
+    let mut json = String::from("{");
+    let _ = write!(json, "\"title\": {}, ", string(config.title()));
+    let _ = write!(json, "\"labelx\": {}, ", string(config.labelx()));
+    let _ = write!(json, "\"labely\": {}, ", string(config.labely()));
+    let _ = write!(json, "\"labelz\": {}, ", string(config.labelz()));
+    let _ = write!(json, "\"logx\": {}, ", number(config.logx()));
+    let _ = write!(json, "\"logy\": {}, ", number(config.logy()));
+    let _ = write!(json, "\"logz\": {}, ", number(config.logz()));
+    let _ = write!(json, "\"rangex\": {}, ", range(config.rangex()));
+    let _ = write!(json, "\"rangey\": {}, ", range(config.rangey()));
+    let _ = write!(json, "\"rangez\": {}, ", range(config.rangez()));
+    let _ = write!(json, "\"ticsx\": {}, ", string(config.ticsx()));
+    let _ = write!(json, "\"ticsy\": {}, ", string(config.ticsy()));
+    let _ = write!(json, "\"ticsz\": {}, ", string(config.ticsz()));
+    let _ = write!(
+        json,
+        "\"style\": {}, ",
+        json_string(&format!("{:?}", config.style()))
+    );
+    let _ = write!(
+        json,
+        "\"dashtype\": {}, ",
+        config
+            .dashtype()
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| String::from("null"))
+    );
+    let _ = write!(json, "\"pause\": {}, ", number(config.pause()));
+    let _ = write!(json, "\"terminal\": {}, ", string(config.terminal()));
+    let _ = write!(
+        json,
+        "\"publication\": {}, ",
+        string(config.publication().map(|p| p.to_string()).as_ref())
+    );
+    let _ = write!(
+        json,
+        "\"output\": {}, ",
+        string(config.output().map(|o| o.display().to_string()).as_ref())
+    );
+    let _ = write!(json, "\"header\": {}, ", config.header());
+    let _ = write!(
+        json,
+        "\"date\": {}, ",
+        json_string(&config.date().to_rfc3339())
+    );
+    let mut custom: Vec<(&String, &String)> = config.custom_config().iter().collect();
+    custom.sort();
+    let custom: Vec<String> = custom
+        .into_iter()
+        .map(|(key, value)| format!("{}: {}", json_string(key), json_string(value)))
+        .collect();
+    let _ = write!(json, "\"custom\": {{{}}}", custom.join(", "));
+    json.push('}');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest() -> Result<(), PreexplorerError> {
+        use crate::prelude::*;
+        let _lock = crate::functions::ROOT_DIR_TEST_LOCK.lock().unwrap();
+        let root = std::env::temp_dir().join("preexplorer_run_test");
+        crate::set_root_dir(&root);
+
+        let run = Run::start()?;
+        assert!(run.dir().starts_with(root.join("runs")));
+        assert_eq!(crate::root_dir(), run.dir());
+        assert!(Run::start().is_err());
+
+        (0..10)
+            .preexplore()
+            .set_title("My \"sequence\"")
+            .plot_later("my_sequence")?;
+        ((0..10).preexplore() + (0..5).preexplore()).plot_later("my_sequences")?;
+        let mut pro = pre::Process::new(vec![0_usize, 1], vec![1_u64, 2]);
+        pro.set_id("my_binary").save_binary()?;
+        assert!(!run.manifest_path().exists());
+        let manifest_path = run.finish()?;
+        assert_eq!(crate::root_dir(), root);
+        crate::set_root_dir::<_, PathBuf>(None);
+
+        let manifest = std::fs::read_to_string(&manifest_path)?;
+        assert!(manifest.contains("\"id\": \"my_sequence\""));
+        assert!(manifest.contains("\"plot_type\": \"Sequence\""));
+        assert!(manifest.contains("\"data_files\": [\"data/my_sequence.txt\"]"));
+        assert!(manifest.contains("\"script_file\": \"plots/my_sequence.gnu\""));
+        assert!(manifest.contains("\"title\": \"My \\\"sequence\\\"\""));
+        assert!(manifest.contains("\"plot_type\": \"Sequences\""));
+        assert!(manifest.contains(
+            "\"data_files\": [\"data/my_sequences_0.txt\", \"data/my_sequences_1.txt\"]"
+        ));
+        assert!(manifest.contains("\"data_files\": [\"data/my_binary.bin\"]"));
+
+        std::fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn plot_types() {
+        assert_eq!(plot_type::<crate::Sequence<u32>>(), "Sequence");
+        assert_eq!(plot_type::<crate::Heatmaps<u32, f64, f64>>(), "Heatmaps");
+    }
+}
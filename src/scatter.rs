@@ -0,0 +1,577 @@
+// Structs
+use crate::configuration::plot::style::Style;
+use std::path::Path;
+
+// Traits
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::fmt::Display;
+use core::ops::Add;
+
+pub mod comparison;
+
+pub use comparison::Scatters;
+
+/// Unordered points in two or three dimensions, with an optional size, color value
+/// and label for each point.
+///
+/// # Remarks
+///
+/// Unlike [Process], points are not joined in any order: they are drawn as points by default.
+/// Points in three dimensions are plotted with ``splot``.
+///
+/// Sizes scale the default size of points. Color values are mapped to the palette,
+/// with the label of the z axis in its colorbar.
+///
+/// Labels are saved in a single column, double-quoted. Gnuplot reads them without escapes,
+/// so double quotes in a label are saved as single quotes and line breaks as spaces.
+///
+/// # Examples
+///
+/// Quick plot.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let points = [(0.1, 0.5), (0.7, 0.2), (0.4, 0.9)];
+/// pre::Scatter::new(points)
+///     .set_colors([1, 2, 3])
+///     .set_labels(["a", "b", "c"])
+///     .plot("my_identifier")
+///     .unwrap();
+/// ```
+///
+/// Compare [Scatter] structs.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// pre::Scatters::new([
+///     pre::Scatter::new([(0, 1), (1, 0)]),
+///     pre::Scatter::new_3d([(0, 0, 1), (1, 1, 0)]),
+///     ])
+///     .plot("my_identifier").unwrap();
+/// ```
+///
+/// [Process]: struct.Process.html
+/// [Scatter]: struct.Scatter.html
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scatter<T>
+where
+    T: Display + Clone,
+{
+    data: Vec<T>,
+    dim: usize,
+    sizes: Option<Vec<f64>>,
+    colors: Option<Vec<f64>>,
+    labels: Option<Vec<String>>,
+    config: crate::configuration::Configuration,
+}
+
+impl<T> Scatter<T>
+where
+    T: Display + Clone,
+{
+    /// Constructs a new ``Scatter<T>`` from points ``(x, y)``.
+    ///
+    /// # Examples
+    ///
+    /// From a complicated computation.
+    /// ```
+    /// use preexplorer::prelude::*;
+    /// let data = (0..10).map(|i| (i * i % 7, i % 3));
+    /// let scatter = pre::Scatter::new(data);
+    /// ```
+    pub fn new<I>(data: I) -> Scatter<T>
+    where
+        I: IntoIterator<Item = (T, T)>,
+    {
+        let data = data.into_iter().flat_map(|(x, y)| vec![x, y]).collect();
+        Scatter::with_dim(data, 2)
+    }
+
+    /// Constructs a new ``Scatter<T>`` from points ``(x, y, z)``.
+    ///
+    /// # Examples
+    ///
+    /// Points in space.
+    /// ```
+    /// use preexplorer::prelude::*;
+    /// let data = (0..10).map(|i| (i, i * i, i % 3));
+    /// let scatter = pre::Scatter::new_3d(data);
+    /// ```
+    pub fn new_3d<I>(data: I) -> Scatter<T>
+    where
+        I: IntoIterator<Item = (T, T, T)>,
+    {
+        let data = data
+            .into_iter()
+            .flat_map(|(x, y, z)| vec![x, y, z])
+            .collect();
+        Scatter::with_dim(data, 3)
+    }
+
+    fn with_dim(data: Vec<T>, dim: usize) -> Scatter<T> {
+        let mut config = crate::configuration::Configuration::default();
+        config.set_style("points").unwrap();
+
+        Scatter {
+            data,
+            dim,
+            sizes: None,
+            colors: None,
+            labels: None,
+            config,
+        }
+    }
+
+    /// Sets the size of each point, relative to the default size.
+    ///
+    /// # Panics
+    ///
+    /// If there is not exactly one size per point.
+    pub fn set_sizes<I>(&mut self, sizes: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<f64>,
+    {
+        self.sizes = Some(self.per_point(sizes, "sizes", Into::into));
+        self
+    }
+
+    /// Sets the value of each point mapped to the palette of colors.
+    ///
+    /// # Panics
+    ///
+    /// If there is not exactly one value per point.
+    pub fn set_colors<I>(&mut self, colors: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<f64>,
+    {
+        self.colors = Some(self.per_point(colors, "colors", Into::into));
+        self
+    }
+
+    /// Sets the label written next to each point.
+    ///
+    /// # Panics
+    ///
+    /// If there is not exactly one label per point.
+    pub fn set_labels<I>(&mut self, labels: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        self.labels = Some(self.per_point(labels, "labels", |label| label.to_string()));
+        self
+    }
+
+    fn per_point<I, U, F>(&self, values: I, name: &str, f: F) -> Vec<U>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> U,
+    {
+        let values: Vec<U> = values.into_iter().map(f).collect();
+        let points = self.data.len() / self.dim;
+        if values.len() != points {
+            panic!(
+                "There are {} {} for {} points: there must be one per point.",
+                values.len(),
+                name,
+                points
+            );
+        }
+        values
+    }
+
+    /// Dimension of the points: two or three.
+    pub(crate) fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Checks if the points have color values.
+    pub(crate) fn has_colors(&self) -> bool {
+        self.colors.is_some()
+    }
+
+    /// Columns of the sizes, colors and labels, if any, counting from one.
+    fn extra_columns(&self) -> (Option<usize>, Option<usize>, Option<usize>) {
+        let mut next = self.dim;
+        let mut column = |present: bool| {
+            if present {
+                next += 1;
+                Some(next)
+            } else {
+                None
+            }
+        };
+        (
+            column(self.sizes.is_some()),
+            column(self.colors.is_some()),
+            column(self.labels.is_some()),
+        )
+    }
+
+    /// Elements of a gnuplot ``plot`` command, or ``splot`` command if ``splot`` is true,
+    /// drawing the points saved in ``path`` with the legend ``title``, both gnuplot strings,
+    /// followed by their labels, if any.
+    ///
+    /// Points in two dimensions are drawn at height zero by ``splot``.
+    pub(crate) fn plot_elements(
+        &self,
+        path: &str,
+        splot: bool,
+        style: &Style,
+        dashtype: usize,
+        title: Option<&str>,
+    ) -> String {
+        let position = match (splot, self.dim) {
+            (false, _) => "1:2",
+            (true, 3) => "1:2:3",
+            (true, _) => "1:2:(0)",
+        };
+        let (size, color, label) = self.extra_columns();
+        let mut using = String::from(position);
+        let mut options = String::new();
+        if let Some(size) = size {
+            using += &format!(":{}", size);
+            options += " pointsize variable";
+        }
+        if let Some(color) = color {
+            using += &format!(":{}", color);
+            options += " linecolor palette";
+        }
+        let title = match title {
+            Some(title) => format!(" title {}", title),
+            None => String::new(),
+        };
+
+        let mut elements = format!(
+            "{} using {} with {}{}{} dashtype {}",
+            path, using, style, options, title, dashtype
+        );
+        if let Some(label) = label {
+            elements += &format!(
+                ", {} using {}:(stringcolumn({})) with labels noenhanced left offset character 1,0 notitle",
+                path, position, label
+            );
+        }
+        elements
+    }
+
+    /// Matplotlib commands drawing the points saved in ``path``, with their labels, if any.
+    ///
+    /// If ``colorbar`` is true, color values get a colorbar with the label of the z axis
+    /// of the configuration.
+    pub(crate) fn matplotlib_elements(
+        &self,
+        config: &crate::configuration::Configuration,
+        path: &Path,
+        style: &Style,
+        color: usize,
+        legend: Option<&str>,
+        colorbar: bool,
+    ) -> String {
+        let (size_column, color_column, label_column) = self.extra_columns();
+        let mut arguments = String::new();
+        if let Some(column) = size_column {
+            arguments += &format!(", sizes={}", column - 1);
+        }
+        if let Some(column) = color_column {
+            arguments += &format!(", values={}", column - 1);
+            arguments += &crate::matplotlib::norm_arguments(config);
+            match (colorbar, config.labelz()) {
+                (false, _) => arguments += ", colorbar=False",
+                (true, Some(label)) => {
+                    arguments += &format!(", colorbar={}", crate::matplotlib::python_string(label))
+                }
+                (true, None) => (),
+            }
+        }
+        if let Some(column) = label_column {
+            arguments += &format!(", texts={}", column - 1);
+        }
+        if let Some(legend) = legend {
+            arguments += &format!(", label={}", crate::matplotlib::python_string(legend));
+        }
+        format!(
+            "data = rows({})\nscatter(fig, ax, data, {}, \"{}\"{}, color=\"C{}\")\n",
+            config.python_path(path),
+            self.dim,
+            style,
+            arguments,
+            color
+        )
+    }
+
+    /// Vega-Lite layers drawing the points of ``data``, colored by their color values,
+    /// by their height in three dimensions, or else by the legend, if any, and their labels.
+    pub(crate) fn vega_lite_layers(
+        &self,
+        config: &crate::configuration::Configuration,
+        data: &str,
+        style: &Style,
+        dashtype: usize,
+        legend: Option<&str>,
+    ) -> Vec<String> {
+        let position = vec![crate::vega::x(config, "x"), crate::vega::y(config, "y")];
+        let mut encoding = position.clone();
+        if self.sizes.is_some() {
+            encoding.push(String::from(
+                "\"size\": {\"field\": \"size\", \"type\": \"quantitative\"}",
+            ));
+        }
+        match (&self.colors, self.dim, legend) {
+            (Some(_), _, _) => encoding.push(crate::vega::z(config, "color")),
+            (None, 3, _) => encoding.push(crate::vega::z(config, "z")),
+            (None, _, Some(legend)) => encoding.push(crate::vega::color(legend)),
+            (None, _, None) => (),
+        }
+
+        let mut layers = vec![format!(
+            "{{\"data\": {}, \"mark\": {}, \"encoding\": {{{}}}}}",
+            data,
+            crate::vega::mark(style, dashtype),
+            encoding.join(", ")
+        )];
+        if self.labels.is_some() {
+            layers.push(format!(
+                "{{\"data\": {}, \"mark\": {{\"type\": \"text\", \"align\": \"left\", \"dx\": 5}}, \
+                \"encoding\": {{{}, \"text\": {{\"field\": \"label\"}}}}}}",
+                data,
+                position.join(", ")
+            ));
+        }
+        layers
+    }
+}
+
+impl<T> Add for Scatter<T>
+where
+    T: Display + Clone,
+{
+    type Output = crate::Scatters<T>;
+
+    fn add(self, other: crate::Scatter<T>) -> crate::Scatters<T> {
+        let mut cmp = self.into();
+        cmp += other;
+        cmp
+    }
+}
+
+impl<T> Configurable for Scatter<T>
+where
+    T: Display + Clone,
+{
+    fn configuration_mut(&mut self) -> &mut crate::configuration::Configuration {
+        &mut self.config
+    }
+    fn configuration(&self) -> &crate::configuration::Configuration {
+        &self.config
+    }
+}
+
+impl<T> Saveable for Scatter<T>
+where
+    T: Display + Clone,
+{
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Initial warning
+        if self.data.is_empty() {
+            eprintln!("Warning: There is no data.");
+        }
+
+        for (i, point) in self.data.chunks(self.dim).enumerate() {
+            let mut fields: Vec<String> = point.iter().map(|value| value.to_string()).collect();
+            if let Some(sizes) = &self.sizes {
+                fields.push(sizes[i].to_string());
+            }
+            if let Some(colors) = &self.colors {
+                fields.push(colors[i].to_string());
+            }
+            if let Some(labels) = &self.labels {
+                fields.push(crate::gnuplot::datafile_string(&labels[i]));
+            }
+            writeln!(writer, "{}", fields.join("\t"))?;
+        }
+
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        let mut names = vec!["x", "y", "z"];
+        names.truncate(self.dim);
+        if self.sizes.is_some() {
+            names.push("size");
+        }
+        if self.colors.is_some() {
+            names.push("color");
+        }
+        if self.labels.is_some() {
+            names.push("label");
+        }
+        names.iter().map(|name| name.to_string()).collect()
+    }
+}
+
+impl<T> Plotable for Scatter<T>
+where
+    T: Display + Clone,
+{
+    fn plot_script(&self) -> String {
+        let mut gnuplot_script = self.opening_plot_script();
+
+        let command = if self.dim == 3 { "splot" } else { "plot" };
+        gnuplot_script += &format!(
+            "{} {}\n",
+            command,
+            self.plot_elements(
+                &self.config.script_path(self.data_path()),
+                self.dim == 3,
+                self.style(),
+                self.dashtype().unwrap_or(1),
+                None,
+            )
+        );
+        gnuplot_script += &self.ending_plot_script();
+
+        gnuplot_script
+    }
+}
+
+impl<T> Matplotlib for Scatter<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = if self.dim == 3 {
+            self.config.opening_matplotlib_script_3d()
+        } else {
+            self.opening_matplotlib_script()
+        };
+        python_script += &self.matplotlib_elements(
+            self.configuration(),
+            self.data_path(),
+            self.style(),
+            0,
+            None,
+            true,
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Scatter<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layers = self.vega_lite_layers(
+            self.configuration(),
+            &data,
+            self.style(),
+            self.dashtype().unwrap_or(1),
+            None,
+        );
+
+        crate::vega::layered_spec(self.configuration(), &layers)
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_style() {
+        let mut scatter = Scatter::new([(0, 1), (1, 0)]);
+        scatter.set_style("dots").unwrap();
+
+        assert_eq!(
+            &crate::configuration::plot::style::Style::Dots,
+            scatter.style()
+        );
+    }
+
+    #[test]
+    fn plotable_data() {
+        let mut scatter = Scatter::new([(0, 1), (2, 3)]);
+        scatter
+            .set_sizes([1, 2])
+            .set_labels(["first \"point\" `date`", ""]);
+        assert_eq!(
+            scatter.plotable_data(),
+            "0\t1\t1\t\"first 'point' `date`\"\n2\t3\t2\t\"\"\n"
+        );
+        assert_eq!(scatter.column_names(), vec!["x", "y", "size", "label"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_colors() {
+        Scatter::new_3d([(0, 1, 2)]).set_colors([1, 2]);
+    }
+
+    #[test]
+    fn plot_script() {
+        let mut scatter = Scatter::new([(0.1, 0.5), (0.7, 0.2)]);
+        scatter
+            .set_colors([1., 2.])
+            .set_labels(["a", "b"])
+            .set_id("scatter_test");
+        let script = scatter.plot_script();
+
+        assert!(script
+            .contains("scatter_test.txt\" using 1:2:3 with points linecolor palette dashtype 1, "));
+        assert!(script.contains(
+            "scatter_test.txt\" using 1:2:(stringcolumn(4)) with labels noenhanced left offset character 1,0 notitle\n"
+        ));
+
+        let mut scatter = Scatter::new_3d([(0, 1, 2)]);
+        scatter.set_sizes([0.5]).set_id("scatter_3d_test");
+        let script = scatter.plot_script();
+        assert!(script.contains(
+            "scatter_3d_test.txt\" using 1:2:3:4 with points pointsize variable dashtype 1\n"
+        ));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut scatter = Scatter::new_3d([(0, 1, 2)]);
+        scatter
+            .set_colors([0.5])
+            .set_labels(["a"])
+            .set_labelz("height")
+            .set_id("scatter_matplotlib_test");
+        let script = scatter.matplotlib_script();
+
+        assert!(script.contains("fig, ax = plt.subplots(subplot_kw={\"projection\": \"3d\"})\n"));
+        assert!(script.contains("ax.set_zlabel(\"height\")\n"));
+        assert!(script.contains(
+            "scatter(fig, ax, data, 3, \"points\", values=3, colorbar=\"height\", texts=4, color=\"C0\")\n"
+        ));
+    }
+
+    #[test]
+    fn vega_lite_spec() {
+        let mut scatter = Scatter::new([(0, 1)]);
+        scatter
+            .set_sizes([2])
+            .set_labels(["a b"])
+            .set_id("scatter_vega_lite_test");
+        let spec = scatter.vega_lite_spec();
+
+        assert!(spec.contains(
+            "{\"values\": [\n{\"x\":0,\"y\":1,\"size\":2,\"label\":\"a b\",\"block\":0}\n]}"
+        ));
+        assert!(spec.contains("\"size\": {\"field\": \"size\", \"type\": \"quantitative\"}"));
+        assert!(spec.contains("\"text\": {\"field\": \"label\"}"));
+        assert_eq!(spec.matches("\"mark\":").count(), 2);
+    }
+}
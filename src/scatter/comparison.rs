@@ -0,0 +1,328 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::PathBuf;
+
+// Traits
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::fmt::Display;
+use core::ops::{Add, AddAssign};
+
+/// Comparison counter part of [Scatter] struct.
+///
+/// # Remarks
+///
+/// If any of the structs has points in three dimensions, all are plotted with ``splot``,
+/// with points in two dimensions at height zero.
+///
+/// # Examples
+///
+/// Quick plot.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let many_scatter = (0..5).map(|i| pre::Scatter::new([(i, i * i), (i * i, i)]));
+/// pre::Scatters::new(many_scatter).plot("my_identifier").unwrap();
+/// ```
+///
+/// [Scatter]: struct.Scatter.html
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scatters<T>
+where
+    T: Display + Clone,
+{
+    data_set: Vec<crate::scatter::Scatter<T>>,
+    config: crate::configuration::Configuration,
+}
+impl<T> Scatters<T>
+where
+    T: Display + Clone,
+{
+    pub fn new<I>(data_set: I) -> Scatters<T>
+    where
+        I: IntoIterator<Item = crate::Scatter<T>>,
+    {
+        let config = crate::configuration::Configuration::default();
+        let data_set = data_set.into_iter().collect::<Vec<crate::Scatter<T>>>();
+        Scatters { data_set, config }
+    }
+
+    /// Checks if any of the structs has points in three dimensions.
+    fn three_d(&self) -> bool {
+        self.data_set.iter().any(|scatter| scatter.dim() == 3)
+    }
+}
+
+impl<T> From<crate::Scatter<T>> for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn from(scatter: crate::Scatter<T>) -> Self {
+        Scatters::new(vec![scatter])
+    }
+}
+
+impl<T> Add<crate::Scatter<T>> for Scatters<T>
+where
+    T: Display + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: crate::Scatter<T>) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<T> Add for Scatters<T>
+where
+    T: Display + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<T> AddAssign<crate::Scatter<T>> for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn add_assign(&mut self, other: crate::Scatter<T>) {
+        self.data_set.push(other);
+    }
+}
+
+impl<T> AddAssign for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn add_assign(&mut self, mut other: Self) {
+        self.data_set.append(&mut other.data_set);
+    }
+}
+
+impl<T> Configurable for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn configuration_mut(&mut self) -> &mut crate::configuration::Configuration {
+        &mut self.config
+    }
+    fn configuration(&self) -> &crate::configuration::Configuration {
+        &self.config
+    }
+}
+
+impl<T> Saveable for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for scatter in self.data_set.iter() {
+            scatter.write_data(writer)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
+        for (counter, scatter) in self.data_set.iter().enumerate() {
+            let inner_id = format!("{}_{}", id, counter);
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(scatter, &inner_path)?;
+        }
+
+        Ok(self)
+    }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
+}
+
+impl<T> Plotable for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn plot_script(&self) -> String {
+        let id = self.checked_id();
+        let mut gnuplot_script = self.config.opening_plot_script_comparison();
+
+        let three_d = self.three_d();
+        gnuplot_script += if three_d { "splot " } else { "plot " };
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, scatter) in self.data_set.iter().enumerate() {
+            let inner_id = format!("{}_{}", id, counter);
+            let mut inner_path = self.data_path().to_path_buf();
+            if let Some(extension) = self.data_extension() {
+                inner_path.set_file_name(&inner_id);
+                inner_path.set_extension(extension);
+            } else {
+                inner_path.set_file_name(&inner_id);
+            }
+            let legend = match scatter.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let scatter_style = match style {
+                crate::configuration::plot::style::Style::Default => scatter.style(),
+                _ => style,
+            };
+            let dashtype = match scatter.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            gnuplot_script += &scatter.plot_elements(
+                &self.config.script_path(&inner_path),
+                three_d,
+                scatter_style,
+                dashtype,
+                Some(&self.config.text_string(&legend)),
+            );
+            gnuplot_script += ", ";
+
+            if counter < self.data_set.len() - 1 {
+                gnuplot_script += "\\\n";
+            }
+        }
+        gnuplot_script += "\n";
+        gnuplot_script += &self.ending_plot_script();
+
+        gnuplot_script
+    }
+}
+
+impl<T> Matplotlib for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = if self.three_d() {
+            self.config.opening_matplotlib_script_3d()
+        } else {
+            self.config.opening_matplotlib_script()
+        };
+
+        let style = self.style();
+        let mut colorbar = true;
+
+        for (counter, scatter) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match scatter.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let scatter_style = match style {
+                crate::configuration::plot::style::Style::Default => scatter.style(),
+                _ => style,
+            };
+            python_script += &scatter.matplotlib_elements(
+                self.configuration(),
+                &inner_path,
+                scatter_style,
+                counter,
+                Some(&legend),
+                colorbar,
+            );
+            // Only one colorbar
+            colorbar &= !scatter.has_colors();
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Scatters<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, scatter) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                scatter,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match scatter.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let scatter_style = match style {
+                crate::configuration::plot::style::Style::Default => scatter.style(),
+                _ => style,
+            };
+            let dashtype = match scatter.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.append(&mut scatter.vega_lite_layers(
+                self.configuration(),
+                &data,
+                scatter_style,
+                dashtype,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(self.configuration(), &layers)
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_script() {
+        let mut scatters = crate::Scatter::new([(0, 1)]).set_title("first").to_owned()
+            + crate::Scatter::new_3d([(0, 1, 2)])
+                .set_sizes([2])
+                .to_owned();
+        scatters.set_id("scatters_test");
+        let script = scatters.plot_script();
+
+        assert!(script.contains("splot "));
+        assert!(script.contains(
+            "scatters_test_0.txt\" using 1:2:(0) with points title \"first\" noenhanced dashtype 1"
+        ));
+        assert!(script.contains("scatters_test_1.txt\" using 1:2:3:4 with points pointsize variable title \"1\" noenhanced dashtype 2"));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut scatters = crate::Scatter::new([(0, 1)]).set_colors([1]).to_owned()
+            + crate::Scatter::new([(1, 0)]).set_colors([2]).to_owned();
+        scatters.set_id("scatters_matplotlib_test");
+        let script = scatters.matplotlib_script();
+
+        assert!(script.contains("fig, ax = plt.subplots()\n"));
+        assert!(script.contains(
+            "scatter(fig, ax, data, 2, \"points\", values=2, label=\"0\", color=\"C0\")\n"
+        ));
+        assert!(script.contains(
+            "scatter(fig, ax, data, 2, \"points\", values=2, colorbar=False, label=\"1\", color=\"C1\")\n"
+        ));
+    }
+}
@@ -1,7 +1,13 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::Path;
+
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 pub mod bin;
 pub mod comparison;
@@ -79,6 +85,57 @@ where
     }
 }
 
+impl<T> Sequence<T>
+where
+    T: Display + Clone,
+{
+    /// Starts recording the sequence under ``id``, appending new values to its data file
+    /// as they are produced. See [Recorder] for more.
+    ///
+    /// # Examples
+    ///
+    /// Recording the values of a simulation.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// let mut recorder = pre::Sequence::new(vec![0.]).record("my_identifier").unwrap();
+    /// recorder.push(1.).unwrap();
+    /// recorder.finish().unwrap();
+    /// ```
+    ///
+    /// [Recorder]: struct.Recorder.html
+    pub fn record<S: Display>(self, id: S) -> Result<crate::Recorder<Self>, PreexplorerError> {
+        let rows = self.data.len();
+        crate::Recorder::start(self, id, rows)
+    }
+}
+
+impl<T> Sequence<T>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    /// Reads a ``Sequence<T>`` from a data file written by it,
+    /// restoring title, id and date from the header.
+    ///
+    /// # Examples
+    ///
+    /// Plotting later in another binary.
+    /// ```no_run
+    /// use preexplorer::prelude::*;
+    /// let seq = pre::Sequence::<f64>::load("target/preexplorer/data/my_identifier.txt").unwrap();
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Sequence<T>, PreexplorerError> {
+        let file = crate::load::DataFile::read(path)?;
+        let data = file
+            .rows()
+            .map(|row| crate::load::parse_entry(row, 1))
+            .collect::<Result<Vec<T>, _>>()?;
+        let mut seq = Sequence::new(data);
+        file.restore_header(&mut seq);
+        Ok(seq)
+    }
+}
+
 impl<T> Add for Sequence<T>
 where
     T: Display + Clone,
@@ -108,19 +165,24 @@ impl<T> Saveable for Sequence<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.data.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-
-        for (counter, value) in self.data.clone().into_iter().enumerate() {
-            plotable_data.push_str(&format!("{}\t{}\n", counter, value));
+        for (counter, value) in self.data.iter().enumerate() {
+            writeln!(writer, "{}\t{}", counter, value)?;
         }
 
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["index", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -133,8 +195,8 @@ where
 
         let dashtype = self.dashtype().unwrap_or(1);
         gnuplot_script += &format!(
-            "plot {:?} with {} dashtype {} \n",
-            self.data_path(),
+            "plot {} with {} dashtype {} \n",
+            self.config.script_path(self.data_path()),
             self.style(),
             dashtype,
         );
@@ -144,7 +206,80 @@ where
     }
 }
 
+impl<T> Matplotlib for Sequence<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        let dashtype = self.dashtype().unwrap_or(1);
+        python_script += &crate::matplotlib::series_script(
+            &self.config,
+            self.data_path(),
+            self.style(),
+            dashtype,
+            0,
+            None,
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Sequence<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layer = crate::vega::series_layer(
+            &self.config,
+            &data,
+            ("index", "value"),
+            self.style(),
+            self.dashtype().unwrap_or(1),
+            None,
+        );
+
+        crate::vega::layered_spec(&self.config, &[layer])
+    }
+}
+
 ///////////////////////////////////////////////
+impl<T> Sequence<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    /// Points to draw, in the given color.
+    pub(crate) fn series(&self, color: usize) -> crate::figure::Series {
+        let points = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (i as f64, value.to_f64().unwrap_or(f64::NAN)));
+        let mut series = crate::figure::Series::new(points, self.style(), color);
+        series.dashtype = self.dashtype();
+        series
+    }
+}
+
+impl<T> Figurable for Sequence<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    fn figure(&self) -> crate::figure::Figure {
+        let layer = crate::figure::Layer::Series(self.series(0));
+        crate::figure::Figure::new(&self.config, vec![layer])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +295,65 @@ mod tests {
             seq.style()
         );
     }
+
+    #[test]
+    fn load() -> Result<(), PreexplorerError> {
+        let mut seq = Sequence::new(vec![0.5, 1.5, -2.]);
+        seq.set_title("My title")
+            .set_id("sequence_load_test")
+            .set_root_dir(std::env::temp_dir().join("preexplorer_sequence_load"))
+            .save()?;
+
+        let loaded = Sequence::<f64>::load(seq.data_path())?;
+        assert_eq!(loaded.data, seq.data);
+        assert_eq!(loaded.title(), seq.title());
+        assert_eq!(loaded.id(), seq.id());
+        assert_eq!(loaded.date(), seq.date());
+        Ok(())
+    }
+
+    #[test]
+    fn publication() -> Result<(), PreexplorerError> {
+        let mut seq = Sequence::new(vec![1, 2, 4]);
+        seq.set_title("Growth")
+            .set_publication(crate::Publication::Epslatex)
+            .set_root_dir(std::env::temp_dir().join("preexplorer_sequence_publication"))
+            .plot_later("sequence_publication_test")?;
+
+        let gnuplot_script = std::fs::read_to_string(seq.plot_path())?;
+        assert!(gnuplot_script.starts_with("unset key\nset terminal epslatex color\n"));
+        let latex_snippet = std::fs::read_to_string(seq.plot_path().with_extension("figure.tex"))?;
+        assert!(latex_snippet.contains("sequence_publication_test.tex}\n  \\caption{Growth}\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn plot_to_file_keeps_terminal_and_output() {
+        let mut seq = Sequence::new(0..2);
+        seq.set_root_dir(std::env::temp_dir().join("preexplorer_sequence_plot_to_file"));
+        match seq.plot_to_file("plot_to_file_test") {
+            // Testing without gnuplot installed
+            Ok(_) | Err(crate::errors::PreexplorerError::GnuplotNotFound(_)) => (),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+        let gnuplot_script = std::fs::read_to_string(seq.plot_path()).unwrap();
+        assert!(gnuplot_script.contains("set terminal pngcairo"));
+        assert_eq!(seq.terminal(), None);
+        assert_eq!(seq.output(), None);
+    }
+
+    #[test]
+    fn plot_with_wrong_script() {
+        let mut seq = Sequence::new(0..2);
+        seq.set_wait(true)
+            .set_root_dir(std::env::temp_dir().join("preexplorer_sequence_wrong_script"));
+        match seq.plot_with_script("wrong_script_test", "set title \"\"\nplot wrong_variable\n") {
+            // Testing without gnuplot installed
+            Err(crate::errors::PreexplorerError::GnuplotNotFound(_)) => (),
+            Err(crate::errors::PreexplorerError::GnuplotScript { line, .. }) => {
+                assert_eq!(line, 2)
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
 }
@@ -1,5 +1,5 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
 
@@ -118,23 +118,28 @@ impl<T> Saveable for SequenceBin<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.data.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-
-        for (counter, values) in self.data.clone().into_iter().enumerate() {
+        for (counter, values) in self.data.iter().enumerate() {
             for value in values {
-                plotable_data.push_str(&format!("{}\t{}\n", counter, value));
+                writeln!(writer, "{}\t{}", counter, value)?;
             }
             // Separate datasets
-            plotable_data.push_str("\n\n");
+            writer.write_all(b"\n\n")?;
         }
 
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["index", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -155,20 +160,21 @@ where
         gnuplot_script += &format!("\
 # Plotting each histogram
 do for [i=0:{}] {{
-    set table '{}'.'partial_plot'.i
-    plot {:?} index i using 2:(1. / (DataPoints[i+1] * {})) bins binwidth=BINWIDTH with boxes # reference: http://www.bersch.net/gnuplot-doc/plot.html#commands-plot-datafile-bins 
+    set table {}.'partial_plot'.i{}
+    plot {} index i using 2:(1. / (DataPoints[i+1] * {})) bins binwidth=BINWIDTH with boxes # reference: http://www.bersch.net/gnuplot-doc/plot.html#commands-plot-datafile-bins 
     unset table
 }}
 # Plotting the serie of histograms
 set style fill transparent solid 0.5
-plot for [i=0:{}] '{}'.'partial_plot'.i using (i):1:(i):(i+$2):3:4 with boxxyerrorbars # using x:y:xlow:xhigh:ylow:yhigh
+plot for [i=0:{}] {}.'partial_plot'.i using (i):1:(i):(i+$2):3:4 with boxxyerrorbars # using x:y:xlow:xhigh:ylow:yhigh
 ",
             self.data.len() - 1,
-            self.data_path().display(),
-            self.data_path(),
+            self.config.script_path(self.data_path()),
+            self.data_format().table_separator(),
+            self.config.script_path(self.data_path()),
             self.binwidth,
             self.data.len() - 1,
-            self.data_path().display(),
+            self.config.script_path(self.data_path()),
         );
         gnuplot_script += &self.ending_plot_script();
 
@@ -176,6 +182,42 @@ plot for [i=0:{}] '{}'.'partial_plot'.i using (i):1:(i):(i+$2):3:4 with boxxyerr
     }
 }
 
+impl<T> Matplotlib for SequenceBin<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        python_script += "# Histogram of each block, normalized\n";
+        python_script += &format!(
+            "for block in load({}):\n    bins(ax, block[0, 0], block[:, 1], {})\n",
+            self.config.python_path(self.data_path()),
+            self.binwidth,
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for SequenceBin<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layer = crate::vega::bins_layer(&self.config, &data, "index", self.binwidth, None);
+
+        crate::vega::layered_spec(&self.config, &[layer])
+    }
+}
+
 ///////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
@@ -1,9 +1,10 @@
 // Structs
 use crate::errors::PreexplorerError;
 use crate::SequenceBin;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -110,23 +111,28 @@ impl<T> Saveable for SequenceBins<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for sequence_bin in self.data_set.iter() {
-            raw_data += &sequence_bin.plotable_data();
-            raw_data += "\n";
+            sequence_bin.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
         for (counter, sequence_bin) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            sequence_bin.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(sequence_bin, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl<T> Plotable for SequenceBins<T>
@@ -171,14 +177,15 @@ where
             gnuplot_script += &format!("\
 # Plotting each histogram
 do for [i=0:{}] {{
-    set table {:?}.'_partial_plot'.i
-    plot {:?} index i using 2:(1. / (DATA_POINTS_{}[i+1] * {})) bins binwidth=BINWIDTH_{} with boxes # reference: http://www.bersch.net/gnuplot-doc/plot.html#commands-plot-datafile-bins 
+    set table {}.'_partial_plot'.i{}
+    plot {} index i using 2:(1. / (DATA_POINTS_{}[i+1] * {})) bins binwidth=BINWIDTH_{} with boxes # reference: http://www.bersch.net/gnuplot-doc/plot.html#commands-plot-datafile-bins 
     unset table
 }}
 ",
                 sequence_bin.data.len() - 1,
-                self.data_path().with_file_name(inner_id),
-                inner_path,
+                self.config.script_path(self.data_path().with_file_name(inner_id)),
+                self.data_format().table_separator(),
+                self.config.script_path(&inner_path),
                 counter,
                 sequence_bin.binwidth,
                 counter,
@@ -203,23 +210,23 @@ do for [i=0:{}] {{
                 gnuplot_script += "re";
             }
             gnuplot_script += &format!("\
-plot '{}'.'_partial_plot'.'0' using (0):1:(0):(0+$2):3:4 with boxxyerrorbars linecolor {} title \"{}\" # using x:y:xlow:xhigh:ylow:yhigh
+plot {}.'_partial_plot'.'0' using (0):1:(0):(0+$2):3:4 with boxxyerrorbars linecolor {} title {} # using x:y:xlow:xhigh:ylow:yhigh
 ",
-                inner_path.display(),
+                self.config.script_path(&inner_path),
                 counter,
-                legend,
+                self.config.text_string(&legend),
             );
         }
 
         // Plot without titles
         let mut path = self.data_path().to_path_buf();
-        path.set_file_name(&id);
+        path.set_file_name(id);
         gnuplot_script += &format!("\
 # Plotting the rest of the histograms in each sequence
-replot for [j=0:{}] for [i=1:SEQUENCE_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i using (i):1:(i):(i+$2):3:4 with boxxyerrorbars linecolor j notitle # using x:y:xlow:xhigh:ylow:yhigh
+replot for [j=0:{}] for [i=1:SEQUENCE_LENGTHS[j+1]-1] {}.'_'.j.'_partial_plot'.i using (i):1:(i):(i+$2):3:4 with boxxyerrorbars linecolor j notitle # using x:y:xlow:xhigh:ylow:yhigh
 ",
             self.data_set.len() - 1,
-            path.display(),
+            self.config.script_path(&path),
         );
         gnuplot_script += "\n";
         gnuplot_script += &self.ending_plot_script();
@@ -227,3 +234,64 @@ replot for [j=0:{}] for [i=1:SEQUENCE_LENGTHS[j+1]-1] '{}_'.j.'_partial_plot'.i
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for SequenceBins<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        for (counter, sequence_bin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match sequence_bin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            python_script += &format!(
+                "for i, block in enumerate(load({})):\n    bins(ax, block[0, 0], block[:, 1], {}, color=\"C{}\", label={} if i == 0 else None)\n",
+                self.config.python_path(&inner_path),
+                sequence_bin.binwidth,
+                counter,
+                crate::matplotlib::python_string(&legend)
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for SequenceBins<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut layers = Vec::new();
+
+        for (counter, sequence_bin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                sequence_bin,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match sequence_bin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            layers.push(crate::vega::bins_layer(
+                &self.config,
+                &data,
+                "index",
+                sequence_bin.binwidth,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
@@ -1,10 +1,13 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::{Path, PathBuf};
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Figurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
+use num_traits::ToPrimitive;
+use std::str::FromStr;
 
 /// Comparison counter part of [Sequence] struct.
 ///
@@ -43,6 +46,31 @@ where
     }
 }
 
+impl<T> Sequences<T>
+where
+    T: Display + Clone + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    /// Reads a ``Sequences<T>`` from the data files written by it,
+    /// where ``path`` is the data path of the comparison, e.g. ``target/preexplorer/data/my_identifier.txt``.
+    /// The data of each sequence is read from the files ``my_identifier_0.txt``, ``my_identifier_1.txt``, etc.
+    /// See [Sequence::load] for more.
+    ///
+    /// [Sequence::load]: struct.Sequence.html#method.load
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Sequences<T>, PreexplorerError> {
+        let path = path.as_ref();
+        let data_set = crate::load::comparison_paths(path)?
+            .iter()
+            .map(crate::Sequence::load)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut comparison = Sequences::new(data_set);
+        if let Some(id) = path.file_stem() {
+            comparison.set_id(id.to_string_lossy());
+        }
+        Ok(comparison)
+    }
+}
+
 impl<T> From<crate::sequence::Sequence<T>> for Sequences<T>
 where
     T: Display + Clone,
@@ -110,23 +138,28 @@ impl<T> Saveable for Sequences<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for sequence in self.data_set.iter() {
-            raw_data += &sequence.plotable_data();
-            raw_data += "\n";
+            sequence.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
         for (counter, sequence) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            sequence.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(sequence, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl<T> Plotable for Sequences<T>
@@ -167,8 +200,11 @@ where
                 }
             };
             gnuplot_script += &format!(
-                "{:?} using 1:2 with {} title \"{}\" dashtype {}, ",
-                inner_path, sequence_style, legend, dashtype
+                "{} using 1:2 with {} title {} dashtype {}, ",
+                self.config.script_path(&inner_path),
+                sequence_style,
+                self.config.text_string(&legend),
+                dashtype
             );
             if counter < self.data_set.len() - 1 {
                 gnuplot_script += "\\\n";
@@ -180,3 +216,189 @@ where
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for Sequences<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, sequence) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match sequence.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let sequence_style = match style {
+                crate::configuration::plot::style::Style::Default => sequence.style(),
+                _ => style,
+            };
+            let dashtype = match sequence.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            python_script += &crate::matplotlib::series_script(
+                &self.config,
+                &inner_path,
+                sequence_style,
+                dashtype,
+                counter,
+                Some(&legend),
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Sequences<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, sequence) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                sequence,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match sequence.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let sequence_style = match style {
+                crate::configuration::plot::style::Style::Default => sequence.style(),
+                _ => style,
+            };
+            let dashtype = match sequence.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.push(crate::vega::series_layer(
+                &self.config,
+                &data,
+                ("index", "value"),
+                sequence_style,
+                dashtype,
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
+
+impl<T> Sequences<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    /// Points to draw, one series per sequence.
+    pub(crate) fn series(&self) -> Vec<crate::figure::Series> {
+        self.data_set
+            .iter()
+            .enumerate()
+            .map(|(counter, sequence)| {
+                let series = sequence.series(counter);
+                crate::figure::comparison_series(series, self.style(), sequence.title(), counter)
+            })
+            .collect()
+    }
+}
+
+impl<T> Figurable for Sequences<T>
+where
+    T: Display + Clone + ToPrimitive,
+{
+    fn figure(&self) -> crate::figure::Figure {
+        let layers = self.series().into_iter().map(crate::figure::Layer::Series);
+        crate::figure::Figure::new(&self.config, layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load() -> Result<(), PreexplorerError> {
+        let mut first = crate::Sequence::new(vec![1, 2, 3]);
+        first.set_title("first");
+        let mut seqs = first + crate::Sequence::new(vec![4, 5]);
+        seqs.set_id("sequences_load_test")
+            .set_root_dir(std::env::temp_dir().join("preexplorer_sequences_load"))
+            .save()?;
+
+        let loaded = Sequences::<i32>::load(seqs.data_path())?;
+        assert_eq!(loaded.id(), seqs.id());
+        let data: Vec<Vec<i32>> = loaded.data_set.iter().map(|seq| seq.data.clone()).collect();
+        assert_eq!(data, vec![vec![1, 2, 3], vec![4, 5]]);
+        assert_eq!(loaded.data_set[0].title(), Some(&String::from("first")));
+        Ok(())
+    }
+
+    #[test]
+    fn publication_legends() {
+        let mut first = crate::Sequence::new(vec![1, 2]);
+        first.set_title("run_1 & 50%");
+        let mut seqs = first + crate::Sequence::new(vec![3]);
+        seqs.set_id("sequences_publication_test")
+            .set_publication(crate::Publication::Tikz);
+        let script = seqs.plot_script();
+
+        assert!(script.contains("title \"run\\\\_1 \\\\& 50\\\\%\" dashtype 1"));
+        assert!(!script.contains("noenhanced"));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut first = crate::Sequence::new(vec![1, 2, 3]);
+        first.set_title("first").set_style("points").unwrap();
+        let mut seqs = first + crate::Sequence::new(vec![4, 5]);
+        seqs.set_id("sequences_matplotlib_test").set_logy(10);
+        let script = seqs.matplotlib_script();
+
+        assert!(script.contains("ax.set_yscale(\"log\", base=10)\n"));
+        assert!(script.contains("sequences_matplotlib_test_0.txt\")\ndraw(ax, data[:, 0], data[:, 1], \"points\", 1, color=\"C0\", label=\"first\")\n"));
+        assert!(script.contains("sequences_matplotlib_test_1.txt\")\ndraw(ax, data[:, 0], data[:, 1], \"lines\", 2, color=\"C1\", label=\"1\")\n"));
+        assert!(script.ends_with("ax.legend()\nplt.show()\n"));
+    }
+
+    #[test]
+    fn vega_lite_spec() {
+        let mut first = crate::Sequence::new(vec![1, 2]);
+        first.set_title("first");
+        let mut seqs = first + crate::Sequence::new(vec![3]);
+        seqs.set_id("sequences_vega_lite_test").set_logy(10);
+        let spec = seqs.vega_lite_spec();
+
+        assert!(spec.contains("\"layer\": [\n{\"data\": {\"values\": [\n{\"index\":0,\"value\":1,\"block\":0},\n{\"index\":1,\"value\":2,\"block\":0}\n]}"));
+        assert!(spec.contains("\"scale\": {\"type\": \"log\", \"base\": 10}"));
+        assert!(spec.contains("\"color\": {\"datum\": \"first\"}"));
+        assert!(spec.contains("\"strokeDash\": [6, 4]}"));
+        assert!(spec.contains("\"color\": {\"datum\": \"1\"}"));
+
+        seqs.set_data_format(crate::DataFormat::Json).unwrap();
+        assert!(seqs
+            .vega_lite_spec()
+            .contains("sequences_vega_lite_test_1.json\"}"));
+    }
+}
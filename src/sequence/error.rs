@@ -1,5 +1,5 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::ops::Add;
 
 // Structs
@@ -92,19 +92,24 @@ impl Configurable for SequenceError {
 }
 
 impl Saveable for SequenceError {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.data.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-
-        for (counter, (value, error)) in self.data.clone().into_iter().enumerate() {
-            plotable_data.push_str(&format!("{}\t{}\t{}\n", counter, value, error));
+        for (counter, (value, error)) in self.data.iter().enumerate() {
+            writeln!(writer, "{}\t{}\t{}", counter, value, error)?;
         }
 
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["index", "value", "error"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -114,8 +119,8 @@ impl Plotable for SequenceError {
 
         let dashtype = self.dashtype().unwrap_or(1);
         gnuplot_script += &format!(
-            "plot {:?} using 1:2 with {} dashtype {}, \"\" using 1:2:3 with yerrorbars \n",
-            self.data_path(),
+            "plot {} using 1:2 with {} dashtype {}, \"\" using 1:2:3 with yerrorbars \n",
+            self.config.script_path(self.data_path()),
             self.style(),
             dashtype,
         );
@@ -125,6 +130,54 @@ impl Plotable for SequenceError {
     }
 }
 
+impl Matplotlib for SequenceError {
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        let dashtype = self.dashtype().unwrap_or(1);
+        python_script += &crate::matplotlib::series_script(
+            &self.config,
+            self.data_path(),
+            self.style(),
+            dashtype,
+            0,
+            None,
+        );
+        python_script +=
+            "ax.errorbar(data[:, 0], data[:, 1], yerr=data[:, 2], fmt=\"none\", color=\"C0\")\n";
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl VegaLite for SequenceError {
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layer = crate::vega::series_layer(
+            &self.config,
+            &data,
+            ("index", "value"),
+            self.style(),
+            self.dashtype().unwrap_or(1),
+            None,
+        );
+        let errors = format!(
+            "{{\"data\": {}, \"mark\": \"errorbar\", \"encoding\": {{{}, {}, \"yError\": {{\"field\": \"error\"}}}}}}",
+            data,
+            crate::vega::x(&self.config, "index"),
+            crate::vega::y(&self.config, "value"),
+        );
+
+        crate::vega::layered_spec(&self.config, &[layer, errors])
+    }
+}
+
 impl<T> From<crate::Densities<T>> for SequenceError
 where
     T: Into<f64> + core::fmt::Display + Clone,
@@ -1,8 +1,9 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -83,23 +84,28 @@ impl Configurable for SequenceErrors {
 }
 
 impl Saveable for SequenceErrors {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for sequence in self.data_set.iter() {
-            raw_data += &sequence.plotable_data();
-            raw_data += "\n";
+            sequence.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
         for (counter, sequence) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            sequence.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(sequence, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl Plotable for SequenceErrors {
@@ -119,7 +125,7 @@ impl Plotable for SequenceErrors {
                 inner_path.set_file_name(&inner_id);
                 inner_path.set_extension(extension);
             } else {
-                inner_path.set_file_name(&id);
+                inner_path.set_file_name(&inner_id);
             }
             let legend = match sequence.title() {
                 Some(leg) => String::from(leg),
@@ -137,8 +143,8 @@ impl Plotable for SequenceErrors {
                 }
             };
             gnuplot_script += &format!(
-                "{:?} using 1:2 with {} title \"{}\" dashtype {}, \\\n{:?} using 1:2:3 with yerrorbars notitle, ",
-                inner_path, sequence_style, legend, dashtype, inner_path
+                "{} using 1:2 with {} title {} dashtype {}, \\\n{} using 1:2:3 with yerrorbars notitle, ",
+                self.config.script_path(&inner_path), sequence_style, self.config.text_string(&legend), dashtype, self.config.script_path(&inner_path)
             );
             if counter < self.data_set.len() - 1 {
                 gnuplot_script += "\\\n";
@@ -150,3 +156,98 @@ impl Plotable for SequenceErrors {
         gnuplot_script
     }
 }
+
+impl Matplotlib for SequenceErrors {
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, sequence) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match sequence.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let sequence_style = match style {
+                crate::configuration::plot::style::Style::Default => sequence.style(),
+                _ => style,
+            };
+            let dashtype = match sequence.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            python_script += &crate::matplotlib::series_script(
+                &self.config,
+                &inner_path,
+                sequence_style,
+                dashtype,
+                counter,
+                Some(&legend),
+            );
+            python_script += &format!(
+                "ax.errorbar(data[:, 0], data[:, 1], yerr=data[:, 2], fmt=\"none\", color=\"C{}\")\n",
+                counter
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl VegaLite for SequenceErrors {
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, sequence) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                sequence,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match sequence.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let sequence_style = match style {
+                crate::configuration::plot::style::Style::Default => sequence.style(),
+                _ => style,
+            };
+            let dashtype = match sequence.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.push(crate::vega::series_layer(
+                &self.config,
+                &data,
+                ("index", "value"),
+                sequence_style,
+                dashtype,
+                Some(&legend),
+            ));
+            layers.push(format!(
+                "{{\"data\": {}, \"mark\": \"errorbar\", \"encoding\": {{{}, {}, \"yError\": {{\"field\": \"error\"}}, {}}}}}",
+                data,
+                crate::vega::x(&self.config, "index"),
+                crate::vega::y(&self.config, "value"),
+                crate::vega::color(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
@@ -1,5 +1,5 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
 
@@ -93,23 +93,28 @@ impl<T> Saveable for SequenceViolin<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.data.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-
-        for (counter, values) in self.data.clone().into_iter().enumerate() {
+        for (counter, values) in self.data.iter().enumerate() {
             for value in values {
-                plotable_data.push_str(&format!("{}\t{}\n", counter, value));
+                writeln!(writer, "{}\t{}", counter, value)?;
             }
             // Separate datasets
-            plotable_data.push_str("\n\n");
+            writer.write_all(b"\n\n")?;
         }
 
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["index", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -125,15 +130,15 @@ renormalize = 2
 do for [i=0:{}] {{
     # Computing some values
     set table $_
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     renormalize = (renormalize < 2 * GPVAL_Y_MAX) ? 2 * GPVAL_Y_MAX : renormalize
     # Plotting a greater domain
-    set table '{}'.'_partial_plot'.i
+    set table {}.'_partial_plot'.i{}
     x_min = (GPVAL_X_MIN < GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MIN : GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH
     x_max = (GPVAL_X_MAX > GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MAX : GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH
     set xrange [x_min:x_max]
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     # Clean the plotting
     unset xrange
@@ -142,18 +147,19 @@ do for [i=0:{}] {{
 
 # Plotting the violins
 # Right side
-plot for [i=0:{}] '{}'.'_partial_plot'.i using (i + $2/renormalize):1 with filledcurve x=i linecolor i
+plot for [i=0:{}] {}.'_partial_plot'.i using (i + $2/renormalize):1 with filledcurve x=i linecolor i
 # Left side
-replot for [i=0:{}] '{}'.'_partial_plot'.i using (i - $2/renormalize):1 with filledcurve x=i linecolor i
+replot for [i=0:{}] {}.'_partial_plot'.i using (i - $2/renormalize):1 with filledcurve x=i linecolor i
 ",
             self.data.len() - 1,
-            self.data_path(),
-            self.data_path().display(),
-            self.data_path(),
+            self.config.script_path(self.data_path()),
+            self.config.script_path(self.data_path()),
+            self.data_format().table_separator(),
+            self.config.script_path(self.data_path()),
             self.data.len() - 1,
-            self.data_path().display(),
+            self.config.script_path(self.data_path()),
             self.data.len() - 1,
-            self.data_path().display(),
+            self.config.script_path(self.data_path()),
         );
         gnuplot_script += &self.ending_plot_script();
 
@@ -161,6 +167,40 @@ replot for [i=0:{}] '{}'.'_partial_plot'.i using (i - $2/renormalize):1 with fil
     }
 }
 
+impl<T> Matplotlib for SequenceViolin<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        python_script += &format!(
+            "violins(ax, load({}))\n",
+            self.config.python_path(self.data_path())
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for SequenceViolin<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let layer = crate::vega::violins_layer(&self.config, &data, "index", None);
+
+        crate::vega::layered_spec(&self.config, &[layer])
+    }
+}
+
 impl<T> From<crate::Densities<T>> for SequenceViolin<T>
 where
     T: Display + Clone,
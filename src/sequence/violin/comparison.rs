@@ -1,9 +1,10 @@
 // Structs
 use crate::errors::PreexplorerError;
 use crate::SequenceViolin;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -110,23 +111,28 @@ impl<T> Saveable for SequenceViolins<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for sequence_violin in self.data_set.iter() {
-            raw_data += &sequence_violin.plotable_data();
-            raw_data += "\n";
+            sequence_violin.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
         for (counter, sequence_violin) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            sequence_violin.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(sequence_violin, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
 impl<T> Plotable for SequenceViolins<T>
@@ -160,15 +166,15 @@ RENORMALIZE[{}] = 2
 do for [i=0:{}] {{
     # Computing some values
     set table $_
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     RENORMALIZE[{}] = (RENORMALIZE[{}] < 2 * GPVAL_Y_MAX) ? 2 * GPVAL_Y_MAX : RENORMALIZE[{}]
     # Plotting a greater domain
-    set table {:?}.'_partial_plot'.i
+    set table {}.'_partial_plot'.i{}
     x_min = (GPVAL_X_MIN < GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MIN : GPVAL_X_MIN - 5 * GPVAL_KDENSITY_BANDWIDTH
     x_max = (GPVAL_X_MAX > GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH)? GPVAL_X_MAX : GPVAL_X_MAX + 5 * GPVAL_KDENSITY_BANDWIDTH
     set xrange [x_min:x_max]
-    plot {:?} index i using 2:(1) smooth kdensity
+    plot {} index i using 2:(1) smooth kdensity
     unset table
     # Clean the plotting
     unset xrange
@@ -178,12 +184,13 @@ do for [i=0:{}] {{
                 counter,
                 counter + 1,
                 sequence_violin.data.len() - 1,
-                inner_path,
+                self.config.script_path(&inner_path),
                 counter + 1,
                 counter + 1,
                 counter + 1,
-                self.data_path().with_file_name(inner_id),
-                inner_path,
+                self.config.script_path(self.data_path().with_file_name(inner_id)),
+                self.data_format().table_separator(),
+                self.config.script_path(&inner_path),
             );
         }
 
@@ -204,28 +211,28 @@ do for [i=0:{}] {{
                 gnuplot_script += "re";
             }
             gnuplot_script += &format!("\
-plot '{}'.'_partial_plot'.'0' using (0 + $2/RENORMALIZE[{}]):1 with filledcurve x=0 linecolor {} title \"{}\"
+plot {}.'_partial_plot'.'0' using (0 + $2/RENORMALIZE[{}]):1 with filledcurve x=0 linecolor {} title {}
 ",
-                inner_path.display(),
+                self.config.script_path(&inner_path),
                 counter + 1,
                 counter,
-                legend,
+                self.config.text_string(&legend),
             );
         }
 
         // Plot without titles
         let mut path = self.data_path().to_path_buf();
-        path.set_file_name(&id);
+        path.set_file_name(id);
         gnuplot_script += &format!("\
 # Right side
-replot for [j=0:{}] for [i=1:DATA_POINTS[j+1]-1] '{}_'.j.'_partial_plot'.i using (i + $2/RENORMALIZE[j+1]):1 with filledcurve x=i linecolor j notitle
+replot for [j=0:{}] for [i=1:DATA_POINTS[j+1]-1] {}.'_'.j.'_partial_plot'.i using (i + $2/RENORMALIZE[j+1]):1 with filledcurve x=i linecolor j notitle
 # Left side
-replot for [j=0:{}] for [i=0:DATA_POINTS[j+1]-1] '{}_'.j.'_partial_plot'.i using (i - $2/RENORMALIZE[j+1]):1 with filledcurve x=i linecolor j notitle
+replot for [j=0:{}] for [i=0:DATA_POINTS[j+1]-1] {}.'_'.j.'_partial_plot'.i using (i - $2/RENORMALIZE[j+1]):1 with filledcurve x=i linecolor j notitle
 ",
             self.data_set.len() - 1,
-            path.display(),
+            self.config.script_path(&path),
             self.data_set.len() - 1,
-            path.display(),
+            self.config.script_path(&path),
         );
         gnuplot_script += "\n";
         gnuplot_script += &self.ending_plot_script();
@@ -233,3 +240,62 @@ replot for [j=0:{}] for [i=0:DATA_POINTS[j+1]-1] '{}_'.j.'_partial_plot'.i using
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for SequenceViolins<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+
+        for (counter, sequence_violin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match sequence_violin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            python_script += &format!(
+                "violins(ax, load({}), color=\"C{}\", label={})\n",
+                self.config.python_path(&inner_path),
+                counter,
+                crate::matplotlib::python_string(&legend)
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for SequenceViolins<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut layers = Vec::new();
+
+        for (counter, sequence_violin) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                sequence_violin,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match sequence_violin.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            layers.push(crate::vega::violins_layer(
+                &self.config,
+                &data,
+                "index",
+                Some(&legend),
+            ));
+        }
+
+        crate::vega::layered_spec(&self.config, &layers)
+    }
+}
@@ -0,0 +1,488 @@
+// Traits
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::fmt::Display;
+use core::ops::Add;
+
+pub mod comparison;
+
+pub use comparison::Surfaces;
+
+/// Rotatable three-dimensional surface of values in a grid, drawn as a wireframe
+/// or colored by pm3d.
+///
+/// Check out [surface demo] of gnuplot for more options.
+///
+/// # Remarks
+///
+/// Unlike [Contour] and [Heatmap], the surface is drawn in three dimensions and can be rotated
+/// with the mouse in the interactive terminals of gnuplot.
+///
+/// Vega-Lite has no three-dimensional plots: its specifications draw the values as a heatmap.
+///
+/// # Examples
+///
+/// Quick plot.
+/// ```no_run
+/// use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// let values = iproduct!(0..10, 0..5).map(|(x, y)| x * y);
+/// pre::Surface::new(0..10, 0..5, values)
+///     .set_hidden3d(true)
+///     .set_view(60, 120)
+///     .plot("my_identifier")
+///     .unwrap();
+/// ```
+///
+/// Compare [Surface] structs.
+/// ```no_run
+/// use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// pre::Surfaces::new(vec![
+///     pre::Surface::new(0..10, 0..5, iproduct!(0..10, 0..5).map(|(x, y)| x + y)),
+///     pre::Surface::new(0..10, 0..5, iproduct!(0..10, 0..5).map(|(x, y)| x * y)),
+///     ])
+///     .plot("my_identifier").unwrap();
+/// ```
+///
+/// [Contour]: struct.Contour.html
+/// [Heatmap]: struct.Heatmap.html
+/// [Surface]: struct.Surface.html
+/// [surface demo]: http://gnuplot.sourceforge.net/demo/surface1.html
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    xs: Vec<T>,
+    ys: Vec<S>,
+    values: Vec<U>,
+    pm3d: bool,
+    scene: Scene,
+    config: crate::configuration::Configuration,
+}
+
+impl<T, S, U> Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    /// Constructs a new ``Surface<T, S, U>``.
+    ///
+    /// # Panics
+    ///
+    /// The number of values must be equal to the dimension of the grid
+    /// given by the cartesian product of ``xs`` and ``ys``.
+    ///
+    /// # Examples
+    ///
+    /// From a complicated computation.
+    /// ```
+    /// # use itertools::iproduct;
+    /// use preexplorer::prelude::*;
+    /// let values = iproduct!(0..10, 0..5).map(|(x, y)| x * y);
+    /// let surface = pre::Surface::new(0..10, 0..5, values);
+    /// ```
+    pub fn new<I, J, K>(xs: I, ys: J, values: K) -> Surface<T, S, U>
+    where
+        I: IntoIterator<Item = T>,
+        J: IntoIterator<Item = S>,
+        K: IntoIterator<Item = U>,
+    {
+        let xs: Vec<T> = xs.into_iter().collect();
+        let ys: Vec<S> = ys.into_iter().collect();
+        let values: Vec<U> = values.into_iter().collect();
+
+        debug_assert!(
+            xs.len() * ys.len() == values.len(),
+            "The numbers of values ({}) does not match the grid ({}x{})",
+            values.len(),
+            xs.len(),
+            ys.len()
+        );
+
+        let mut config = crate::configuration::Configuration::default();
+        config.set_style("lines").unwrap();
+
+        Surface {
+            xs,
+            ys,
+            values,
+            pm3d: false,
+            scene: Scene::default(),
+            config,
+        }
+    }
+
+    /// Choose whether the surface is colored by its values, with pm3d,
+    /// instead of drawn as a wireframe.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: the surface is a wireframe.
+    pub fn set_pm3d(&mut self, pm3d: bool) -> &mut Self {
+        self.pm3d = pm3d;
+        self
+    }
+
+    /// Choose whether lines hidden behind the surface are removed.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: the wireframe is transparent.
+    pub fn set_hidden3d(&mut self, hidden3d: bool) -> &mut Self {
+        self.scene.hidden3d = hidden3d;
+        self
+    }
+
+    /// Sets the view angles, in degrees: the rotation around the x axis, from looking down
+    /// the z axis, and then around the new z axis, as in ``set view`` of gnuplot.
+    ///
+    /// # Default
+    ///
+    /// The default view of gnuplot is ``(60, 30)``.
+    pub fn set_view<A, B>(&mut self, rot_x: A, rot_z: B) -> &mut Self
+    where
+        A: Into<f64>,
+        B: Into<f64>,
+    {
+        self.scene.view = Some((rot_x.into(), rot_z.into()));
+        self
+    }
+
+    /// Choose whether contours of the surface are projected on the base.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: there are no contours.
+    pub fn set_base_contours(&mut self, base_contours: bool) -> &mut Self {
+        self.scene.base_contours = base_contours;
+        self
+    }
+
+    /// Element of a gnuplot ``splot`` command drawing the surface saved in ``path``,
+    /// with the legend ``title``, both gnuplot strings.
+    pub(crate) fn plot_element(
+        &self,
+        path: &str,
+        style: &crate::configuration::plot::style::Style,
+        dashtype: usize,
+        title: Option<&str>,
+    ) -> String {
+        let title = match title {
+            Some(title) => format!(" title {}", title),
+            None => String::new(),
+        };
+        if self.pm3d {
+            format!("{} using 1:2:3 with pm3d{}", path, title)
+        } else {
+            format!(
+                "{} using 1:2:3 with {}{} dashtype {}",
+                path, style, title, dashtype
+            )
+        }
+    }
+
+    /// Matplotlib command drawing the surface saved in ``path`` in the scene.
+    pub(crate) fn matplotlib_element(
+        &self,
+        config: &crate::configuration::Configuration,
+        path: &std::path::Path,
+        scene: &Scene,
+        color: usize,
+        legend: Option<&str>,
+    ) -> String {
+        let legend = match legend {
+            Some(legend) => format!(", label={}", crate::matplotlib::python_string(legend)),
+            None => String::new(),
+        };
+        format!(
+            "surface(ax, load({}), pm3d={}, hidden={}, contours={}, color=\"C{}\"{})\n",
+            config.python_path(path),
+            python_bool(self.pm3d),
+            python_bool(scene.hidden3d),
+            python_bool(scene.base_contours),
+            color,
+            legend
+        )
+    }
+}
+
+impl<T, S, U> Add for Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    type Output = crate::Surfaces<T, S, U>;
+
+    fn add(self, other: crate::Surface<T, S, U>) -> crate::Surfaces<T, S, U> {
+        let mut cmp = self.into();
+        cmp += other;
+        cmp
+    }
+}
+
+impl<T, S, U> Configurable for Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn configuration_mut(&mut self) -> &mut crate::configuration::Configuration {
+        &mut self.config
+    }
+    fn configuration(&self) -> &crate::configuration::Configuration {
+        &self.config
+    }
+}
+
+impl<T, S, U> Saveable for Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Initial warning
+        if self.xs.is_empty() {
+            eprintln!("Warning: There is no data.");
+        }
+
+        for i in 0..self.xs.len() {
+            for j in 0..self.ys.len() {
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}",
+                    self.xs[i],
+                    self.ys[j],
+                    self.values[i * self.ys.len() + j]
+                )?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["x", "y", "value"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+}
+
+impl<T, S, U> Plotable for Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn plot_script(&self) -> String {
+        let mut gnuplot_script = self.opening_plot_script();
+
+        gnuplot_script += &self.scene.plot_script(self.pm3d);
+        gnuplot_script += &format!(
+            "splot {}\n",
+            self.plot_element(
+                &self.config.script_path(self.data_path()),
+                self.style(),
+                self.dashtype().unwrap_or(1),
+                None
+            )
+        );
+        gnuplot_script += &self.ending_plot_script();
+
+        gnuplot_script
+    }
+}
+
+impl<T, S, U> Matplotlib for Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.config.opening_matplotlib_script_3d();
+
+        python_script +=
+            &self.matplotlib_element(&self.config, self.data_path(), &self.scene, 0, None);
+        python_script += &self.scene.matplotlib_script();
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S, U> VegaLite for Surface<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        // Vega-Lite has no surfaces: the values are drawn as a heatmap
+        let view = format!(
+            "{},\n{}",
+            crate::vega::size(),
+            crate::vega::heatmap_view(&self.config, &data)
+        );
+
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
+impl<T> From<ndarray::Array2<T>> for Surface<usize, usize, T>
+where
+    T: Display + Clone,
+{
+    fn from(array: ndarray::Array2<T>) -> Self {
+        let shape = array.shape();
+
+        let xs: Vec<usize> = (0..shape[0]).collect();
+        let ys: Vec<usize> = (0..shape[1]).rev().collect();
+        let values: Vec<T> = array.t().iter().cloned().collect();
+
+        Surface::new(xs, ys, values)
+    }
+}
+
+/// Options of a three-dimensional plot, shared by all surfaces in it.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Scene {
+    pub(crate) hidden3d: bool,
+    pub(crate) view: Option<(f64, f64)>,
+    pub(crate) base_contours: bool,
+}
+
+impl Scene {
+    /// Gnuplot commands setting up the scene, before ``splot``, if ``pm3d`` surfaces
+    /// are among the surfaces drawn or not.
+    pub(crate) fn plot_script(&self, pm3d: bool) -> String {
+        let mut gnuplot_script = String::new();
+        if pm3d {
+            // Hidden line removal does not apply to pm3d, which has to draw from back to front
+            gnuplot_script += "set pm3d depthorder\n";
+        }
+        if self.hidden3d {
+            gnuplot_script += "set hidden3d\n";
+        }
+        if let Some((rot_x, rot_z)) = self.view {
+            gnuplot_script += &format!("set view {},{}\n", rot_x, rot_z);
+        }
+        if self.base_contours {
+            gnuplot_script += "set contour base\n";
+        }
+        gnuplot_script
+    }
+
+    /// Matplotlib commands setting up the scene, after drawing the surfaces.
+    ///
+    /// The view of gnuplot is looking down the z axis, rotated by ``rot_x`` around the x axis
+    /// and by ``rot_z`` around the z axis, which corresponds to an elevation of
+    /// ``90 - rot_x`` and an azimuth of ``rot_z - 90``.
+    pub(crate) fn matplotlib_script(&self) -> String {
+        match self.view {
+            Some((rot_x, rot_z)) => format!("ax.view_init({}, {})\n", 90. - rot_x, rot_z - 90.),
+            None => String::new(),
+        }
+    }
+}
+
+/// Boolean as written in Python.
+fn python_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plotable_data() {
+        let surface = Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        assert_eq!(
+            surface.plotable_data(),
+            "0\t0\t1\n0\t1\t2\n\n1\t0\t3\n1\t1\t4\n\n"
+        );
+    }
+
+    #[test]
+    fn plot_script() {
+        let mut surface = Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        surface.set_id("surface_test");
+        let script = surface.plot_script();
+        assert!(!script.contains("pm3d"));
+        assert!(!script.contains("set contour"));
+        assert!(script.contains("surface_test.txt\" using 1:2:3 with lines dashtype 1\n"));
+
+        surface
+            .set_hidden3d(true)
+            .set_view(45, 120)
+            .set_base_contours(true)
+            .set_pm3d(true);
+        let script = surface.plot_script();
+        assert!(script.contains(
+            "set pm3d depthorder\nset hidden3d\nset view 45,120\nset contour base\nsplot "
+        ));
+        assert!(script.contains("surface_test.txt\" using 1:2:3 with pm3d\n"));
+    }
+
+    #[test]
+    fn pm3d() -> Result<(), crate::errors::PreexplorerError> {
+        let mut surface = Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        surface
+            .set_pm3d(true)
+            .set_root_dir(std::env::temp_dir().join("preexplorer_surface_pm3d"))
+            .plot_later("surface_pm3d_test")?;
+        let script = std::fs::read_to_string(surface.plot_path())?;
+
+        assert!(script.contains("set pm3d depthorder\nsplot "));
+        assert!(script.contains("surface_pm3d_test.txt\" using 1:2:3 with pm3d\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut surface = Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        surface
+            .set_hidden3d(true)
+            .set_view(60, 30)
+            .set_labelz("height")
+            .set_id("surface_matplotlib_test");
+        let script = surface.matplotlib_script();
+
+        assert!(script.contains("fig, ax = plt.subplots(subplot_kw={\"projection\": \"3d\"})\n"));
+        assert!(script.contains("ax.set_zlabel(\"height\")\n"));
+        assert!(script.contains(
+            "surface_matplotlib_test.txt\"), pm3d=False, hidden=True, contours=False, color=\"C0\")\n"
+        ));
+        assert!(script.contains("ax.view_init(30, -60)\n"));
+    }
+
+    #[test]
+    fn vega_lite_spec() {
+        let mut surface = Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        surface.set_id("surface_vega_lite_test");
+        let spec = surface.vega_lite_spec();
+
+        assert!(spec.contains("{\"x\":1,\"y\":1,\"value\":4,\"block\":1}"));
+        assert!(spec.contains("\"mark\": \"rect\""));
+    }
+}
@@ -0,0 +1,363 @@
+// Structs
+use crate::errors::PreexplorerError;
+use std::path::PathBuf;
+
+// Traits
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::fmt::Display;
+use core::ops::{Add, AddAssign};
+
+/// Comparison counter part of [Surface] struct.
+///
+/// # Remarks
+///
+/// All surfaces are drawn in the same axes, with the hidden lines, view and contours
+/// of the comparison, not of each surface.
+///
+/// # Examples
+///
+/// Quick plot.
+/// ```no_run
+/// # use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// let many_surfaces = (0..3).map(|i| pre::Surface::new(0..10, 0..5, iproduct!(0..10, 0..5).map(move |(x, y)| x * y + 10 * i)));
+/// pre::Surfaces::new(many_surfaces).plot("my_identifier").unwrap();
+/// ```
+///
+/// [Surface]: struct.Surface.html
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    data_set: Vec<crate::Surface<T, S, U>>,
+    scene: crate::surface::Scene,
+    config: crate::configuration::Configuration,
+}
+
+impl<T, S, U> Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    pub fn new<I>(data_set: I) -> Surfaces<T, S, U>
+    where
+        I: IntoIterator<Item = crate::Surface<T, S, U>>,
+    {
+        let config = crate::configuration::Configuration::default();
+        let data_set = data_set
+            .into_iter()
+            .collect::<Vec<crate::Surface<T, S, U>>>();
+        Surfaces {
+            data_set,
+            scene: crate::surface::Scene::default(),
+            config,
+        }
+    }
+
+    /// Choose whether lines hidden behind the surfaces are removed.
+    /// See [Surface::set_hidden3d].
+    ///
+    /// [Surface::set_hidden3d]: struct.Surface.html#method.set_hidden3d
+    pub fn set_hidden3d(&mut self, hidden3d: bool) -> &mut Self {
+        self.scene.hidden3d = hidden3d;
+        self
+    }
+
+    /// Sets the view angles, in degrees. See [Surface::set_view].
+    ///
+    /// [Surface::set_view]: struct.Surface.html#method.set_view
+    pub fn set_view<A, B>(&mut self, rot_x: A, rot_z: B) -> &mut Self
+    where
+        A: Into<f64>,
+        B: Into<f64>,
+    {
+        self.scene.view = Some((rot_x.into(), rot_z.into()));
+        self
+    }
+
+    /// Choose whether contours of the surfaces are projected on the base.
+    /// See [Surface::set_base_contours].
+    ///
+    /// [Surface::set_base_contours]: struct.Surface.html#method.set_base_contours
+    pub fn set_base_contours(&mut self, base_contours: bool) -> &mut Self {
+        self.scene.base_contours = base_contours;
+        self
+    }
+}
+
+impl<T, S, U> From<crate::Surface<T, S, U>> for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn from(surface: crate::Surface<T, S, U>) -> Self {
+        Surfaces::new(vec![surface])
+    }
+}
+
+impl<T, S, U> Add<crate::Surface<T, S, U>> for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: crate::Surface<T, S, U>) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<T, S, U> Add for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<T, S, U> AddAssign<crate::Surface<T, S, U>> for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn add_assign(&mut self, other: crate::Surface<T, S, U>) {
+        self.data_set.push(other);
+    }
+}
+
+impl<T, S, U> AddAssign for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn add_assign(&mut self, mut other: Self) {
+        self.data_set.append(&mut other.data_set);
+    }
+}
+
+impl<T, S, U> Configurable for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn configuration_mut(&mut self) -> &mut crate::configuration::Configuration {
+        &mut self.config
+    }
+    fn configuration(&self) -> &crate::configuration::Configuration {
+        &self.config
+    }
+}
+
+impl<T, S, U> Saveable for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for surface in self.data_set.iter() {
+            surface.write_data(writer)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn save_with_id<W: Display>(&self, id: W) -> Result<&Self, PreexplorerError> {
+        for (counter, surface) in self.data_set.iter().enumerate() {
+            let inner_id = format!("{}_{}", id, counter);
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(surface, &inner_path)?;
+        }
+        Ok(self)
+    }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
+}
+
+impl<T, S, U> Plotable for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn plot_script(&self) -> String {
+        let id = self.checked_id();
+        let mut gnuplot_script = self.config.opening_plot_script_comparison();
+        let pm3d = self.data_set.iter().any(|surface| surface.pm3d);
+        gnuplot_script += &self.scene.plot_script(pm3d);
+
+        gnuplot_script += "splot ";
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, surface) in self.data_set.iter().enumerate() {
+            let inner_id = format!("{}_{}", id, counter);
+            let mut inner_path = self.data_path().to_path_buf();
+            if let Some(extension) = self.data_extension() {
+                inner_path.set_file_name(&inner_id);
+                inner_path.set_extension(extension);
+            } else {
+                inner_path.set_file_name(&inner_id);
+            }
+            let legend = match surface.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let surface_style = match style {
+                crate::configuration::plot::style::Style::Default => surface.style(),
+                _ => style,
+            };
+            let dashtype = match surface.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            gnuplot_script += &surface.plot_element(
+                &self.config.script_path(&inner_path),
+                surface_style,
+                dashtype,
+                Some(&self.config.text_string(&legend)),
+            );
+            gnuplot_script += ", ";
+
+            if counter < self.data_set.len() - 1 {
+                gnuplot_script += "\\\n";
+            }
+        }
+        gnuplot_script += "\n";
+        gnuplot_script += &self.ending_plot_script();
+
+        gnuplot_script
+    }
+}
+
+impl<T, S, U> Matplotlib for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script_3d();
+
+        for (counter, surface) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match surface.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            python_script += &surface.matplotlib_element(
+                &self.config,
+                &inner_path,
+                &self.scene,
+                counter,
+                Some(&legend),
+            );
+        }
+        python_script += &self.scene.matplotlib_script();
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S, U> VegaLite for Surfaces<T, S, U>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+    U: Display + Clone,
+{
+    /// Each surface as a heatmap in its own view.
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let mut views = Vec::new();
+
+        for (counter, surface) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                surface,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match surface.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            views.push(format!(
+                "{{\"title\": {}, {}, {}}}",
+                crate::configuration::save::format::json_string(&legend),
+                crate::vega::size(),
+                crate::vega::heatmap_view(&self.config, &data),
+            ));
+        }
+
+        let rows = (self.data_set.len() as f64).sqrt().ceil();
+        let columns = (self.data_set.len() as f64 / rows).ceil();
+        let view = format!(
+            "\"columns\": {},\n\"concat\": [\n{}\n],\n\"resolve\": {{\"scale\": {{\"color\": \"independent\"}}}}",
+            columns,
+            views.join(",\n"),
+        );
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_script() {
+        let mut first = crate::Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        first.set_title("first").set_pm3d(true);
+        let mut surfaces = first + crate::Surface::new(0..2, 0..2, vec![4, 3, 2, 1]);
+        surfaces.set_id("surfaces_test").set_view(30, 60);
+        let script = surfaces.plot_script();
+
+        assert!(script.contains("set pm3d depthorder\nset view 30,60\nsplot "));
+        assert!(script
+            .contains("surfaces_test_0.txt\" using 1:2:3 with pm3d title \"first\" noenhanced, "));
+        assert!(script.contains(
+            "surfaces_test_1.txt\" using 1:2:3 with lines title \"1\" noenhanced dashtype 2, "
+        ));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let surface = crate::Surface::new(0..2, 0..2, vec![1, 2, 3, 4]);
+        let mut surfaces = surface.clone() + surface;
+        surfaces
+            .set_base_contours(true)
+            .set_id("surfaces_matplotlib_test");
+        let script = surfaces.matplotlib_script();
+
+        assert!(script.contains("contours=True, color=\"C1\", label=\"1\")\n"));
+        assert!(!script.contains("ax.view_init"));
+    }
+}
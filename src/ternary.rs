@@ -1,5 +1,5 @@
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::Add;
 
@@ -13,10 +13,13 @@ pub use comparison::Ternaries;
 ///
 /// # Remarks
 ///
-/// Values will be automatically normalized (per entry) for plotting, but not for saving. 
+/// Values will be automatically normalized (per entry) for plotting, but not for saving.
 /// In particular, the data point `(1, 1, 0)` will be saved as such, but will be ploted
 /// as the probability distribution `(0.5, 0.5, 0.0)`.
 ///
+/// The corners of the simplex are labeled by the x, y and z labels, in that order.
+/// By default, they are labeled `p_1`, `p_2` and `p_3`.
+///
 /// # Examples
 ///
 /// Quick plot.
@@ -30,8 +33,8 @@ pub use comparison::Ternaries;
 /// ```no_run
 /// use preexplorer::prelude::*;
 /// pre::Ternaries::new([
-///     pre::Ternary::new([[0.5, 0.5, 0.0]]);
-///     pre::Ternary::new([[0.0, 0.5, 0.5]]);
+///     pre::Ternary::new([[0.5, 0.5, 0.0]]),
+///     pre::Ternary::new([[0.0, 0.5, 0.5]]),
 ///     ])
 ///     .plot("my_identifier").unwrap();
 /// ```
@@ -63,12 +66,13 @@ where
     /// let data = (0..10).map(|i| [i * i + 1, i, 0] );
     /// let ternary = pre::Ternary::new(data);
     /// ```
-    pub fn new<I, D>(data: I) -> Ternary<T>
+    pub fn new<I>(data: I) -> Ternary<T>
     where
         I: IntoIterator<Item = [T; 3]>,
     {
         let data: Vec<[T; 3]> = data.into_iter().collect();
-        let config = crate::configuration::Configuration::default();
+        let mut config = crate::configuration::Configuration::default();
+        config.set_style("points").unwrap();
 
         Ternary { data, config }
     }
@@ -103,19 +107,28 @@ impl<T> Saveable for Ternary<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Initial warning
         if self.data.is_empty() {
             eprintln!("Warning: There is no data.");
         }
 
-        let mut plotable_data = String::new();
-
-        for probability in self.data.clone() {
-            plotable_data.push_str(&format!("{}\t{}\t{}\n", probability[0], probability[1], probability[2]));
+        for probability in self.data.iter() {
+            writeln!(
+                writer,
+                "{}\t{}\t{}",
+                probability[0], probability[1], probability[2]
+            )?;
         }
 
-        plotable_data
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["p_1", "p_2", "p_3"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
 }
 
@@ -125,26 +138,316 @@ where
 {
     fn plot_script(&self) -> String {
         let mut gnuplot_script = self.opening_plot_script();
+        gnuplot_script += &simplex_plot_script(self.configuration());
 
-        //////////////////////////////////////////////////
-        todo!("Check out internet scripts");
-        todo!("Make your own script");
-
-        // let dashtype = self.dashtype().unwrap_or(1);
-        // gnuplot_script += &format!(
-        //     "plot {:?} with {} dashtype {} \n",
-        //     self.data_path(),
-        //     self.style(),
-        //     dashtype,
-        // );
-        //////////////////////////////////////////////////
-
+        let dashtype = self.dashtype().unwrap_or(1);
+        gnuplot_script += &format!(
+            "plot {} using (simplex_x($1, $2, $3)):(simplex_y($1, $2, $3)) with {} dashtype {}\n",
+            self.config.script_path(self.data_path()),
+            self.style(),
+            dashtype,
+        );
         gnuplot_script += &self.ending_plot_script();
 
         gnuplot_script
     }
 }
 
+impl<T> Matplotlib for Ternary<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+        python_script += &simplex_matplotlib_script(self.configuration());
+
+        let dashtype = self.dashtype().unwrap_or(1);
+        python_script += &format!(
+            "data = table({})\ndraw(ax, *simplex(data), \"{}\", {}, color=\"C0\")\n",
+            self.config.python_path(self.data_path()),
+            self.style(),
+            dashtype,
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Ternary<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+        let mut layers = simplex_vega_lite_layers(self.configuration());
+        layers.push(simplex_vega_lite_layer(
+            &data,
+            self.style(),
+            self.dashtype().unwrap_or(1),
+            None,
+        ));
+
+        simplex_vega_lite_spec(self.configuration(), &layers)
+    }
+}
+
+/// Number of divisions of each side of the simplex for gridlines.
+const GRID_DIVISIONS: usize = 10;
+
+/// Gnuplot commands that draw the two-dimensional simplex: sides, gridlines and corner labels.
+///
+/// It also defines the functions ``simplex_x`` and ``simplex_y``, which map a (non-normalized)
+/// point ``(p_1, p_2, p_3)`` to cartesian coordinates in the plane. The first corner
+/// is located at ``(0, 0)``, the second at ``(1, 0)`` and the third at ``(1/2, sqrt(3)/2)``.
+pub(crate) fn simplex_plot_script(config: &crate::configuration::Configuration) -> String {
+    let height = 3f64.sqrt() / 2.;
+    let mut gnuplot_script = String::new();
+
+    gnuplot_script += "# Ternary plot: coordinates in the two-dimensional simplex\n";
+    gnuplot_script += "simplex_x(a, b, c) = (b + c / 2.) / (a + b + c)\n";
+    gnuplot_script += "simplex_y(a, b, c) = (sqrt(3.) / 2. * c) / (a + b + c)\n";
+    gnuplot_script += "unset border\n";
+    gnuplot_script += "unset xtics\n";
+    gnuplot_script += "unset ytics\n";
+    gnuplot_script += "unset xlabel\n";
+    gnuplot_script += "unset ylabel\n";
+    gnuplot_script += "set size ratio -1\n";
+    gnuplot_script += "set xrange [-0.1:1.1]\n";
+    gnuplot_script += &format!("set yrange [-0.1:{}]\n", height + 0.1);
+
+    // Gridlines
+    gnuplot_script += "# Gridlines\n";
+    for i in 1..GRID_DIVISIONS {
+        let g = i as f64 / GRID_DIVISIONS as f64;
+        let segments = [
+            // Constant first coordinate
+            ((1. - g, 0.), ((1. - g) / 2., (1. - g) * height)),
+            // Constant second coordinate
+            ((g, 0.), (g + (1. - g) / 2., (1. - g) * height)),
+            // Constant third coordinate
+            ((g / 2., g * height), (1. - g / 2., g * height)),
+        ];
+        for (from, to) in segments.iter() {
+            gnuplot_script += &format!(
+                "set arrow from {},{} to {},{} nohead dashtype 3 linecolor \"grey\" back\n",
+                from.0, from.1, to.0, to.1
+            );
+        }
+    }
+
+    // Sides
+    gnuplot_script += "# Sides\n";
+    let corners = [(0., 0.), (1., 0.), (0.5, height)];
+    for i in 0..3 {
+        let from = corners[i];
+        let to = corners[(i + 1) % 3];
+        gnuplot_script += &format!(
+            "set arrow from {},{} to {},{} nohead linewidth 1.5 front\n",
+            from.0, from.1, to.0, to.1
+        );
+    }
+
+    // Corner labels
+    gnuplot_script += "# Corner labels\n";
+    let label_1 = config.labelx().map(|s| s.as_str()).unwrap_or("p_1");
+    let label_2 = config.labely().map(|s| s.as_str()).unwrap_or("p_2");
+    let label_3 = config.labelz().map(|s| s.as_str()).unwrap_or("p_3");
+    gnuplot_script += &format!(
+        "set label {} at -0.03,-0.03 right\n",
+        config.text_string(label_1)
+    );
+    gnuplot_script += &format!(
+        "set label {} at 1.03,-0.03 left\n",
+        config.text_string(label_2)
+    );
+    gnuplot_script += &format!(
+        "set label {} at 0.5,{} center\n",
+        config.text_string(label_3),
+        height + 0.05
+    );
+
+    gnuplot_script
+}
+
+/// Matplotlib commands drawing the two-dimensional simplex, with gridlines and corner labels,
+/// in the axes ``ax``, without any frame.
+///
+/// It also defines the function ``simplex``, which maps the rows of (non-normalized)
+/// points ``(p_1, p_2, p_3)`` to cartesian coordinates in the plane, as in [simplex_plot_script].
+///
+/// [simplex_plot_script]: fn.simplex_plot_script.html
+pub(crate) fn simplex_matplotlib_script(config: &crate::configuration::Configuration) -> String {
+    let height = 3f64.sqrt() / 2.;
+    let mut python_script = String::new();
+
+    python_script += "# Ternary plot: coordinates in the two-dimensional simplex\n";
+    python_script += "def simplex(data):\n";
+    python_script += "    total = data.sum(axis=1)\n";
+    python_script += "    return (data[:, 1] + data[:, 2] / 2.) / total, np.sqrt(3.) / 2. * data[:, 2] / total\n\n";
+    python_script += "ax.set_axis_off()\n";
+    python_script += "ax.set_aspect(\"equal\")\n";
+    python_script += "ax.set_xlim(-0.1, 1.1)\n";
+    python_script += &format!("ax.set_ylim(-0.1, {})\n", height + 0.1);
+    python_script += &format!("HEIGHT = {}\n", height);
+
+    // Gridlines
+    python_script += "# Gridlines\n";
+    python_script += &format!("for g in np.arange(1, {0}) / {0}:\n", GRID_DIVISIONS);
+    python_script += "    ax.plot([1 - g, (1 - g) / 2], [0, (1 - g) * HEIGHT], \":\", color=\"grey\", linewidth=0.5)\n";
+    python_script += "    ax.plot([g, g + (1 - g) / 2], [0, (1 - g) * HEIGHT], \":\", color=\"grey\", linewidth=0.5)\n";
+    python_script += "    ax.plot([g / 2, 1 - g / 2], [g * HEIGHT, g * HEIGHT], \":\", color=\"grey\", linewidth=0.5)\n";
+
+    // Sides
+    python_script += "# Sides\n";
+    python_script += "ax.plot([0, 1, 0.5, 0], [0, 0, HEIGHT, 0], color=\"black\", linewidth=1.5)\n";
+
+    // Corner labels
+    python_script += "# Corner labels\n";
+    let label_1 = config.labelx().map(|s| s.as_str()).unwrap_or("p_1");
+    let label_2 = config.labely().map(|s| s.as_str()).unwrap_or("p_2");
+    let label_3 = config.labelz().map(|s| s.as_str()).unwrap_or("p_3");
+    python_script += &format!(
+        "ax.text(-0.03, -0.03, {}, ha=\"right\", va=\"top\")\n",
+        crate::matplotlib::python_string(label_1)
+    );
+    python_script += &format!(
+        "ax.text(1.03, -0.03, {}, ha=\"left\", va=\"top\")\n",
+        crate::matplotlib::python_string(label_2)
+    );
+    python_script += &format!(
+        "ax.text(0.5, HEIGHT + 0.05, {}, ha=\"center\", va=\"bottom\")\n",
+        crate::matplotlib::python_string(label_3)
+    );
+
+    python_script
+}
+
+/// Vega-Lite layers drawing the two-dimensional simplex: gridlines, sides and corner labels,
+/// in the coordinates of [simplex_plot_script].
+///
+/// [simplex_plot_script]: fn.simplex_plot_script.html
+pub(crate) fn simplex_vega_lite_layers(
+    config: &crate::configuration::Configuration,
+) -> Vec<String> {
+    let height = 3f64.sqrt() / 2.;
+    let mut layers = Vec::new();
+
+    // Gridlines
+    let mut segments = Vec::new();
+    for i in 1..GRID_DIVISIONS {
+        let g = i as f64 / GRID_DIVISIONS as f64;
+        for (from, to) in [
+            ((1. - g, 0.), ((1. - g) / 2., (1. - g) * height)),
+            ((g, 0.), (g + (1. - g) / 2., (1. - g) * height)),
+            ((g / 2., g * height), (1. - g / 2., g * height)),
+        ] {
+            segments.push(format!(
+                "{{\"x\": {}, \"y\": {}, \"x2\": {}, \"y2\": {}}}",
+                from.0, from.1, to.0, to.1
+            ));
+        }
+    }
+    layers.push(format!(
+        "{{\"data\": {{\"values\": [{}]}}, \"mark\": {{\"type\": \"rule\", \"color\": \"grey\", \"strokeDash\": [2, 2]}}, \
+        \"encoding\": {{{}, \"x2\": {{\"field\": \"x2\"}}, \"y2\": {{\"field\": \"y2\"}}}}}}",
+        segments.join(", "),
+        simplex_vega_lite_encoding("x", "y"),
+    ));
+
+    // Sides
+    layers.push(format!(
+        "{{\"data\": {{\"values\": [\
+            {{\"x\": 0, \"y\": 0, \"order\": 0}}, {{\"x\": 1, \"y\": 0, \"order\": 1}}, \
+            {{\"x\": 0.5, \"y\": {}, \"order\": 2}}, {{\"x\": 0, \"y\": 0, \"order\": 3}}]}}, \
+        \"mark\": {{\"type\": \"line\", \"color\": \"black\"}}, \
+        \"encoding\": {{{}, \"order\": {{\"field\": \"order\"}}}}}}",
+        height,
+        simplex_vega_lite_encoding("x", "y"),
+    ));
+
+    // Corner labels
+    let label_1 = config.labelx().map(|s| s.as_str()).unwrap_or("p_1");
+    let label_2 = config.labely().map(|s| s.as_str()).unwrap_or("p_2");
+    let label_3 = config.labelz().map(|s| s.as_str()).unwrap_or("p_3");
+    for (label, (x, y), align, baseline) in [
+        (label_1, (-0.03, -0.03), "right", "top"),
+        (label_2, (1.03, -0.03), "left", "top"),
+        (label_3, (0.5, height + 0.05), "center", "bottom"),
+    ] {
+        layers.push(format!(
+            "{{\"data\": {{\"values\": [{{\"x\": {}, \"y\": {}, \"label\": {}}}]}}, \
+            \"mark\": {{\"type\": \"text\", \"align\": \"{}\", \"baseline\": \"{}\"}}, \
+            \"encoding\": {{{}, \"text\": {{\"field\": \"label\"}}}}}}",
+            x,
+            y,
+            crate::configuration::save::format::json_string(label),
+            align,
+            baseline,
+            simplex_vega_lite_encoding("x", "y"),
+        ));
+    }
+
+    layers
+}
+
+/// Vega-Lite layer drawing the (non-normalized) points ``(p_1, p_2, p_3)`` of the data
+/// in the simplex, colored by its legend, if any.
+pub(crate) fn simplex_vega_lite_layer(
+    data: &str,
+    style: &crate::configuration::plot::style::Style,
+    dashtype: usize,
+    legend: Option<&str>,
+) -> String {
+    let mut encoding = simplex_vega_lite_encoding("simplex_x", "simplex_y");
+    if let Some(legend) = legend {
+        encoding += &format!(", {}", crate::vega::color(legend));
+    }
+    format!(
+        "{{\"data\": {}, \"transform\": [\
+            {{\"calculate\": \"(datum.p_2 + datum.p_3 / 2) / (datum.p_1 + datum.p_2 + datum.p_3)\", \"as\": \"simplex_x\"}}, \
+            {{\"calculate\": \"sqrt(3) / 2 * datum.p_3 / (datum.p_1 + datum.p_2 + datum.p_3)\", \"as\": \"simplex_y\"}}], \
+        \"mark\": {}, \"encoding\": {{{}}}}}",
+        data,
+        crate::vega::mark(style, dashtype),
+        encoding,
+    )
+}
+
+/// Vega-Lite specification of a ternary plot with the given layers, without axes.
+pub(crate) fn simplex_vega_lite_spec(
+    config: &crate::configuration::Configuration,
+    layers: &[String],
+) -> String {
+    let height = 3f64.sqrt() / 2.;
+    crate::vega::spec(
+        config,
+        &format!(
+            "\"width\": {}, \"height\": {},\n\"layer\": [\n{}\n]",
+            400,
+            (400. * (height + 0.2) / 1.2).round(),
+            layers.join(",\n")
+        ),
+    )
+}
+
+/// Encoding of the position in the plane of the simplex, without axes.
+fn simplex_vega_lite_encoding(x_field: &str, y_field: &str) -> String {
+    let height = 3f64.sqrt() / 2.;
+    format!(
+        "\"x\": {{\"field\": \"{}\", \"type\": \"quantitative\", \"axis\": null, \"scale\": {{\"domain\": [-0.1, 1.1]}}}}, \
+        \"y\": {{\"field\": \"{}\", \"type\": \"quantitative\", \"axis\": null, \"scale\": {{\"domain\": [-0.1, {}]}}}}",
+        x_field,
+        y_field,
+        height + 0.1
+    )
+}
+
 ///////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
@@ -161,4 +464,66 @@ mod tests {
             seq.style()
         );
     }
+
+    #[test]
+    fn plot_script() {
+        let data = [[0.5, 0.5, 0.0], [0.2, 0.3, 0.5]];
+        let mut ternary = Ternary::new(data);
+        ternary
+            .set_id("ternary_test")
+            .set_labelx("Rock")
+            .set_labely("Paper");
+        let script = ternary.plot_script();
+
+        assert!(script.contains("simplex_x(a, b, c) = (b + c / 2.) / (a + b + c)\n"));
+        assert!(script.contains("set size ratio -1\n"));
+        assert!(script.contains("set label \"Rock\" noenhanced at"));
+        assert!(script.contains("set label \"Paper\" noenhanced at"));
+        assert!(script.contains("set label \"p_3\" noenhanced at"));
+        assert!(
+            script.contains("using (simplex_x($1, $2, $3)):(simplex_y($1, $2, $3)) with points")
+        );
+        // Three sides and three families of gridlines
+        assert_eq!(
+            script.matches("set arrow").count(),
+            3 + 3 * (GRID_DIVISIONS - 1)
+        );
+    }
+
+    #[test]
+    fn plotable_data() {
+        let ternary = Ternary::new([[1, 1, 0], [0, 0, 2]]);
+        assert_eq!(ternary.plotable_data(), "1\t1\t0\n0\t0\t2\n");
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut ternary = Ternary::new([[0.5, 0.5, 0.0]]);
+        ternary
+            .set_id("ternary_matplotlib_test")
+            .set_labelz("Scissors");
+        let script = ternary.matplotlib_script();
+
+        assert!(script.contains("def simplex(data):\n"));
+        assert!(script.contains("ax.set_axis_off()\n"));
+        assert!(script.contains("ax.text(0.5, HEIGHT + 0.05, \"Scissors\""));
+        assert!(script.contains("draw(ax, *simplex(data), \"points\", 1, color=\"C0\")\n"));
+    }
+
+    #[test]
+    fn vega_lite_spec() {
+        let mut ternary = Ternary::new([[0.5, 0.5, 0.0]]);
+        ternary
+            .set_id("ternary_vega_lite_test")
+            .set_labelz("Scissors");
+        let spec = ternary.vega_lite_spec();
+
+        assert!(
+            spec.contains("{\"values\": [\n{\"p_1\":0.5,\"p_2\":0.5,\"p_3\":0,\"block\":0}\n]}")
+        );
+        assert!(spec.contains("\"as\": \"simplex_x\""));
+        assert!(spec.contains("\"label\": \"Scissors\""));
+        assert!(spec.contains("\"axis\": null"));
+        assert_eq!(spec.matches("\"mark\":").count(), 6);
+    }
 }
@@ -1,8 +1,9 @@
 // Structs
 use crate::errors::PreexplorerError;
+use std::path::PathBuf;
 
 // Traits
-pub use crate::traits::{Configurable, Plotable, Saveable};
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
 use core::fmt::Display;
 use core::ops::{Add, AddAssign};
 
@@ -31,15 +32,13 @@ impl<T> Ternaries<T>
 where
     T: Display + Clone,
 {
-    pub fn new<I>(data_set: I) -> Sequences<T>
+    pub fn new<I>(data_set: I) -> Ternaries<T>
     where
         I: IntoIterator<Item = crate::Ternary<T>>,
     {
         let config = crate::configuration::Configuration::default();
-        let data_set = data_set
-            .into_iter()
-            .collect::<Vec<crate::Ternary<T>>>();
-        Sequences { data_set, config }
+        let data_set = data_set.into_iter().collect::<Vec<crate::Ternary<T>>>();
+        Ternaries { data_set, config }
     }
 }
 
@@ -48,7 +47,7 @@ where
     T: Display + Clone,
 {
     fn from(ternary: crate::Ternary<T>) -> Self {
-        Sequences::new(vec![ternary])
+        Ternaries::new(vec![ternary])
     }
 }
 
@@ -110,32 +109,38 @@ impl<T> Saveable for Ternaries<T>
 where
     T: Display + Clone,
 {
-    fn plotable_data(&self) -> String {
-        let mut raw_data = String::new();
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         for ternary in self.data_set.iter() {
-            raw_data += &ternary.plotable_data();
-            raw_data += "\n";
+            ternary.write_data(writer)?;
+            writeln!(writer)?;
         }
-        raw_data
+        Ok(())
     }
 
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
         for (counter, ternary) in self.data_set.iter().enumerate() {
             let inner_id = format!("{}_{}", id, counter);
-            ternary.save_with_id(&inner_id)?;
+            let inner_path = self.configuration().data_path_with_id(&inner_id);
+            self.data_format().write(ternary, &inner_path)?;
         }
 
         Ok(self)
     }
+
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.configuration()
+            .comparison_data_paths(self.data_set.len())
+    }
 }
 
-impl<T> Plotable for Sequences<T>
+impl<T> Plotable for Ternaries<T>
 where
     T: Display + Clone,
 {
     fn plot_script(&self) -> String {
         let id = self.checked_id();
         let mut gnuplot_script = self.config.opening_plot_script_comparison();
+        gnuplot_script += &crate::ternary::simplex_plot_script(self.configuration());
 
         gnuplot_script += "plot ";
 
@@ -155,7 +160,7 @@ where
                 Some(leg) => String::from(leg),
                 None => counter.to_string(),
             };
-            let sequence_style = match style {
+            let ternary_style = match style {
                 crate::configuration::plot::style::Style::Default => ternary.style(),
                 _ => style,
             };
@@ -166,14 +171,10 @@ where
                     dashtype_counter
                 }
             };
-
-            ////////////////////////////////////////////
-            todo!("Create your own instruction to plot in ternary plane");
             gnuplot_script += &format!(
-                "{:?} using 1:2 with {} title \"{}\" dashtype {}, ",
-                inner_path, sequence_style, legend, dashtype
+                "{} using (simplex_x($1, $2, $3)):(simplex_y($1, $2, $3)) with {} title {} dashtype {}, ",
+                self.config.script_path(&inner_path), ternary_style, self.config.text_string(&legend), dashtype
             );
-            ////////////////////////////////////////////
 
             if counter < self.data_set.len() - 1 {
                 gnuplot_script += "\\\n";
@@ -185,3 +186,114 @@ where
         gnuplot_script
     }
 }
+
+impl<T> Matplotlib for Ternaries<T>
+where
+    T: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let id = self.checked_id();
+        let mut python_script = self.config.opening_matplotlib_script();
+        python_script += &crate::ternary::simplex_matplotlib_script(self.configuration());
+
+        let style = self.style();
+        let mut dashtype_counter = 0;
+
+        for (counter, ternary) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let legend = match ternary.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let ternary_style = match style {
+                crate::configuration::plot::style::Style::Default => ternary.style(),
+                _ => style,
+            };
+            let dashtype = match ternary.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            python_script += &format!(
+                "data = table({})\ndraw(ax, *simplex(data), \"{}\", {}, color=\"C{}\", label={})\n",
+                self.config.python_path(&inner_path),
+                ternary_style,
+                dashtype,
+                counter,
+                crate::matplotlib::python_string(&legend)
+            );
+        }
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T> VegaLite for Ternaries<T>
+where
+    T: Display + Clone,
+{
+    fn vega_lite_spec(&self) -> String {
+        let id = self.checked_id();
+        let style = self.style();
+        let mut dashtype_counter = 0;
+        let mut layers = Vec::new();
+
+        for (counter, ternary) in self.data_set.iter().enumerate() {
+            let inner_path = self.config.data_path_with_id(format!("{}_{}", id, counter));
+            let data = crate::vega::data(
+                self.configuration(),
+                ternary,
+                self.data_format(),
+                &inner_path,
+            );
+            let legend = match ternary.title() {
+                Some(leg) => String::from(leg),
+                None => counter.to_string(),
+            };
+            let ternary_style = match style {
+                crate::configuration::plot::style::Style::Default => ternary.style(),
+                _ => style,
+            };
+            let dashtype = match ternary.dashtype() {
+                Some(dashtype) => dashtype,
+                None => {
+                    dashtype_counter += 1;
+                    dashtype_counter
+                }
+            };
+            layers.push(crate::ternary::simplex_vega_lite_layer(
+                &data,
+                ternary_style,
+                dashtype,
+                Some(&legend),
+            ));
+        }
+
+        let mut simplex_layers = crate::ternary::simplex_vega_lite_layers(self.configuration());
+        simplex_layers.append(&mut layers);
+        crate::ternary::simplex_vega_lite_spec(self.configuration(), &simplex_layers)
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_script() {
+        let mut ternaries = crate::Ternary::new([[0.5, 0.5, 0.0]])
+            .set_title("first")
+            .to_owned()
+            + crate::Ternary::new([[0.0, 0.5, 0.5]]);
+        ternaries.set_id("ternaries_test");
+        let script = ternaries.plot_script();
+
+        assert!(script.contains("set size ratio -1\n"));
+        assert!(script.contains("ternaries_test_0.txt\" using (simplex_x($1, $2, $3)):(simplex_y($1, $2, $3)) with points title \"first\" noenhanced dashtype 1"));
+        assert!(script.contains("ternaries_test_1.txt\" using (simplex_x($1, $2, $3)):(simplex_y($1, $2, $3)) with points title \"1\" noenhanced dashtype 2"));
+    }
+}
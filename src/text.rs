@@ -0,0 +1,357 @@
+// Structs
+use crate::configuration::plot::style::Style;
+use crate::errors::PreexplorerError;
+use crate::figure::{bounds, step_points, y_bounds, Figure};
+
+// Traits
+use crate::traits::{Backend, Figurable};
+use std::io::Write;
+
+/// Size, in characters, of plots in the terminal if ``COLUMNS`` and ``LINES`` are not set.
+const DEFAULT_SIZE: (usize, usize) = (80, 24);
+/// Characters marking each series, in order.
+const MARKERS: [char; 8] = ['*', '+', 'o', 'x', '#', '@', '%', '&'];
+
+/// Quick glance at plots in the terminal, drawn with characters, without gnuplot.
+///
+/// Handy when working over ssh, where gnuplot can not open a window.
+/// Title, labels, ranges and logarithmic scales are taken from the configuration.
+/// Each series is drawn with its own character, listed in a legend if they have titles.
+///
+/// # Remarks
+///
+/// Only works for real numbers.
+///
+/// # Examples
+///
+/// Checking a sequence in a remote server.
+/// ```
+/// use preexplorer::prelude::*;
+/// (0..100).map(|i| i * i)
+///     .preexplore()
+///     .set_title("My computations")
+///     .plot_terminal()
+///     .unwrap();
+/// ```
+pub trait TextPlot: Figurable {
+    /// Draws the struct with characters, in ``width`` columns and ``height`` lines.
+    fn text_plot(&self, width: usize, height: usize) -> String {
+        text_plot(&self.figure(), width, height)
+    }
+
+    /// Prints the plot to the standard output, as large as the terminal.
+    ///
+    /// The size of the terminal is read from the environment variables ``COLUMNS`` and ``LINES``,
+    /// and is 80 by 24 otherwise.
+    fn plot_terminal(&self) -> Result<&Self, PreexplorerError> {
+        let text = self.plot_with(&Text::terminal())?;
+        let mut stdout = std::io::stdout();
+        stdout
+            .write_all(text.as_bytes())
+            .and_then(|_| stdout.flush())
+            .map_err(PreexplorerError::Plotting)?;
+        Ok(self)
+    }
+}
+
+impl<F: Figurable> TextPlot for F {}
+
+/// [Backend] drawing figures with characters, into a ``String``.
+///
+/// # Remarks
+///
+/// Grids are not drawn.
+///
+/// [Backend]: trait.Backend.html
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Text {
+    width: usize,
+    height: usize,
+}
+
+impl Text {
+    /// Text of ``width`` columns and ``height`` lines.
+    pub fn new(width: usize, height: usize) -> Self {
+        Text { width, height }
+    }
+
+    /// Text as large as the terminal, according to the environment variables
+    /// ``COLUMNS`` and ``LINES``, and 80 by 24 otherwise.
+    pub fn terminal() -> Self {
+        let size = |var: &str, default: usize| {
+            std::env::var(var)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default)
+        };
+        Text::new(
+            size("COLUMNS", DEFAULT_SIZE.0),
+            size("LINES", DEFAULT_SIZE.1),
+        )
+    }
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Text::new(DEFAULT_SIZE.0, DEFAULT_SIZE.1)
+    }
+}
+
+impl Backend for Text {
+    type Output = String;
+
+    fn draw(&self, figure: &Figure) -> Result<String, PreexplorerError> {
+        Ok(text_plot(figure, self.width, self.height))
+    }
+}
+
+/// Draws the series of a figure with characters.
+fn text_plot(figure: &Figure, width: usize, height: usize) -> String {
+    let series = figure.series();
+    let scale = |base: Option<f64>, v: f64| match base {
+        Some(base) => v.log(base),
+        None => v,
+    };
+    let unscale = |base: Option<f64>, v: f64| match base {
+        Some(base) => base.powf(v),
+        None => v,
+    };
+    let (logx, logy) = (figure.x.log_base(), figure.y.log_base());
+    let (x0, x1) = figure.x.range.unwrap_or_else(|| {
+        bounds(
+            series.iter().flat_map(|s| s.points.iter().map(|p| p.0)),
+            logx.is_some(),
+        )
+    });
+    let (y0, y1) = figure
+        .y
+        .range
+        .unwrap_or_else(|| y_bounds(&series, logy.is_some()));
+    let (x0, x1) = (scale(logx, x0), scale(logx, x1));
+    let (y0, y1) = (scale(logy, y0), scale(logy, y1));
+
+    // Layout
+    let top = number(unscale(logy, y1));
+    let bottom = number(unscale(logy, y0));
+    let margin = top.len().max(bottom.len());
+    let legend: Vec<String> = series
+        .iter()
+        .filter_map(|s| {
+            s.title
+                .as_ref()
+                .map(|title| format!("{} {}", MARKERS[s.color % MARKERS.len()], title))
+        })
+        .collect();
+    let extra_lines = 2
+        + figure.title.is_some() as usize
+        + figure.y.label.is_some() as usize
+        + figure.x.label.is_some() as usize
+        + !legend.is_empty() as usize;
+    let columns = width.saturating_sub(margin + 2).max(2);
+    let rows = height.saturating_sub(extra_lines).max(2);
+
+    // Canvas
+    let mut canvas = vec![vec![' '; columns]; rows];
+    let column = |x: f64| ((scale(logx, x) - x0) / (x1 - x0) * (columns - 1) as f64).round();
+    let row = |y: f64| ((y1 - scale(logy, y)) / (y1 - y0) * (rows - 1) as f64).round();
+    let base = row(unscale(logy, 0_f64.max(y0).min(y1)));
+    for s in series.iter() {
+        let marker = MARKERS[s.color % MARKERS.len()];
+        let mut mark = |c: f64, r: f64| {
+            if c >= 0. && r >= 0. && (c as usize) < columns && (r as usize) < rows {
+                canvas[r as usize][c as usize] = marker;
+            }
+        };
+        let cells = |points: &[(f64, f64)]| -> Vec<(f64, f64)> {
+            points
+                .iter()
+                .map(|&(x, y)| (column(x), row(y)))
+                .filter(|(c, r)| c.is_finite() && r.is_finite())
+                .collect()
+        };
+        match s.style {
+            Style::Points | Style::Dots => {
+                for (c, r) in cells(&s.points) {
+                    mark(c, r);
+                }
+            }
+            Style::Impulses => {
+                for (c, r) in cells(&s.points) {
+                    for r in segment((c, base), (c, r)) {
+                        mark(r.0, r.1);
+                    }
+                }
+            }
+            _ => {
+                let points = match s.style {
+                    // Only the top of boxes, since filling them hides the rest
+                    Style::Steps | Style::Fsteps | Style::Histeps | Style::Boxes => {
+                        step_points(&s.points, &s.style)
+                    }
+                    _ => s.points.clone(),
+                };
+                let cells = cells(&points);
+                if let [single] = cells[..] {
+                    mark(single.0, single.1);
+                }
+                for pair in cells.windows(2) {
+                    for (c, r) in segment(pair[0], pair[1]) {
+                        mark(c, r);
+                    }
+                }
+            }
+        }
+    }
+
+    // Text
+    let mut text = String::new();
+    if let Some(title) = &figure.title {
+        text += &format!("{:^width$}\n", title, width = margin + 2 + columns);
+    }
+    if let Some(label) = &figure.y.label {
+        text += &format!("{}\n", label);
+    }
+    for (i, line) in canvas.iter().enumerate() {
+        let tick = match i {
+            0 => top.as_str(),
+            i if i == rows - 1 => bottom.as_str(),
+            _ => "",
+        };
+        let line: String = line.iter().collect();
+        text += &format!("{:>margin$} |{}\n", tick, line.trim_end(), margin = margin);
+    }
+    text += &format!(
+        "{:>margin$} +{}\n",
+        "",
+        "-".repeat(columns),
+        margin = margin
+    );
+    let left = number(unscale(logx, x0));
+    let right = number(unscale(logx, x1));
+    let gap = columns.saturating_sub(left.len() + right.len()).max(1);
+    text += &format!(
+        "{:>margin$}  {}{}{}\n",
+        "",
+        left,
+        " ".repeat(gap),
+        right,
+        margin = margin
+    );
+    if let Some(label) = &figure.x.label {
+        text += &format!(
+            "{:>margin$}  {:^columns$}\n",
+            "",
+            label,
+            margin = margin,
+            columns = columns
+        );
+    }
+    if !legend.is_empty() {
+        text += &format!("{}\n", legend.join("  "));
+    }
+    text
+}
+
+/// Short representation of a tick value.
+fn number(value: f64) -> String {
+    if value != 0. && (value.abs() >= 1e5 || value.abs() < 1e-3) {
+        format!("{:.2e}", value)
+    } else {
+        let text = format!("{:.3}", value);
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        match text {
+            "-0" => String::from("0"),
+            _ => String::from(text),
+        }
+    }
+}
+
+/// Cells in the segment between two cells, both included.
+fn segment(from: (f64, f64), to: (f64, f64)) -> Vec<(f64, f64)> {
+    let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).max(1.) as usize;
+    (0..=steps)
+        .map(|i| {
+            let t = i as f64 / steps as f64;
+            (
+                (from.0 + t * (to.0 - from.0)).round(),
+                (from.1 + t * (to.1 - from.1)).round(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn sequence() {
+        let mut seq = pre::Sequence::new(vec![0, 1, 2, 3]);
+        seq.set_title("Line");
+        let text = seq.text_plot(12, 8);
+        assert_eq!(
+            text,
+            "    Line    \n\
+             3 |       **\n\
+             \u{20} |     **\n\
+             \u{20} |    *\n\
+             \u{20} |  **\n\
+             0 |**\n\
+             \u{20} +---------\n\
+             \u{20}  0       3\n"
+        );
+    }
+
+    #[test]
+    fn unsigned() {
+        let seq = pre::Sequence::new(vec![0usize, 1, 2, 3]);
+        let signed = pre::Sequence::new(vec![0, 1, 2, 3]);
+        assert_eq!(seq.text_plot(12, 8), signed.text_plot(12, 8));
+
+        let pros = pre::Process::new(vec![0u64, 1], vec![2usize, 3])
+            + pre::Process::new(vec![0u64, 1], vec![3usize, 2]);
+        assert!(pros.text_plot(20, 8).contains("*"));
+    }
+
+    #[test]
+    fn comparison() {
+        let mut first = pre::Process::new(vec![1., 10., 100.], vec![1., 1., 1.]);
+        first.set_title("first").set_style("points").unwrap();
+        let mut second = pre::Process::new(vec![1., 100.], vec![2., 2.]);
+        second.set_title("second");
+        let mut pros = first + second;
+        pros.set_logx(10).set_labelx("time").set_rangey(0., 3.);
+        let text = pros.text_plot(40, 12);
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 12);
+        assert!(lines.iter().all(|line| line.chars().count() <= 40));
+        assert_eq!(lines[0], "3 |");
+        assert!(lines.iter().any(|line| line.trim() == "time"));
+        assert_eq!(lines[lines.len() - 1], "* first  + second");
+        // Logarithmic scale puts 10 in the middle
+        let points = lines.iter().find(|line| line.contains('*')).unwrap();
+        let stars: Vec<usize> = points.match_indices('*').map(|(i, _)| i).collect();
+        assert_eq!(stars.len(), 3);
+        assert_eq!(stars[1] - stars[0], stars[2] - stars[1]);
+    }
+
+    #[test]
+    fn default_log_base() {
+        let mut pro = pre::Process::new(vec![1., 10., 100.], vec![1., 1., 1.]);
+        pro.set_logx(10);
+        let text = pro.text_plot(40, 12);
+        pro.set_logx(-1.);
+        assert_eq!(pro.text_plot(40, 12), text);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(number(0.), "0");
+        assert_eq!(number(2.5), "2.5");
+        assert_eq!(number(-3.), "-3");
+        assert_eq!(number(123456.), "1.23e5");
+        assert_eq!(number(0.0001), "1.00e-4");
+    }
+}
@@ -4,6 +4,7 @@
 //! 1. [Configurable]
 //! 2. [Saveable]
 //! 3. [Plotable]
+//! 4. [Figurable], optionally, to draw with any [Backend].
 //!
 //! ## Configurable
 //!
@@ -25,6 +26,7 @@
 //!
 //! Extract the data to plot from your struct in the form of a String.
 //! Different from serializing your struct, you only want the data.
+//! For large data sets, implement ``write_data`` instead, which streams the data into the file.
 //!
 //! ### Examples
 //!
@@ -105,16 +107,26 @@
 //! }
 //! ```
 //!
+//! ## Figurable
+//!
+//! Describe the plot of your struct with [Figure::new], which takes into account
+//! configuration options, and layers of data. Then, it can be drawn by any [Backend],
+//! for example in the terminal with [TextPlot].
+//!
 //! [Configurable]: trait.Configurable.html
 //! [Saveable]: trait.Saveable.html
 //! [Plotable]: trait.Plotable.html
+//! [Figurable]: trait.Figurable.html
+//! [Backend]: trait.Backend.html
+//! [TextPlot]: trait.TextPlot.html
+//! [Figure::new]: figure/struct.Figure.html#method.new
 //! [Configuration]: struct.Configuration.html
 
 // Types
 use crate::errors::PreexplorerError;
 use core::convert::TryInto;
 use std::ffi::OsStr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 // Traits
 use core::fmt::Display;
@@ -421,6 +433,31 @@ pub trait Configurable {
         self
     }
 
+    /// Choose the format of the data file. Too see all options, go to ``DataFormat`` enum.
+    /// The extension of the data file changes accordingly and plot scripts
+    /// read the data in this format.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``gnuplot``, whitespace separated columns.
+    /// ```
+    /// # use preexplorer::prelude::*;
+    /// let mut seq = (0..10).preexplore();
+    /// assert_eq!(seq.data_format(), pre::DataFormat::Gnuplot);
+    /// seq.set_data_format("csv").unwrap();
+    /// assert_eq!(seq.data_extension().unwrap().to_str(), Some("csv"));
+    /// ```
+    fn set_data_format<F>(
+        &mut self,
+        format: F,
+    ) -> Result<&mut Self, <F as TryInto<crate::configuration::save::format::DataFormat>>::Error>
+    where
+        F: TryInto<crate::configuration::save::format::DataFormat>,
+    {
+        self.configuration_mut().set_data_format(format)?;
+        Ok(self)
+    }
+
     /// Choose the style for the plot. Too see all options, go to ``Style`` struct.
     /// If you set a style and then compare with other structs, then
     /// in the joint plot, the style shall be mantained for those structs that had
@@ -432,7 +469,7 @@ pub trait Configurable {
     /// ```
     /// # use preexplorer::prelude::*;
     /// let seq = (0..10).preexplore();
-    /// assert_eq!(seq.style().to_string(), "Default".to_string());
+    /// assert_eq!(seq.style().to_string(), "lines".to_string());
     /// ```
     fn set_style<S>(
         &mut self,
@@ -467,6 +504,28 @@ pub trait Configurable {
         self
     }
 
+    /// Set the root directory in which both data and plot script will be saved,
+    /// under the folders ``data`` and ``plots`` respectively.
+    ///
+    /// # Default
+    ///
+    /// The root directory of the program, see [root_dir].
+    ///
+    /// # Examples
+    ///
+    /// Saving in a temporary directory.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// let mut seq = (0..10).preexplore();
+    /// seq.set_root_dir(std::env::temp_dir()).plot("my_id").unwrap();
+    /// ```
+    ///
+    /// [root_dir]: fn.root_dir.html
+    fn set_root_dir<P: AsRef<Path>>(&mut self, root: P) -> &mut Self {
+        self.configuration_mut().set_root_dir(root);
+        self
+    }
+
     /// Set the unique id or file name with which both data and plot script will be saved.
     /// There is no default value and one must set it before plotting or saving.
     ///
@@ -573,7 +632,6 @@ pub trait Configurable {
         self
     }
 
-
     /// Control tics in the z axis. Passing ``""`` shows no tics.
     /// See gnuplot documentation for a correct format.
     fn set_ticsz<T, S>(&mut self, ticsz: T) -> &mut Self
@@ -665,6 +723,140 @@ pub trait Configurable {
         self
     }
 
+    /// Choose the gnuplot terminal, possibly with options, e.g. ``"pngcairo size 800,600"``.
+    /// Passing ``None`` uses the default (interactive) terminal of gnuplot.
+    /// Run ``set terminal`` in gnuplot to list all available terminals.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``None``.
+    ///
+    /// # Examples
+    ///
+    /// Plotting into a svg file.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// let mut seq = (0..10).preexplore();
+    /// seq.set_terminal("svg").set_output("my_plot.svg");
+    /// assert_eq!(seq.terminal().unwrap().as_str(), "svg");
+    /// seq.plot("svg_plot").unwrap();
+    /// ```
+    fn set_terminal<T, S>(&mut self, terminal: T) -> &mut Self
+    where
+        T: Into<Option<S>>,
+        S: Display,
+    {
+        self.configuration_mut().set_terminal(terminal);
+        self
+    }
+
+    /// Decide whether plotting waits for gnuplot to finish.
+    /// If activated, errors of gnuplot (e.g. in the plot script) are reported
+    /// as a [PreexplorerError] instead of being lost.
+    ///
+    /// Note that gnuplot finishes only after the pause at the end of the plot
+    /// script, see ``set_pause``.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``.
+    ///
+    /// # Examples
+    ///
+    /// Checking that a plot script runs.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// (0..10).preexplore()
+    ///     .set_wait(true)
+    ///     .set_pause(0)
+    ///     .plot("checked_plot")
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [PreexplorerError]: errors/enum.PreexplorerError.html
+    fn set_wait(&mut self, wait: bool) -> &mut Self {
+        self.configuration_mut().set_wait(wait);
+        self
+    }
+
+    /// Choose the file where gnuplot writes the plot. It should be used together with
+    /// a file terminal, see ``set_terminal``. When an output is set, the plot script
+    /// does not pause.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``None``, which means plotting into a window.
+    fn set_output<T, P>(&mut self, output: T) -> &mut Self
+    where
+        T: Into<Option<P>>,
+        P: AsRef<Path>,
+    {
+        self.configuration_mut().set_output(output);
+        self
+    }
+
+    /// Choose a publication mode, with a LaTeX terminal of gnuplot, or leave it with ``None``.
+    ///
+    /// In publication mode, plot scripts draw into a ``.tex`` file next to them (unless
+    /// terminal or output are set), the title becomes the caption of the figure and labels
+    /// are typeset by LaTeX, math included. Writing the plot script also writes a LaTeX snippet
+    /// with the figure, ending in ``.figure.tex``, ready to be included in a document.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``None``.
+    ///
+    /// # Examples
+    ///
+    /// Drawing a figure for a paper.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// (1..100).map(|n| 1. / n as f64)
+    ///     .preexplore()
+    ///     .set_title("Error of the method")
+    ///     .set_labelx("$n$")
+    ///     .set_labely("$\\epsilon_n$")
+    ///     .set_publication(pre::Publication::Cairolatex)
+    ///     .plot_to_file("error")
+    ///     .unwrap();
+    /// // Then, \input{target/preexplorer/plots/error.figure.tex} in the document
+    /// ```
+    fn set_publication<T>(&mut self, publication: T) -> &mut Self
+    where
+        T: Into<Option<crate::configuration::plot::publication::Publication>>,
+    {
+        self.configuration_mut().set_publication(publication);
+        self
+    }
+
+    /// Choose whether plot scripts refer to data and output files by paths relative
+    /// to the plot script, so that the whole folder can be moved or archived.
+    ///
+    /// # Remarks
+    ///
+    /// Such plot scripts are run by gnuplot from their own directory,
+    /// as in ``cd target/preexplorer/plots && gnuplot my_plot.gnu``.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: paths are written as they are.
+    ///
+    /// # Examples
+    ///
+    /// Plotting into a run directory that is archived afterwards.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// (0..10).preexplore()
+    ///     .set_root_dir("runs/latest")
+    ///     .set_relative_paths(true)
+    ///     .plot("my_identifier")
+    ///     .unwrap();
+    /// ```
+    fn set_relative_paths(&mut self, relative_paths: bool) -> &mut Self {
+        self.configuration_mut().set_relative_paths(relative_paths);
+        self
+    }
+
     //////////////////////////////////////////////////////////
     // Getting
     fn title(&self) -> Option<&String> {
@@ -739,6 +931,9 @@ pub trait Configurable {
     fn header(&self) -> bool {
         self.configuration().header()
     }
+    fn data_format(&self) -> crate::configuration::save::format::DataFormat {
+        self.configuration().data_format()
+    }
     fn style(&self) -> &crate::configuration::plot::style::Style {
         self.configuration().style()
     }
@@ -778,6 +973,21 @@ pub trait Configurable {
     fn pause(&self) -> Option<f64> {
         self.configuration().pause()
     }
+    fn terminal(&self) -> Option<&String> {
+        self.configuration().terminal()
+    }
+    fn output(&self) -> Option<&Path> {
+        self.configuration().output()
+    }
+    fn wait(&self) -> bool {
+        self.configuration().wait()
+    }
+    fn publication(&self) -> Option<crate::configuration::plot::publication::Publication> {
+        self.configuration().publication()
+    }
+    fn relative_paths(&self) -> bool {
+        self.configuration().relative_paths()
+    }
 }
 
 /// Allows quick saving.
@@ -799,7 +1009,48 @@ pub trait Saveable: Configurable {
     /// If there is no data, a warning will be printed to [`io::stderr`].
     ///
     /// [`io::stderr`]: https://doc.rust-lang.org/std/io/struct.Stderr.html
-    fn plotable_data(&self) -> String;
+    ///
+    /// # Implementation
+    ///
+    /// At least one of ``plotable_data`` and [write_data] must be implemented,
+    /// since each one defaults to the other. Prefer [write_data] for large data sets.
+    ///
+    /// [write_data]: #method.write_data
+    fn plotable_data(&self) -> String {
+        let mut plotable_data = Vec::new();
+        self.write_data(&mut plotable_data)
+            .expect("Writing into memory should not fail.");
+        String::from_utf8_lossy(&plotable_data).into_owned()
+    }
+
+    /// Write the data from the struct, row by row, without building it in memory.
+    /// This is how data files are saved.
+    ///
+    /// # Remarks
+    ///
+    /// The data format is the same as the one of [plotable_data].
+    ///
+    /// # Examples
+    ///
+    /// Writing into standard output.
+    /// ```
+    /// # use preexplorer::prelude::*;
+    /// let seq = (0..3).preexplore();
+    /// seq.write_data(&mut std::io::stdout()).unwrap();
+    /// ```
+    ///
+    /// [plotable_data]: #method.plotable_data
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(self.plotable_data().as_bytes())
+    }
+
+    /// Names of the columns of [plotable_data], used as header row in CSV
+    /// and as keys in JSON lines. Missing names are completed with ``column_1``, ``column_2``, etc.
+    ///
+    /// [plotable_data]: #method.plotable_data
+    fn column_names(&self) -> Vec<String> {
+        Vec::new()
+    }
 
     /// Save the data in a file. The directory is ``target\\preexplorer\\data\\``.
     ///
@@ -823,7 +1074,9 @@ pub trait Saveable: Configurable {
     /// ```
     fn save(&self) -> Result<&Self, PreexplorerError> {
         let id = self.checked_id();
-        self.save_with_id(id)
+        self.save_with_id(id)?;
+        crate::run::record(self, id, &self.data_paths(), None);
+        Ok(self)
     }
 
     /// Save the file with a given ``id``.
@@ -839,32 +1092,43 @@ pub trait Saveable: Configurable {
     /// assert_eq!(seq.id(), None);
     /// ```
     fn save_with_id<S: Display>(&self, id: S) -> Result<&Self, PreexplorerError> {
-        let data_dir_path = self.data_path().parent().unwrap();
-        std::fs::create_dir_all(data_dir_path)?;
-
-        let mut path = self.data_path().to_path_buf();
-        path.set_file_name(id.to_string());
-        if let Some(extension) = self.data_extension() {
-            path.set_extension(extension);
-        };
-
-        let mut data_gnuplot = String::new();
-        if self.header() {
-            if let Some(title) = self.title() {
-                data_gnuplot.push_str(&format!("# {}\n", title));
-            }
-            if let Some(id) = self.id() {
-                data_gnuplot.push_str(&format!("# {}\n", id));
-            }
-            data_gnuplot.push_str(&format!("# {}\n", self.date()));
-        }
-
-        data_gnuplot += &self.plotable_data();
-
-        std::fs::write(path, data_gnuplot)?;
+        let id = id.to_string();
+        let path = self.configuration().data_path_with_id(&id);
+        self.save_with_path(path)?;
+        Ok(self)
+    }
 
+    /// Save the file in a given path, creating its directory if needed.
+    /// It does not change the current id or data path.
+    ///
+    /// # Remarks
+    ///
+    /// Comparison structs use this method to save the data of each of their elements
+    /// in their own data directory.
+    ///
+    /// # Examples
+    ///
+    /// Saving in a temporary directory.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// let seq = (0..10).preexplore();
+    /// seq.save_with_path(std::env::temp_dir().join("quick_test.txt")).unwrap();
+    /// ```
+    fn save_with_path<P: AsRef<Path>>(&self, path: P) -> Result<&Self, PreexplorerError> {
+        self.data_format().write(self, path.as_ref())?;
         Ok(self)
     }
+
+    /// Paths of the data files written by [save], and nothing else.
+    ///
+    /// # Implementation
+    ///
+    /// Structs that save more than one file, like comparisons, must list all of them.
+    ///
+    /// [save]: #method.save
+    fn data_paths(&self) -> Vec<PathBuf> {
+        self.data_format().paths(self.data_path())
+    }
 }
 
 /// Allows quick plotting.
@@ -918,6 +1182,39 @@ pub trait Plotable: Configurable + Saveable {
         Ok(self)
     }
 
+    /// Saves the data and writes an archive with everything needed to plot it elsewhere:
+    /// the data files, the plot script and a README.
+    ///
+    /// # Remarks
+    ///
+    /// The archive is a zip file if ``path`` ends in ``.zip``, and an uncompressed
+    /// tar file otherwise. Its content is a folder named after the id, with the plot
+    /// script reading the data from the ``data`` folder next to it, so that
+    /// ``gnuplot -p <id>.gnu`` works from that folder wherever the archive is extracted.
+    /// In publication mode, it also has the LaTeX snippet of the figure.
+    ///
+    /// # Panics
+    ///
+    /// If there is no id, as when saving.
+    ///
+    /// # Examples
+    ///
+    /// Sending a plot to a collaborator.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// (0..10).preexplore()
+    ///     .set_id("my_identifier")
+    ///     .export_bundle("my_identifier.zip")
+    ///     .unwrap();
+    /// ```
+    fn export_bundle<P: AsRef<Path>>(&mut self, path: P) -> Result<&mut Self, PreexplorerError> {
+        let id = self.checked_id().clone();
+        self.save()?;
+        crate::bundle::export(self, &id, path.as_ref())?;
+
+        Ok(self)
+    }
+
     /// Main command.
     /// In other words:
     /// 1. Assign id.
@@ -925,6 +1222,11 @@ pub trait Plotable: Configurable + Saveable {
     /// 3. Save the plot script.
     /// 4. Run (asynchronous) the plot script.  
     ///
+    /// # Errors
+    ///
+    /// If gnuplot is not installed, [PreexplorerError::GnuplotNotFound] is returned.
+    /// Other errors from gnuplot are only reported if the configuration says so, see ``set_wait``.
+    ///
     /// # Examples
     ///
     /// Quickest plot.
@@ -934,6 +1236,8 @@ pub trait Plotable: Configurable + Saveable {
     ///     .plot("my_identifier")
     ///     .unwrap();
     /// ```
+    ///
+    /// [PreexplorerError::GnuplotNotFound]: errors/enum.PreexplorerError.html#variant.GnuplotNotFound
     fn plot<S: Display>(&mut self, id: S) -> Result<&mut Self, PreexplorerError> {
         let id = id.to_string();
         self.set_id(id.clone());
@@ -978,13 +1282,61 @@ pub trait Plotable: Configurable + Saveable {
         self.save()?;
         self.write_plot_script(script)?;
 
-        let gnuplot_file = self.plot_path();
-        std::process::Command::new("gnuplot")
-            .arg(gnuplot_file)
-            .spawn()?;
+        crate::functions::run_gnuplot(self.plot_path(), self.wait(), self.relative_paths())?;
         Ok(self)
     }
 
+    /// Plot into a file, without any window, and return the path of the file.
+    /// In other words:
+    /// 1. Assign id.
+    /// 2. Save the data.
+    /// 3. Save the plot script.
+    /// 4. Run the plot script, waiting for gnuplot to finish.
+    ///
+    /// # Remarks
+    ///
+    /// If no terminal was set, ``pngcairo`` is used. If no output was set, the file is
+    /// located next to the plot script, with an extension given by the terminal.
+    /// Both are only used for this plot: the configured terminal and output are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// Quickest image.
+    /// ```no_run
+    /// # use preexplorer::prelude::*;
+    /// let path = (0..10).preexplore()
+    ///     .plot_to_file("my_identifier")
+    ///     .unwrap();
+    /// assert_eq!(path.extension().unwrap(), "png");
+    /// ```
+    fn plot_to_file<S: Display>(&mut self, id: S) -> Result<PathBuf, PreexplorerError> {
+        self.set_id(id);
+        let previous_terminal = self.terminal().cloned();
+        let previous_output = self.output().map(Path::to_path_buf);
+        if previous_terminal.is_none() && self.publication().is_none() {
+            self.set_terminal(crate::configuration::plot::DEFAULT_TERMINAL);
+        }
+        let output = match self.output() {
+            Some(output) => output.to_path_buf(),
+            None => self.configuration().default_output(),
+        };
+        self.set_output(&output);
+
+        // Terminal and output are only needed to write the script
+        let written = match output.parent() {
+            Some(parent) => std::fs::create_dir_all(parent).map_err(PreexplorerError::from),
+            None => Ok(()),
+        }
+        .and_then(|_| self.save().map(|_| ()))
+        .and_then(|_| self.write_plot_script(self.plot_script()).map(|_| ()));
+        self.set_terminal::<_, String>(previous_terminal);
+        self.set_output::<_, PathBuf>(previous_output);
+        written?;
+
+        crate::functions::run_gnuplot(self.plot_path(), true, self.relative_paths())?;
+        Ok(output)
+    }
+
     /// Write plot script given by ``plot_script`` in the machine for posterior running.
     ///
     /// # Remarks
@@ -997,6 +1349,20 @@ pub trait Plotable: Configurable + Saveable {
         let gnuplot_script = gnuplot_script.to_string();
 
         std::fs::write(gnuplot_file, gnuplot_script)?;
+        if let (Some(publication), Some(id)) = (self.publication(), self.id()) {
+            let output = match self.output() {
+                Some(output) => output.to_path_buf(),
+                None => self.configuration().default_output(),
+            };
+            let title = self.title().map(|s| s.as_str());
+            std::fs::write(
+                gnuplot_file.with_extension("figure.tex"),
+                publication.figure_snippet(&output, title, id),
+            )?;
+        }
+        if let Some(id) = self.id() {
+            crate::run::record(self, id, &[], Some(gnuplot_file));
+        }
         Ok(self)
     }
 
@@ -1010,3 +1376,260 @@ pub trait Plotable: Configurable + Saveable {
         self.configuration().ending_plot_script()
     }
 }
+
+/// Saves and plots data as raw little-endian ``f64`` records,
+/// which is faster and lighter than text for large data sets.
+///
+/// # Remarks
+///
+/// It is only implemented for numeric types, i.e. all primitive numbers, through ``num_traits::ToPrimitive``.
+/// Integers too large for ``f64`` lose precision.
+/// The binary data file is next to the text one, with extension ``bin``.
+///
+/// # Examples
+///
+/// Plotting a million points.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let times: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 1e-6).collect();
+/// let values: Vec<f64> = times.iter().map(|t| t.sin()).collect();
+/// pre::Process::new(times, values)
+///     .plot_binary("my_identifier")
+///     .unwrap();
+/// ```
+pub trait Binary: Plotable {
+    /// Writes all records, each one with the same number of ``f64`` values.
+    fn write_binary<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()>;
+
+    /// Plot script reading the binary data file.
+    fn binary_plot_script(&self) -> String;
+
+    /// Path of the binary data file.
+    fn binary_data_path(&self) -> PathBuf {
+        self.data_path().with_extension("bin")
+    }
+
+    /// Save the data as a binary file.
+    ///
+    /// # Panics
+    ///
+    /// If the struct has not been given an ``id``, according to the ``Configurable`` trait.
+    fn save_binary(&self) -> Result<&Self, PreexplorerError> {
+        let id = self.checked_id();
+        let path = self.binary_data_path();
+        if let Some(data_dir_path) = path.parent() {
+            std::fs::create_dir_all(data_dir_path)?;
+        }
+
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(&path)?);
+        self.write_binary(&mut writer)?;
+        std::io::Write::flush(&mut writer)?;
+
+        crate::run::record(self, id, &[path], None);
+        Ok(self)
+    }
+
+    /// Plot from the binary data file. See [Plotable::plot] for more.
+    ///
+    /// [Plotable::plot]: trait.Plotable.html#method.plot
+    fn plot_binary<S: Display>(&mut self, id: S) -> Result<&mut Self, PreexplorerError> {
+        self.set_id(id);
+        self.save_binary()?;
+        self.write_plot_script(self.binary_plot_script())?;
+
+        crate::functions::run_gnuplot(self.plot_path(), self.wait(), self.relative_paths())?;
+        Ok(self)
+    }
+}
+
+/// Leaves a standalone Python script, plotting the saved data with matplotlib,
+/// as an alternative to gnuplot scripts for fine-tuning.
+///
+/// # Remarks
+///
+/// The script is located next to the plot script, with extension ``py``, and reads
+/// the data files, so they must be saved too. It reproduces title, labels, ranges,
+/// logarithmic scales, styles, legends and multiplot layouts of the configuration.
+/// It needs matplotlib and numpy.
+///
+/// # Examples
+///
+/// Fine-tuning a plot in Python.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let mut seq = (0..10).preexplore();
+/// seq.set_title("My title")
+///     .matplotlib_later("my_identifier")
+///     .unwrap();
+/// // Then run: python3 target/preexplorer/plots/my_identifier.py
+/// ```
+pub trait Matplotlib: Plotable {
+    /// Construct a suitable matplotlib script for the struct.
+    fn matplotlib_script(&self) -> String;
+
+    /// Path of the matplotlib script.
+    fn matplotlib_path(&self) -> PathBuf {
+        self.plot_path().with_extension("py")
+    }
+
+    /// Leave the data and a matplotlib script for later.
+    /// In other words:
+    /// 1. Assign id.
+    /// 2. Save the data.
+    /// 3. Save the matplotlib script.
+    fn matplotlib_later<S: Display>(&mut self, id: S) -> Result<&mut Self, PreexplorerError> {
+        self.set_id(id);
+        self.save()?;
+        self.write_matplotlib_script(self.matplotlib_script())?;
+
+        Ok(self)
+    }
+
+    /// Write matplotlib script given by ``matplotlib_script`` in the machine for posterior running.
+    fn write_matplotlib_script<S: Display>(
+        &self,
+        python_script: S,
+    ) -> Result<&Self, PreexplorerError> {
+        let path = self.matplotlib_path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, python_script.to_string())?;
+        Ok(self)
+    }
+
+    /// Helper method for implementing ``Matplotlib``.
+    fn opening_matplotlib_script(&self) -> String {
+        self.configuration().opening_matplotlib_script()
+    }
+
+    /// Helper method for implementing ``Matplotlib``.
+    fn ending_matplotlib_script(&self) -> String {
+        self.configuration().ending_matplotlib_script()
+    }
+}
+
+/// Leaves a [Vega-Lite](https://vega.github.io/vega-lite/) specification of the plot,
+/// in JSON, that any browser can render, for example in a static HTML page with
+/// [vega-embed](https://github.com/vega/vega-embed).
+///
+/// # Remarks
+///
+/// The data is inlined in the specification, unless the data format is ``json``
+/// (see ``set_data_format``): then, it references the JSON file saved next to each data file,
+/// by a path relative to the specification if so configured (see ``set_relative_paths``).
+///
+/// Styles are mapped to marks, logarithmic scales to scale types and the structs
+/// compared are drawn in layers, with colors according to their legend.
+///
+/// # Examples
+///
+/// Sharing a plot in a web dashboard.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let mut seq = (0..10).preexplore();
+/// seq.set_title("My title")
+///     .vega_lite_later("my_identifier")
+///     .unwrap();
+/// // Then embed target/preexplorer/plots/my_identifier.vl.json
+/// ```
+///
+/// Inspecting the specification.
+/// ```
+/// use preexplorer::prelude::*;
+/// let spec = pre::Sequence::new(vec![0, 1]).vega_lite_spec();
+/// assert!(spec.contains("\"values\": [\n{\"index\":0,\"value\":0,\"block\":0},\n{\"index\":1,\"value\":1,\"block\":0}\n]"));
+/// ```
+pub trait VegaLite: Plotable {
+    /// Construct a suitable Vega-Lite specification for the struct.
+    fn vega_lite_spec(&self) -> String;
+
+    /// Path of the Vega-Lite specification, next to the plot script with extension ``vl.json``.
+    fn vega_lite_path(&self) -> PathBuf {
+        self.plot_path().with_extension("vl.json")
+    }
+
+    /// Leave the data and a Vega-Lite specification for later.
+    /// In other words:
+    /// 1. Assign id.
+    /// 2. Save the data.
+    /// 3. Save the Vega-Lite specification.
+    fn vega_lite_later<S: Display>(&mut self, id: S) -> Result<&mut Self, PreexplorerError> {
+        self.set_id(id);
+        self.save()?;
+        self.write_vega_lite_spec(self.vega_lite_spec())?;
+
+        Ok(self)
+    }
+
+    /// Write Vega-Lite specification given by ``vega_lite_spec`` in the machine.
+    fn write_vega_lite_spec<S: Display>(&self, vega_spec: S) -> Result<&Self, PreexplorerError> {
+        let path = self.vega_lite_path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, vega_spec.to_string())?;
+        Ok(self)
+    }
+}
+
+/// Describes the struct as a backend-neutral [Figure], that any [Backend] can draw.
+///
+/// Implementing it is enough to draw a struct in the terminal, with [TextPlot],
+/// or into images, with [Render] under the **`plotters`** feature.
+///
+/// # Remarks
+///
+/// It is implemented for real numbers.
+///
+/// # Examples
+///
+/// Inspecting the figure of a sequence.
+/// ```
+/// use preexplorer::prelude::*;
+/// let mut seq = pre::Sequence::new(vec![0., 1., 4.]);
+/// seq.set_title("Squares");
+/// let figure = seq.figure();
+/// assert_eq!(figure.title, Some(String::from("Squares")));
+/// assert_eq!(figure.series()[0].points, vec![(0., 0.), (1., 1.), (2., 4.)]);
+/// ```
+///
+/// [Figure]: figure/struct.Figure.html
+/// [Backend]: trait.Backend.html
+/// [TextPlot]: trait.TextPlot.html
+/// [Render]: trait.Render.html
+pub trait Figurable: Configurable {
+    /// Title, axes and layers of the plot of the struct.
+    fn figure(&self) -> crate::figure::Figure;
+
+    /// Draws the figure of the struct with a backend.
+    fn plot_with<B: Backend>(&self, backend: &B) -> Result<B::Output, PreexplorerError> {
+        backend.draw(&self.figure())
+    }
+}
+
+/// Draws backend-neutral figures: as gnuplot scripts, text, images or anything else.
+///
+/// # Examples
+///
+/// A backend counting the points of a figure.
+/// ```
+/// use preexplorer::prelude::*;
+/// use preexplorer::figure::Figure;
+/// struct Counter;
+/// impl pre::Backend for Counter {
+///     type Output = usize;
+///     fn draw(&self, figure: &Figure) -> Result<usize, pre::errors::PreexplorerError> {
+///         Ok(figure.series().iter().map(|s| s.points.len()).sum())
+///     }
+/// }
+/// let seqs = pre::Sequence::new(vec![1, 2]) + pre::Sequence::new(vec![3]);
+/// assert_eq!(seqs.plot_with(&Counter).unwrap(), 3);
+/// ```
+pub trait Backend {
+    /// What drawing produces: a script, a text, the path of an image...
+    type Output;
+
+    /// Draws a figure.
+    fn draw(&self, figure: &crate::figure::Figure) -> Result<Self::Output, PreexplorerError>;
+}
@@ -0,0 +1,484 @@
+// Traits
+pub use crate::traits::{Configurable, Matplotlib, Plotable, Saveable, VegaLite};
+use core::fmt::Display;
+
+/// Vectors in a grid, drawn as arrows: direction fields, gradients or flows.
+///
+/// Check out [vectors demo] of gnuplot for more options.
+///
+/// # Remarks
+///
+/// The saved data has the components and the magnitude of the vectors as given,
+/// normalization and scaling are done when plotting.
+///
+/// # Examples
+///
+/// Quick plot.
+/// ```no_run
+/// use itertools::iproduct;
+/// use preexplorer::prelude::*;
+/// let dxs = iproduct!(0..10, 0..5).map(|(_, y)| -y);
+/// let dys = iproduct!(0..10, 0..5).map(|(x, _)| x);
+/// pre::VectorField::new(0..10, 0..5, dxs, dys).plot("my_identifier").unwrap();
+/// ```
+///
+/// Direction field of an ODE, with arrows of the same length colored by the magnitude.
+/// ```no_run
+/// use preexplorer::prelude::*;
+/// let grid: Vec<f64> = (-10..=10).map(|i| i as f64 / 10.).collect();
+/// pre::VectorField::from_fn(grid.clone(), grid, |x, y| (1., x * y))
+///     .set_normalize(true)
+///     .set_scale(0.08)
+///     .set_magnitude_colors(true)
+///     .plot("my_identifier")
+///     .unwrap();
+/// ```
+///
+/// [vectors demo]: http://gnuplot.sourceforge.net/demo/vector.html
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "use-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    xs: Vec<T>,
+    ys: Vec<S>,
+    dxs: Vec<f64>,
+    dys: Vec<f64>,
+    normalize: bool,
+    scale: Option<f64>,
+    magnitude_colors: bool,
+    config: crate::configuration::Configuration,
+}
+
+impl<T, S> VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    /// Constructs a new ``VectorField<T, S>``, with the components of the vector in each
+    /// point of the grid given by the cartesian product of ``xs`` and ``ys``.
+    ///
+    /// # Panics
+    ///
+    /// The number of each component must be equal to the dimension of the grid.
+    ///
+    /// # Examples
+    ///
+    /// From a complicated computation.
+    /// ```
+    /// # use itertools::iproduct;
+    /// use preexplorer::prelude::*;
+    /// let dxs = iproduct!(0..10, 0..5).map(|(x, y)| x + y);
+    /// let dys = iproduct!(0..10, 0..5).map(|(x, y)| x * y);
+    /// let field = pre::VectorField::new(0..10, 0..5, dxs, dys);
+    /// ```
+    pub fn new<I, J, K, L, U>(xs: I, ys: J, dxs: K, dys: L) -> VectorField<T, S>
+    where
+        I: IntoIterator<Item = T>,
+        J: IntoIterator<Item = S>,
+        K: IntoIterator<Item = U>,
+        L: IntoIterator<Item = U>,
+        U: Into<f64>,
+    {
+        let xs: Vec<T> = xs.into_iter().collect();
+        let ys: Vec<S> = ys.into_iter().collect();
+        let dxs: Vec<f64> = dxs.into_iter().map(|dx| dx.into()).collect();
+        let dys: Vec<f64> = dys.into_iter().map(|dy| dy.into()).collect();
+
+        assert!(
+            xs.len() * ys.len() == dxs.len() && dxs.len() == dys.len(),
+            "The numbers of components ({} and {}) do not match the grid ({}x{})",
+            dxs.len(),
+            dys.len(),
+            xs.len(),
+            ys.len()
+        );
+
+        let config = crate::configuration::Configuration::default();
+
+        VectorField {
+            xs,
+            ys,
+            dxs,
+            dys,
+            normalize: false,
+            scale: None,
+            magnitude_colors: false,
+            config,
+        }
+    }
+
+    /// Constructs a new ``VectorField<T, S>`` evaluating ``f`` in each point of the grid
+    /// given by the cartesian product of ``xs`` and ``ys``.
+    ///
+    /// # Examples
+    ///
+    /// Gradient of a function.
+    /// ```
+    /// use preexplorer::prelude::*;
+    /// let grid: Vec<f64> = (-5..=5).map(|i| i as f64).collect();
+    /// let field = pre::VectorField::from_fn(grid.clone(), grid, |x, y| (2. * x, 2. * y));
+    /// ```
+    pub fn from_fn<I, J, F, U>(xs: I, ys: J, mut f: F) -> VectorField<T, S>
+    where
+        I: IntoIterator<Item = T>,
+        J: IntoIterator<Item = S>,
+        F: FnMut(T, S) -> (U, U),
+        U: Into<f64>,
+    {
+        let xs: Vec<T> = xs.into_iter().collect();
+        let ys: Vec<S> = ys.into_iter().collect();
+        let mut dxs = Vec::with_capacity(xs.len() * ys.len());
+        let mut dys = Vec::with_capacity(xs.len() * ys.len());
+        for x in xs.iter() {
+            for y in ys.iter() {
+                let (dx, dy) = f(x.clone(), y.clone());
+                dxs.push(dx.into());
+                dys.push(dy.into());
+            }
+        }
+
+        VectorField::new(xs, ys, dxs, dys)
+    }
+
+    /// Choose whether all arrows are drawn with the same length, showing only the direction
+    /// of the vectors. Vectors equal to zero are drawn as points.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``: arrows are as long as the vectors.
+    pub fn set_normalize(&mut self, normalize: bool) -> &mut Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Sets the factor by which the length of the arrows is multiplied,
+    /// after normalizing them, if asked.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``1``.
+    pub fn set_scale<N: Into<f64>>(&mut self, scale: N) -> &mut Self {
+        self.scale = Some(scale.into());
+        self
+    }
+
+    /// Choose whether arrows are colored by the magnitude of the vectors,
+    /// with the range, logarithmic scale and label of the z axis.
+    ///
+    /// # Default
+    ///
+    /// The default value is ``false``.
+    pub fn set_magnitude_colors(&mut self, magnitude_colors: bool) -> &mut Self {
+        self.magnitude_colors = magnitude_colors;
+        self
+    }
+
+    /// Gnuplot expressions of the arrows, from the components and magnitude saved
+    /// in the third to fifth columns.
+    fn arrow_columns(&self) -> (String, String) {
+        let scale = match self.scale {
+            Some(scale) => format!("{}*", scale),
+            None => String::new(),
+        };
+        if self.normalize {
+            (
+                format!("($5 == 0 ? 0 : {}$3/$5)", scale),
+                format!("($5 == 0 ? 0 : {}$4/$5)", scale),
+            )
+        } else if self.scale.is_some() {
+            (format!("({}$3)", scale), format!("({}$4)", scale))
+        } else {
+            (String::from("3"), String::from("4"))
+        }
+    }
+}
+
+impl<T, S> Configurable for VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn configuration_mut(&mut self) -> &mut crate::configuration::Configuration {
+        &mut self.config
+    }
+    fn configuration(&self) -> &crate::configuration::Configuration {
+        &self.config
+    }
+}
+
+impl<T, S> Saveable for VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn write_data<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Initial warning
+        if self.xs.is_empty() {
+            eprintln!("Warning: There is no data.");
+        }
+
+        for i in 0..self.xs.len() {
+            for j in 0..self.ys.len() {
+                let (dx, dy) = (
+                    self.dxs[i * self.ys.len() + j],
+                    self.dys[i * self.ys.len() + j],
+                );
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}\t{}\t{}",
+                    self.xs[i],
+                    self.ys[j],
+                    dx,
+                    dy,
+                    dx.hypot(dy)
+                )?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ["x", "y", "dx", "dy", "magnitude"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+}
+
+impl<T, S> Plotable for VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn plot_script(&self) -> String {
+        let mut gnuplot_script = self.opening_plot_script();
+
+        let (dx, dy) = self.arrow_columns();
+        let (color_column, color) = if self.magnitude_colors {
+            gnuplot_script += &self.config.colorbar_plot_script();
+            (":5", " linecolor palette")
+        } else {
+            ("", "")
+        };
+        gnuplot_script += &format!(
+            "plot {} using 1:2:{}:{}{} with vectors head filled{} dashtype {}\n",
+            self.config.script_path(self.data_path()),
+            dx,
+            dy,
+            color_column,
+            color,
+            self.dashtype().unwrap_or(1)
+        );
+        gnuplot_script += &self.ending_plot_script();
+
+        gnuplot_script
+    }
+}
+
+impl<T, S> Matplotlib for VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    fn matplotlib_script(&self) -> String {
+        let mut python_script = self.opening_matplotlib_script();
+
+        let mut arguments = String::new();
+        if self.normalize {
+            arguments += ", normalize=True";
+        }
+        if let Some(scale) = self.scale {
+            arguments += &format!(", scale={}", scale);
+        }
+        if self.magnitude_colors {
+            arguments += ", magnitude=True";
+            arguments += &crate::matplotlib::color_arguments(&self.config);
+        }
+        python_script += &format!(
+            "quiver(fig, ax, table({}){})\n",
+            self.config.python_path(self.data_path()),
+            arguments
+        );
+        python_script += &self.ending_matplotlib_script();
+
+        python_script
+    }
+}
+
+impl<T, S> VegaLite for VectorField<T, S>
+where
+    T: Display + Clone,
+    S: Display + Clone,
+{
+    /// Arrows as rules from each point to the tip of its vector, with a triangle on the tip.
+    fn vega_lite_spec(&self) -> String {
+        let data = crate::vega::data(
+            self.configuration(),
+            self,
+            self.data_format(),
+            self.data_path(),
+        );
+
+        let scale = self.scale.unwrap_or(1.);
+        let length = if self.normalize {
+            format!("(datum.magnitude == 0 ? 0 : {} / datum.magnitude)", scale)
+        } else {
+            scale.to_string()
+        };
+        let transform = format!(
+            "\"transform\": [\
+                {{\"calculate\": \"datum.x + {length} * datum.dx\", \"as\": \"tip_x\"}}, \
+                {{\"calculate\": \"datum.y + {length} * datum.dy\", \"as\": \"tip_y\"}}, \
+                {{\"calculate\": \"(atan2(datum.dx, datum.dy) * 180 / PI + 360) % 360\", \"as\": \"angle\"}}]",
+            length = length
+        );
+        let color = if self.magnitude_colors {
+            format!(", {}", crate::vega::z(&self.config, "magnitude"))
+        } else {
+            String::new()
+        };
+        let view = format!(
+            "{},\n\"data\": {},\n{},\n\"layer\": [\n\
+            {{\"mark\": \"rule\", \"encoding\": {{{}, {}, \
+                \"x2\": {{\"field\": \"tip_x\"}}, \"y2\": {{\"field\": \"tip_y\"}}{}}}}},\n\
+            {{\"mark\": {{\"type\": \"point\", \"shape\": \"triangle-up\", \"filled\": true, \"size\": 30}}, \
+                \"encoding\": {{{}, {}, \"angle\": {{\"field\": \"angle\", \"type\": \"quantitative\", \
+                \"scale\": {{\"domain\": [0, 360], \"range\": [0, 360]}}}}{}}}}}\n]",
+            crate::vega::size(),
+            data,
+            transform,
+            crate::vega::x(&self.config, "x"),
+            crate::vega::y(&self.config, "y"),
+            color,
+            crate::vega::x(&self.config, "tip_x"),
+            crate::vega::y(&self.config, "tip_y"),
+            color,
+        );
+
+        crate::vega::spec(&self.config, &view)
+    }
+}
+
+impl<U> From<(ndarray::Array2<U>, ndarray::Array2<U>)> for VectorField<usize, usize>
+where
+    U: Into<f64> + Clone,
+{
+    /// Vector field from its two components, with the same orientation as [Heatmap].
+    ///
+    /// # Panics
+    ///
+    /// The shapes of both components must be equal.
+    ///
+    /// [Heatmap]: struct.Heatmap.html
+    fn from((dxs, dys): (ndarray::Array2<U>, ndarray::Array2<U>)) -> Self {
+        assert!(
+            dxs.shape() == dys.shape(),
+            "The shapes of the components ({:?} and {:?}) do not match",
+            dxs.shape(),
+            dys.shape()
+        );
+        let shape = dxs.shape();
+
+        let xs: Vec<usize> = (0..shape[0]).collect();
+        let ys: Vec<usize> = (0..shape[1]).rev().collect();
+        let dxs: Vec<U> = dxs.t().iter().cloned().collect();
+        let dys: Vec<U> = dys.t().iter().cloned().collect();
+
+        VectorField::new(xs, ys, dxs, dys)
+    }
+}
+
+///////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plotable_data() {
+        let field = VectorField::from_fn(0..2, 0..2, |x, y| (x * 3, y * 4));
+        assert_eq!(
+            field.plotable_data(),
+            "0\t0\t0\t0\t0\n0\t1\t0\t4\t4\n\n1\t0\t3\t0\t3\n1\t1\t3\t4\t5\n\n"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new() {
+        VectorField::new(0..2, 0..2, vec![1, 0, 0, 1], vec![0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_mismatched_arrays() {
+        let dxs = ndarray::Array2::<u8>::zeros((2, 2));
+        let dys = ndarray::Array2::zeros((2, 3));
+        let _ = VectorField::from((dxs, dys));
+    }
+
+    #[test]
+    fn plot_script() {
+        let mut field = VectorField::new(0..2, 0..2, vec![1, 0, 0, 1], vec![0, 1, 1, 0]);
+        field.set_id("vector_field_test");
+        let script = field.plot_script();
+        assert!(script.contains(
+            "vector_field_test.txt\" using 1:2:3:4 with vectors head filled dashtype 1\n"
+        ));
+
+        field
+            .set_normalize(true)
+            .set_scale(0.5)
+            .set_magnitude_colors(true)
+            .set_labelz("speed")
+            .set_logz(10)
+            .set_rangez(1, 100);
+        let script = field.plot_script();
+        assert!(script.contains(
+            "set cblabel \"speed\" noenhanced\nset logscale cb 10\nset cbrange [1:100]\n"
+        ));
+        assert!(script.contains(
+            "using 1:2:($5 == 0 ? 0 : 0.5*$3/$5):($5 == 0 ? 0 : 0.5*$4/$5):5 with vectors head filled linecolor palette"
+        ));
+    }
+
+    #[test]
+    fn matplotlib_script() {
+        let mut field = VectorField::new(0..2, 0..2, vec![1, 0, 0, 1], vec![0, 1, 1, 0]);
+        field
+            .set_normalize(true)
+            .set_magnitude_colors(true)
+            .set_labelz("speed")
+            .set_id("vector_field_matplotlib_test");
+        let script = field.matplotlib_script();
+
+        assert!(script.contains(
+            "vector_field_matplotlib_test.txt\"), normalize=True, magnitude=True, label=\"speed\")\n"
+        ));
+    }
+
+    #[test]
+    fn vega_lite_spec() {
+        let mut field = VectorField::new(0..2, 0..2, vec![1, 0, 0, 1], vec![0, 1, 1, 0]);
+        field.set_id("vector_field_vega_lite_test");
+        let spec = field.vega_lite_spec();
+
+        assert!(spec.contains("{\"x\":1,\"y\":1,\"dx\":1,\"dy\":0,\"magnitude\":1,\"block\":1}"));
+        assert!(spec.contains("\"calculate\": \"datum.x + 1 * datum.dx\""));
+        assert!(spec.contains("\"mark\": \"rule\""));
+    }
+
+    #[test]
+    fn from_arrays() {
+        let dxs = ndarray::arr2(&[[1., 2.], [3., 4.]]);
+        let dys = ndarray::arr2(&[[0., 0.], [0., 1.]]);
+        let field = VectorField::from((dxs, dys));
+        assert_eq!(field.xs, vec![0, 1]);
+        assert_eq!(field.ys, vec![1, 0]);
+        assert_eq!(field.dxs, vec![1., 3., 2., 4.]);
+        assert_eq!(field.dys, vec![0., 0., 0., 1.]);
+    }
+}
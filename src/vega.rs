@@ -0,0 +1,336 @@
+// Structs
+use crate::configuration::plot::style::Style;
+use crate::configuration::save::format::{json_string, json_values, DataFormat};
+use crate::configuration::Configuration;
+use std::path::Path;
+
+// Traits
+use crate::traits::{Configurable, Saveable};
+
+/// Version of Vega-Lite of the specifications.
+const SCHEMA: &str = "https://vega.github.io/schema/vega-lite/v5.json";
+/// Size, in pixels, of each view.
+const SIZE: (usize, usize) = (400, 300);
+
+/// Specification with the title of the configuration and the given view.
+///
+/// ``view`` are the properties of a view, like ``"layer": [...]``, without braces.
+pub(crate) fn spec(config: &Configuration, view: &str) -> String {
+    let mut vega_spec = format!("{{\n\"$schema\": {},\n", json_string(SCHEMA));
+    if let Some(title) = config.title() {
+        vega_spec += &format!("\"title\": {},\n", json_string(title));
+    }
+    vega_spec += view;
+    vega_spec += "\n}\n";
+    vega_spec
+}
+
+/// Specification with the title of the configuration and the given layers.
+pub(crate) fn layered_spec(config: &Configuration, layers: &[String]) -> String {
+    spec(
+        config,
+        &format!("{},\n\"layer\": [\n{}\n]", size(), layers.join(",\n")),
+    )
+}
+
+/// Size properties of a view, without braces.
+pub(crate) fn size() -> String {
+    format!("\"width\": {}, \"height\": {}", SIZE.0, SIZE.1)
+}
+
+/// Data of ``item``, saved in ``path`` with ``format``, for the specification of ``config``.
+///
+/// It references the JSON file next to the data file if the format is [DataFormat::Json],
+/// by a path with ``/`` as separator, relative to the specification if so configured,
+/// and has the values inlined otherwise.
+///
+/// [DataFormat::Json]: ../enum.DataFormat.html#variant.Json
+pub(crate) fn data<S>(config: &Configuration, item: &S, format: DataFormat, path: &Path) -> String
+where
+    S: Saveable + ?Sized,
+{
+    match format {
+        DataFormat::Json => {
+            let url = config.vega_path(path.with_extension("json"));
+            format!("{{\"url\": {}}}", json_string(&url))
+        }
+        _ => format!("{{\"values\": {}}}", json_values(item)),
+    }
+}
+
+/// Mark drawing a series with a style.
+pub(crate) fn mark(style: &Style, dashtype: usize) -> String {
+    let (mark, options) = match style {
+        Style::Default | Style::Lines => ("line", ""),
+        Style::Points => ("point", ", \"shape\": \"cross\""),
+        Style::Linespoints => ("line", ", \"point\": true"),
+        Style::Impulses => ("rule", ""),
+        Style::Dots => ("circle", ", \"size\": 4"),
+        Style::Steps => ("line", ", \"interpolate\": \"step-after\""),
+        Style::Fsteps => ("line", ", \"interpolate\": \"step-before\""),
+        Style::Histeps => ("line", ", \"interpolate\": \"step\""),
+        Style::Boxes => ("bar", ", \"opacity\": 0.5"),
+    };
+    let dash = match dashtype {
+        0 | 1 => "",
+        _ => match (dashtype - 2) % 4 {
+            0 => ", \"strokeDash\": [6, 4]",
+            1 => ", \"strokeDash\": [2, 2]",
+            2 => ", \"strokeDash\": [6, 2, 2, 2]",
+            _ => ", \"strokeDash\": [8, 3, 2, 3, 2, 3]",
+        },
+    };
+    format!("{{\"type\": \"{}\"{}{}}}", mark, options, dash)
+}
+
+/// Encoding of the horizontal position by ``field``, with the label,
+/// logarithmic scale and range of the x axis of the configuration.
+pub(crate) fn x(config: &Configuration, field: &str) -> String {
+    position("x", field, config.labelx(), config.logx(), config.rangex())
+}
+
+/// Encoding of the vertical position by ``field``, with the label,
+/// logarithmic scale and range of the y axis of the configuration.
+pub(crate) fn y(config: &Configuration, field: &str) -> String {
+    position("y", field, config.labely(), config.logy(), config.rangey())
+}
+
+fn position(
+    channel: &str,
+    field: &str,
+    label: Option<&String>,
+    log: Option<f64>,
+    range: Option<(f64, f64)>,
+) -> String {
+    let mut scale = match log {
+        Some(base) if base > 0. => vec![
+            String::from("\"type\": \"log\""),
+            format!("\"base\": {}", base),
+        ],
+        Some(_) => vec![String::from("\"type\": \"log\"")],
+        None => vec![String::from("\"zero\": false")],
+    };
+    if let Some((min, max)) = range {
+        scale.push(format!("\"domain\": [{}, {}]", min, max));
+    }
+    let title = match label {
+        Some(label) => json_string(label),
+        None => String::from("null"),
+    };
+    format!(
+        "\"{}\": {{\"field\": {}, \"type\": \"quantitative\", \"title\": {}, \"scale\": {{{}}}}}",
+        channel,
+        json_string(field),
+        title,
+        scale.join(", ")
+    )
+}
+
+/// Layer drawing the fields ``x_field`` and ``y_field`` of the data as a series,
+/// colored by its legend, if any.
+pub(crate) fn series_layer(
+    config: &Configuration,
+    data: &str,
+    (x_field, y_field): (&str, &str),
+    style: &Style,
+    dashtype: usize,
+    legend: Option<&str>,
+) -> String {
+    let mut encoding = vec![x(config, x_field), y(config, y_field)];
+    if let Style::Impulses = style {
+        encoding.push(String::from("\"y2\": {\"datum\": 0}"));
+    }
+    if let Some(legend) = legend {
+        encoding.push(color(legend));
+    }
+    format!(
+        "{{\"data\": {}, \"mark\": {}, \"encoding\": {{{}}}}}",
+        data,
+        mark(style, dashtype),
+        encoding.join(", ")
+    )
+}
+
+/// Encoding of the color of a layer, shown in the legend.
+pub(crate) fn color(legend: &str) -> String {
+    format!("\"color\": {{\"datum\": {}}}", json_string(legend))
+}
+
+/// Encoding of the color by ``field``, with the label, logarithmic scale and range
+/// of the z axis of the configuration.
+pub(crate) fn z(config: &Configuration, field: &str) -> String {
+    let mut scale = match config.logz() {
+        Some(base) if base > 0. => vec![
+            String::from("\"type\": \"log\""),
+            format!("\"base\": {}", base),
+        ],
+        Some(_) => vec![String::from("\"type\": \"log\"")],
+        None => vec![],
+    };
+    if let Some((min, max)) = config.rangez() {
+        scale.push(format!("\"domain\": [{}, {}]", min, max));
+    }
+    let title = match config.labelz() {
+        Some(label) => json_string(label),
+        None => String::from("null"),
+    };
+    format!(
+        "\"color\": {{\"field\": {}, \"type\": \"quantitative\", \"title\": {}, \"scale\": {{{}}}}}",
+        json_string(field),
+        title,
+        scale.join(", ")
+    )
+}
+
+/// View of the values in a grid as colored rectangles.
+pub(crate) fn heatmap_view(config: &Configuration, data: &str) -> String {
+    let label = |label: Option<&String>| match label {
+        Some(label) => json_string(label),
+        None => String::from("null"),
+    };
+    format!(
+        "\"data\": {}, \"mark\": \"rect\", \"encoding\": {{\
+            \"x\": {{\"field\": \"x\", \"type\": \"ordinal\", \"title\": {}}}, \
+            \"y\": {{\"field\": \"y\", \"type\": \"ordinal\", \"title\": {}, \"sort\": \"descending\"}}, \
+            {}}}",
+        data,
+        label(config.labelx()),
+        label(config.labely()),
+        z(config, "value"),
+    )
+}
+
+/// Layers of a horizontal histogram, of width proportional to its frequency, for the
+/// values of each group, drawn from the group itself.
+pub(crate) fn bins_layer(
+    config: &Configuration,
+    data: &str,
+    group: &str,
+    binwidth: f64,
+    legend: Option<&str>,
+) -> String {
+    let color = match legend {
+        Some(legend) => format!(", {}", color(legend)),
+        None => String::new(),
+    };
+    format!(
+        "{{\"data\": {}, \"transform\": [\
+            {{\"bin\": {{\"step\": {binwidth}}}, \"field\": \"value\", \"as\": [\"bin_start\", \"bin_end\"]}}, \
+            {{\"aggregate\": [{{\"op\": \"count\", \"as\": \"count\"}}], \"groupby\": [\"{group}\", \"bin_start\", \"bin_end\"]}}, \
+            {{\"joinaggregate\": [{{\"op\": \"sum\", \"field\": \"count\", \"as\": \"total\"}}], \"groupby\": [\"{group}\"]}}, \
+            {{\"calculate\": \"datum.{group} + datum.count / (datum.total * {binwidth})\", \"as\": \"frequency\"}}], \
+        \"mark\": {{\"type\": \"rect\", \"opacity\": 0.5}}, \"encoding\": {{\
+            {}, \"x2\": {{\"field\": \"frequency\"}}, \
+            {}, \"y2\": {{\"field\": \"bin_end\"}}{}}}}}",
+        data,
+        x(config, group),
+        y(config, "bin_start"),
+        color,
+        binwidth = binwidth,
+        group = group,
+    )
+}
+
+/// Layer of violins: densities of the values of each group, drawn symmetrically around
+/// the group itself, and normalized so that the widest has width one.
+pub(crate) fn violins_layer(
+    config: &Configuration,
+    data: &str,
+    group: &str,
+    legend: Option<&str>,
+) -> String {
+    let color = match legend {
+        Some(legend) => color(legend),
+        None => format!(
+            "\"color\": {{\"field\": \"{}\", \"type\": \"nominal\", \"legend\": null}}",
+            group
+        ),
+    };
+    format!(
+        "{{\"data\": {}, \"transform\": [\
+            {{\"density\": \"value\", \"groupby\": [\"{group}\"], \"as\": [\"value\", \"density\"]}}, \
+            {{\"joinaggregate\": [{{\"op\": \"max\", \"field\": \"density\", \"as\": \"max\"}}]}}, \
+            {{\"calculate\": \"datum.{group} - datum.density / (2 * datum.max)\", \"as\": \"left\"}}, \
+            {{\"calculate\": \"datum.{group} + datum.density / (2 * datum.max)\", \"as\": \"right\"}}], \
+        \"mark\": {{\"type\": \"area\", \"orient\": \"horizontal\", \"opacity\": 0.5}}, \"encoding\": {{\
+            {}, \"x2\": {{\"field\": \"right\"}}, {}, \
+            \"detail\": {{\"field\": \"{group}\", \"type\": \"nominal\"}}, {}}}}}",
+        data,
+        x(config, "left"),
+        y(config, "value"),
+        color,
+        group = group,
+    )
+}
+
+/// Layer of the gaussian kernel density estimate of the realizations.
+pub(crate) fn density_layer(
+    config: &Configuration,
+    data: &str,
+    style: &Style,
+    dashtype: usize,
+    legend: Option<&str>,
+) -> String {
+    let mut encoding = vec![x(config, "realization"), y(config, "density")];
+    if let Some(legend) = legend {
+        encoding.push(color(legend));
+    }
+    format!(
+        "{{\"data\": {}, \"transform\": [{{\"density\": \"realization\", \"as\": [\"realization\", \"density\"]}}], \
+        \"mark\": {}, \"encoding\": {{{}}}}}",
+        data,
+        mark(style, dashtype),
+        encoding.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks() {
+        assert_eq!(mark(&Style::Default, 1), "{\"type\": \"line\"}");
+        assert_eq!(
+            mark(&Style::Steps, 2),
+            "{\"type\": \"line\", \"interpolate\": \"step-after\", \"strokeDash\": [6, 4]}"
+        );
+        assert_eq!(
+            mark(&Style::Boxes, 1),
+            "{\"type\": \"bar\", \"opacity\": 0.5}"
+        );
+    }
+
+    #[test]
+    fn positions() {
+        let mut config = Configuration::default();
+        assert_eq!(
+            x(&config, "index"),
+            "\"x\": {\"field\": \"index\", \"type\": \"quantitative\", \"title\": null, \"scale\": {\"zero\": false}}"
+        );
+        config.set_labely("Value").set_logy(2).set_rangey(1, 8);
+        assert_eq!(
+            y(&config, "value"),
+            "\"y\": {\"field\": \"value\", \"type\": \"quantitative\", \"title\": \"Value\", \"scale\": {\"type\": \"log\", \"base\": 2, \"domain\": [1, 8]}}"
+        );
+    }
+
+    #[test]
+    fn referenced_data() {
+        let mut seq = crate::Sequence::new(vec![1]);
+        let path = Path::new("runs").join("data").join("my_id.txt");
+        assert_eq!(
+            data(seq.configuration(), &seq, DataFormat::Json, &path),
+            "{\"url\": \"runs/data/my_id.json\"}"
+        );
+        seq.set_root_dir("runs").set_relative_paths(true);
+        assert_eq!(
+            data(seq.configuration(), &seq, DataFormat::Json, &path),
+            "{\"url\": \"../data/my_id.json\"}"
+        );
+        assert_eq!(
+            data(seq.configuration(), &seq, DataFormat::Gnuplot, &path),
+            "{\"values\": [\n{\"index\":0,\"value\":1,\"block\":0}\n]}"
+        );
+    }
+}